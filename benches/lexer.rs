@@ -0,0 +1,32 @@
+//! Lexer throughput on a large source file
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::hint::black_box;
+use TypeScript_Rust_Compiler::lexer::Lexer;
+
+/// About 100KB of TypeScript built from a repeated snippet
+fn large_source() -> String {
+    let snippet = r#"
+interface Point { x: number; y: number; }
+function distance(a: Point, b: Point): number {
+    const dx = a.x - b.x;
+    const dy = a.y - b.y;
+    return Math.sqrt(dx * dx + dy * dy);
+}
+const label = `distance: ${distance({ x: 0, y: 0 }, { x: 3, y: 4 })}`;
+"#;
+    snippet.repeat(100 * 1024 / snippet.len() + 1)
+}
+
+fn bench_lexer(c: &mut Criterion) {
+    let source = large_source();
+    let mut group = c.benchmark_group("tokenize_100kb");
+    group.throughput(Throughput::Bytes(source.len() as u64));
+    group.bench_function("Lexer", |b| {
+        b.iter(|| Lexer::new(black_box(source.clone())).tokenize().unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_lexer);
+criterion_main!(benches);
@@ -3,13 +3,13 @@
 use serde::{Deserialize, Serialize};
 
 /// Root program node
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Program {
     pub statements: Vec<Statement>,
 }
 
 /// Statement types
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Statement {
     VariableDeclaration(VariableDeclaration),
     FunctionDeclaration(FunctionDeclaration),
@@ -17,8 +17,15 @@ pub enum Statement {
     InterfaceDeclaration(InterfaceDeclaration),
     TypeAlias(TypeAlias),
     EnumDeclaration(EnumDeclaration),
+    /// `const enum`, whose members are inlined as constants
+    ConstEnumDeclaration(EnumDeclaration),
     ImportDeclaration(ImportDeclaration),
     ExportDeclaration(Box<ExportDeclaration>),
+    ExportDefaultDeclaration(Box<ExportDefaultDeclaration>),
+    /// `export { a, b as c };`
+    ExportNamedDeclaration(ExportNamedDeclaration),
+    /// `export type { A, B };`, re-exporting types only
+    ExportTypeDeclaration(ExportNamedDeclaration),
     NamespaceDeclaration(NamespaceDeclaration),
     ModuleDeclaration(ModuleDeclaration),
     DeclareStatement(Box<DeclareStatement>),
@@ -26,17 +33,21 @@ pub enum Statement {
     ExpressionStatement(ExpressionStatement),
     IfStatement(Box<IfStatement>),
     WhileStatement(WhileStatement),
+    DoWhileStatement(DoWhileStatement),
     ForStatement(ForStatement),
+    ForOfStatement(ForOfStatement),
+    ForInStatement(ForInStatement),
     ReturnStatement(ReturnStatement),
     BreakStatement(BreakStatement),
     ContinueStatement(ContinueStatement),
+    LabeledStatement(LabeledStatement),
     ThrowStatement(ThrowStatement),
     TryStatement(Box<TryStatement>),
     SwitchStatement(SwitchStatement),
 }
 
 /// Variable declaration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VariableDeclaration {
     pub keyword: crate::lexer::Keyword,
     pub name: String,
@@ -45,27 +56,30 @@ pub struct VariableDeclaration {
 }
 
 /// Function declaration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FunctionDeclaration {
     pub name: String,
     pub type_parameters: Vec<TypeParameter>,
     pub parameters: Vec<Parameter>,
     pub return_type: Option<Type>,
     pub body: Box<Statement>,
+    /// Whether this is a `function*` generator
+    pub generator: bool,
 }
 
 /// Class declaration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ClassDeclaration {
     pub name: String,
     pub type_parameters: Vec<TypeParameter>,
     pub extends: Option<Type>,
     pub implements: Vec<Type>,
     pub body: ClassBody,
+    pub decorators: Vec<Decorator>,
 }
 
 /// Interface declaration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InterfaceDeclaration {
     pub name: String,
     pub type_parameters: Vec<TypeParameter>,
@@ -74,7 +88,7 @@ pub struct InterfaceDeclaration {
 }
 
 /// Type alias
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TypeAlias {
     pub name: String,
     pub type_parameters: Vec<TypeParameter>,
@@ -82,59 +96,79 @@ pub struct TypeAlias {
 }
 
 /// Enum declaration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EnumDeclaration {
     pub name: String,
     pub members: Vec<EnumMember>,
 }
 
 /// Import declaration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ImportDeclaration {
     pub specifiers: Vec<ImportSpecifier>,
     pub source: String,
 }
 
 /// Export declaration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ExportDeclaration {
     pub declaration: Box<Statement>,
 }
 
+/// Named exports of existing bindings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportNamedDeclaration {
+    pub specifiers: Vec<ExportSpecifier>,
+}
+
+/// Export specifier, `local as exported`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportSpecifier {
+    pub local: String,
+    pub exported: String,
+}
+
+/// Default export: a named declaration or any expression
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ExportDefaultDeclaration {
+    Declaration(Statement),
+    Expression(Expression),
+}
+
 /// Namespace declaration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NamespaceDeclaration {
     pub name: String,
     pub body: Box<Statement>,
 }
 
 /// Module declaration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ModuleDeclaration {
     pub name: String,
     pub body: Box<Statement>,
 }
 
 /// Declare statement
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DeclareStatement {
     pub declaration: Box<Statement>,
 }
 
 /// Block statement
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BlockStatement {
     pub statements: Vec<Statement>,
 }
 
 /// Expression statement
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ExpressionStatement {
     pub expression: Expression,
 }
 
 /// If statement
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IfStatement {
     pub condition: Expression,
     pub consequent: Box<Statement>,
@@ -142,47 +176,86 @@ pub struct IfStatement {
 }
 
 /// While statement
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WhileStatement {
     pub condition: Expression,
     pub body: Box<Statement>,
 }
 
+/// Do-while statement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DoWhileStatement {
+    pub body: Box<Statement>,
+    pub condition: Expression,
+}
+
 /// For statement
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ForStatement {
-    pub init: Option<Expression>,
+    pub init: Option<Box<Statement>>,
     pub condition: Option<Expression>,
     pub update: Option<Expression>,
     pub body: Box<Statement>,
 }
 
+/// For...of statement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForOfStatement {
+    pub keyword: crate::lexer::Keyword,
+    pub binding: BindingPattern,
+    pub iterable: Expression,
+    pub body: Box<Statement>,
+}
+
+/// For...in statement over the keys of an object
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForInStatement {
+    pub keyword: crate::lexer::Keyword,
+    pub name: String,
+    pub object: Expression,
+    pub body: Box<Statement>,
+}
+
+/// Binding target of a declaration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BindingPattern {
+    Identifier(String),
+    Array(Vec<Option<BindingPattern>>), // None for holes
+}
+
 /// Return statement
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ReturnStatement {
     pub argument: Option<Expression>,
 }
 
 /// Break statement
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BreakStatement {
     pub label: Option<String>,
 }
 
 /// Continue statement
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ContinueStatement {
     pub label: Option<String>,
 }
 
+/// Labeled statement, e.g. `outer: for (...) {}`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LabeledStatement {
+    pub label: String,
+    pub body: Box<Statement>,
+}
+
 /// Throw statement
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ThrowStatement {
     pub argument: Expression,
 }
 
 /// Try statement
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TryStatement {
     pub block: Box<Statement>,
     pub handler: Option<CatchClause>,
@@ -190,19 +263,20 @@ pub struct TryStatement {
 }
 
 /// Switch statement
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SwitchStatement {
     pub discriminant: Expression,
     pub cases: Vec<SwitchCase>,
 }
 
 /// Expression types
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Expression {
     Literal(Literal),
     Identifier(String),
     Binary(BinaryExpression),
     Unary(UnaryExpression),
+    Update(UpdateExpression),
     Logical(LogicalExpression),
     Conditional(ConditionalExpression),
     Assignment(AssignmentExpression),
@@ -212,7 +286,10 @@ pub enum Expression {
     Object(ObjectExpression),
     Parenthesized(ParenthesizedExpression),
     Arrow(Box<ArrowFunctionExpression>),
+    Function(Box<FunctionExpression>),
+    Class(Box<ClassExpression>),
     New(NewExpression),
+    Spread(SpreadElement),
     Super(SuperExpression),
     This(ThisExpression),
     Yield(Box<YieldExpression>),
@@ -223,13 +300,15 @@ pub enum Expression {
     Optional(OptionalExpression),
     Template(TemplateLiteral),
     TaggedTemplate(TaggedTemplateExpression),
+    MetaProperty(MetaProperty),
 }
 
 /// Literal values
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Literal {
     String(String),
     Number(f64),
+    RadixNumber(f64, String), // value, source text such as 0xFF
     Boolean(bool),
     Null,
     Undefined,
@@ -238,7 +317,7 @@ pub enum Literal {
 }
 
 /// Binary expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BinaryExpression {
     pub left: Box<Expression>,
     pub operator: crate::lexer::Token,
@@ -246,14 +325,22 @@ pub struct BinaryExpression {
 }
 
 /// Unary expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UnaryExpression {
     pub operator: crate::lexer::Token,
     pub argument: Box<Expression>,
 }
 
+/// Increment or decrement such as `++x` or `x--`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateExpression {
+    pub operator: crate::lexer::Token,
+    pub argument: Box<Expression>,
+    pub prefix: bool,
+}
+
 /// Logical expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LogicalExpression {
     pub left: Box<Expression>,
     pub operator: crate::lexer::Token,
@@ -261,7 +348,7 @@ pub struct LogicalExpression {
 }
 
 /// Conditional expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConditionalExpression {
     pub test: Box<Expression>,
     pub consequent: Box<Expression>,
@@ -269,7 +356,7 @@ pub struct ConditionalExpression {
 }
 
 /// Assignment expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AssignmentExpression {
     pub left: Box<Expression>,
     pub operator: crate::lexer::Token,
@@ -277,14 +364,14 @@ pub struct AssignmentExpression {
 }
 
 /// Call expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CallExpression {
     pub callee: Box<Expression>,
     pub arguments: Vec<Expression>,
 }
 
 /// Member expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MemberExpression {
     pub object: Box<Expression>,
     pub property: Box<Expression>,
@@ -292,19 +379,19 @@ pub struct MemberExpression {
 }
 
 /// Array expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ArrayExpression {
     pub elements: Vec<Option<Expression>>,
 }
 
 /// Object expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ObjectExpression {
     pub properties: Vec<ObjectProperty>,
 }
 
 /// Object property
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ObjectProperty {
     pub key: Expression,
     pub value: Expression,
@@ -314,13 +401,13 @@ pub struct ObjectProperty {
 }
 
 /// Parenthesized expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ParenthesizedExpression {
     pub expression: Box<Expression>,
 }
 
 /// Arrow function expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ArrowFunctionExpression {
     pub type_parameters: Vec<TypeParameter>,
     pub parameters: Vec<Parameter>,
@@ -328,84 +415,120 @@ pub struct ArrowFunctionExpression {
     pub body: Box<Statement>,
 }
 
+/// Function expression
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunctionExpression {
+    pub name: Option<String>,
+    pub type_parameters: Vec<TypeParameter>,
+    pub parameters: Vec<Parameter>,
+    pub return_type: Option<Type>,
+    pub body: Box<Statement>,
+    /// Whether this is a `function*` generator
+    pub generator: bool,
+}
+
+/// Class expression, e.g. `const Widget = class extends Base {}`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClassExpression {
+    pub name: Option<String>,
+    pub type_parameters: Vec<TypeParameter>,
+    pub extends: Option<Type>,
+    pub implements: Vec<Type>,
+    pub body: ClassBody,
+}
+
 /// New expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NewExpression {
     pub callee: Box<Expression>,
+    pub type_arguments: Vec<Type>,
     pub arguments: Vec<Expression>,
 }
 
+/// Spread argument such as `...items`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpreadElement {
+    pub argument: Box<Expression>,
+}
+
 /// Super expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SuperExpression;
 
 /// This expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ThisExpression;
 
+/// Meta property such as `new.target` or `import.meta`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetaProperty {
+    pub meta: String,
+    pub property: String,
+}
+
 /// Yield expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct YieldExpression {
     pub argument: Option<Box<Expression>>,
     pub delegate: bool,
 }
 
 /// Await expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AwaitExpression {
     pub argument: Box<Expression>,
 }
 
 /// Type assertion
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TypeAssertion {
     pub expression: Box<Expression>,
     pub type_: Type,
 }
 
 /// As expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AsExpression {
     pub expression: Box<Expression>,
     pub type_: Type,
 }
 
 /// Non-null expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NonNullExpression {
     pub expression: Box<Expression>,
 }
 
 /// Optional expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OptionalExpression {
     pub expression: Box<Expression>,
     pub optional: bool,
 }
 
 /// Template literal
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TemplateLiteral {
     pub quasis: Vec<TemplateElement>,
     pub expressions: Vec<Expression>,
 }
 
 /// Template element
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TemplateElement {
     pub value: String,
     pub tail: bool,
 }
 
 /// Tagged template expression
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TaggedTemplateExpression {
     pub tag: Box<Expression>,
     pub quasi: TemplateLiteral,
 }
 
 /// Type definitions
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Type {
     // Primitive types
     String,
@@ -467,29 +590,40 @@ pub enum Type {
     // Parenthesized types
     Parenthesized(Box<Type>),
 
+    // Literal types such as `"a"` or `1`
+    Literal(Literal),
+
     // Type queries
     TypeQuery(Box<TypeQuery>),
 
+    // Type operators
+    KeyOf(Box<Type>),
+    Readonly(Box<Type>),
+    IndexedAccess {
+        object: Box<Type>,
+        index: Box<Type>,
+    },
+
     // Import types
     Import(Box<ImportType>),
 }
 
 /// Qualified type name
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct QualifiedTypeName {
     pub left: Box<Type>,
     pub right: String,
 }
 
 /// Generic type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GenericType {
     pub type_: Box<Type>,
     pub type_arguments: Vec<Type>,
 }
 
 /// Function type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FunctionType {
     pub type_parameters: Vec<TypeParameter>,
     pub parameters: Vec<Parameter>,
@@ -497,13 +631,13 @@ pub struct FunctionType {
 }
 
 /// Object type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ObjectType {
     pub members: Vec<ObjectTypeMember>,
 }
 
 /// Object type member
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ObjectTypeMember {
     Property(PropertySignature),
     Method(MethodSignature),
@@ -513,7 +647,7 @@ pub enum ObjectTypeMember {
 }
 
 /// Property signature
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PropertySignature {
     pub name: String,
     pub optional: bool,
@@ -522,7 +656,7 @@ pub struct PropertySignature {
 }
 
 /// Method signature
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MethodSignature {
     pub name: String,
     pub optional: bool,
@@ -532,7 +666,7 @@ pub struct MethodSignature {
 }
 
 /// Index signature
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IndexSignature {
     pub parameter: Box<Parameter>,
     pub type_: Type,
@@ -540,7 +674,7 @@ pub struct IndexSignature {
 }
 
 /// Call signature
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CallSignature {
     pub type_parameters: Vec<TypeParameter>,
     pub parameters: Vec<Parameter>,
@@ -548,7 +682,7 @@ pub struct CallSignature {
 }
 
 /// Construct signature
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConstructSignature {
     pub type_parameters: Vec<TypeParameter>,
     pub parameters: Vec<Parameter>,
@@ -556,7 +690,7 @@ pub struct ConstructSignature {
 }
 
 /// Mapped type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MappedType {
     pub type_parameter: Box<TypeParameter>,
     pub constraint: Option<Box<Type>>,
@@ -567,7 +701,7 @@ pub struct MappedType {
 }
 
 /// Conditional type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConditionalType {
     pub check_type: Box<Type>,
     pub extends_type: Box<Type>,
@@ -576,27 +710,27 @@ pub struct ConditionalType {
 }
 
 /// Template literal type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TemplateLiteralType {
     pub head: String,
     pub spans: Vec<TemplateLiteralTypeSpan>,
 }
 
 /// Template literal type span
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TemplateLiteralTypeSpan {
     pub type_: Type,
     pub literal: String,
 }
 
 /// Type query
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TypeQuery {
     pub expr_name: Box<Expression>,
 }
 
 /// Import type
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ImportType {
     pub argument: Box<Type>,
     pub qualifier: Option<String>,
@@ -604,7 +738,7 @@ pub struct ImportType {
 }
 
 /// Type parameter
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TypeParameter {
     pub name: String,
     pub constraint: Option<Box<Type>>,
@@ -612,23 +746,25 @@ pub struct TypeParameter {
 }
 
 /// Parameter
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Parameter {
     pub name: String,
     pub optional: bool,
     pub type_: Option<Box<Type>>,
     pub initializer: Option<Expression>,
     pub rest: bool,
+    /// Access modifiers that make this a constructor parameter property
+    pub modifiers: Vec<Modifier>,
 }
 
 /// Class body
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ClassBody {
     pub members: Vec<ClassMember>,
 }
 
 /// Class member
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ClassMember {
     Property(PropertyDeclaration),
     Method(MethodDeclaration),
@@ -636,22 +772,29 @@ pub enum ClassMember {
     Getter(GetterDeclaration),
     Setter(SetterDeclaration),
     Index(IndexSignature),
-    Decorator(String), // For decorator support
+    Decorator(Decorator), // For decorator support
+}
+
+/// Decorator with its call arguments, e.g. `@Route("/users")`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Decorator {
+    pub name: String,
+    pub arguments: Vec<Expression>,
 }
 
 /// Property declaration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PropertyDeclaration {
     pub name: String,
     pub optional: bool,
     pub type_: Option<Type>,
     pub initializer: Option<Expression>,
     pub modifiers: Vec<Modifier>,
-    pub decorators: Vec<String>,
+    pub decorators: Vec<Decorator>,
 }
 
 /// Method declaration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MethodDeclaration {
     pub name: String,
     pub optional: bool,
@@ -660,53 +803,53 @@ pub struct MethodDeclaration {
     pub return_type: Option<Type>,
     pub body: Option<Statement>,
     pub modifiers: Vec<Modifier>,
-    pub decorators: Vec<String>,
+    pub decorators: Vec<Decorator>,
 }
 
 /// Constructor declaration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConstructorDeclaration {
     pub parameters: Vec<Parameter>,
     pub body: Option<Statement>,
     pub modifiers: Vec<Modifier>,
-    pub decorators: Vec<String>,
+    pub decorators: Vec<Decorator>,
 }
 
 /// Getter declaration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GetterDeclaration {
     pub name: String,
     pub type_: Option<Type>,
     pub body: Option<Statement>,
     pub modifiers: Vec<Modifier>,
-    pub decorators: Vec<String>,
+    pub decorators: Vec<Decorator>,
 }
 
 /// Setter declaration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SetterDeclaration {
     pub name: String,
     pub parameter: Parameter,
     pub body: Option<Statement>,
     pub modifiers: Vec<Modifier>,
-    pub decorators: Vec<String>,
+    pub decorators: Vec<Decorator>,
 }
 
 /// Interface body
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct InterfaceBody {
     pub members: Vec<ObjectTypeMember>,
 }
 
 /// Enum member
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EnumMember {
     pub name: String,
     pub initializer: Option<Expression>,
 }
 
 /// Import specifier
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ImportSpecifier {
     Named(NamedImportSpecifier),
     Default(DefaultImportSpecifier),
@@ -714,40 +857,40 @@ pub enum ImportSpecifier {
 }
 
 /// Named import specifier
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NamedImportSpecifier {
     pub name: String,
     pub imported: String,
 }
 
 /// Default import specifier
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DefaultImportSpecifier {
     pub name: String,
 }
 
 /// Namespace import specifier
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NamespaceImportSpecifier {
     pub name: String,
 }
 
 /// Catch clause
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CatchClause {
     pub parameter: Option<Parameter>,
     pub body: Box<Statement>,
 }
 
 /// Switch case
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SwitchCase {
     pub expression: Option<Expression>,
     pub statements: Vec<Statement>,
 }
 
 /// Modifier
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Modifier {
     Public,
     Private,
@@ -760,14 +903,14 @@ pub enum Modifier {
 }
 
 /// Source location information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SourceLocation {
     pub start: Position,
     pub end: Position,
 }
 
 /// Position in source code
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Position {
     pub line: usize,
     pub column: usize,
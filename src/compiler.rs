@@ -1,17 +1,33 @@
 //! Main compiler implementation
 
+use crate::ast::{Program, Statement};
+use crate::config::GeneratorConfig;
 use crate::error::{CompilerError, Result};
 use crate::generator::CodeGenerator;
-use crate::lexer_utf8::Utf8Lexer;
+use crate::lexer::Lexer;
+use crate::optimizer::Optimizer;
 use crate::parser::Parser;
+use notify::{RecursiveMode, Watcher};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+/// How long sources must stay unchanged before watch mode recompiles
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(100);
 
 /// Main compiler struct
 pub struct Compiler {
     optimize: bool,
-    runtime: bool,
+    generator: GeneratorConfig,
     output_dir: Option<PathBuf>,
+    defines: HashMap<String, bool>,
+    strict: bool,
+    warnings: Vec<CompilerError>,
+    /// Time spent parsing and generating each source file in the last project build
+    timings: HashMap<PathBuf, Duration>,
 }
 
 impl Compiler {
@@ -19,8 +35,12 @@ impl Compiler {
     pub fn new() -> Self {
         Self {
             optimize: false,
-            runtime: false,
+            generator: GeneratorConfig::default(),
             output_dir: None,
+            defines: HashMap::new(),
+            strict: false,
+            warnings: Vec::new(),
+            timings: HashMap::new(),
         }
     }
 
@@ -32,7 +52,25 @@ impl Compiler {
 
     /// Enable runtime for TypeScript semantics
     pub fn with_runtime(mut self, runtime: bool) -> Self {
-        self.runtime = runtime;
+        self.generator.runtime = runtime;
+        self
+    }
+
+    /// Append a `fn main` to single-file output
+    pub fn with_main(mut self, emit_main: bool) -> Self {
+        self.generator.emit_main = emit_main;
+        self
+    }
+
+    /// Re-indent the generated code
+    pub fn with_format(mut self, format: bool) -> Self {
+        self.generator.format = format;
+        self
+    }
+
+    /// Set the style of generated Rust code
+    pub fn with_generator_config(mut self, config: GeneratorConfig) -> Self {
+        self.generator = config;
         self
     }
 
@@ -42,25 +80,117 @@ impl Compiler {
         self
     }
 
+    /// Define compile-time boolean constants such as `__DEV__`
+    pub fn with_defines(mut self, defines: HashMap<String, bool>) -> Self {
+        self.defines.extend(defines);
+        self
+    }
+
+    /// Fail on semantic errors, including non-empty input that translates to nothing, instead of warning
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
     /// Compile TypeScript code to Rust
     pub fn compile(&mut self, input: &Path, output: &Path) -> Result<()> {
         // Read input file
         let input_content = fs::read_to_string(input).map_err(CompilerError::Io)?;
 
-        // Create UTF-8 compatible lexer and tokenize
-        let mut lexer = Utf8Lexer::new(input_content);
+        // Tokenize, parse and generate Rust code
+        let rust_code = self.compile_str(&input_content)?;
+
+        // Write output
+        self.write_output(output, &rust_code)?;
+
+        Ok(())
+    }
+
+    /// Compile TypeScript source to Rust code
+    pub fn compile_str(&mut self, source: &str) -> Result<String> {
+        let (mut program, mut warnings) = self.parse_source(source)?;
+        if program.statements.is_empty() && !source.trim().is_empty() {
+            warnings.push(CompilerError::semantic_error(
+                "Input contains no statements; nothing was translated",
+            ));
+        }
+
+        self.optimize_program(&mut program);
+        let mut generator = CodeGenerator::new(self.generator.clone());
+        let rust_code = generator.generate(&program)?;
+        warnings.extend(generator.take_warnings());
+        self.warnings = self.check_strict(warnings)?;
+        Ok(rust_code)
+    }
+
+    /// In strict mode, turn semantic errors reported as warnings into a failure
+    fn check_strict(&self, warnings: Vec<CompilerError>) -> Result<Vec<CompilerError>> {
+        if !self.strict {
+            return Ok(warnings);
+        }
+        let (errors, warnings): (Vec<_>, Vec<_>) = warnings
+            .into_iter()
+            .partition(|warning| matches!(warning, CompilerError::SemanticError { .. }));
+        if errors.is_empty() {
+            Ok(warnings)
+        } else {
+            Err(CompilerError::multiple(errors))
+        }
+    }
+
+    /// Warnings reported by the last compilation
+    pub fn warnings(&self) -> &[CompilerError] {
+        &self.warnings
+    }
+
+    /// Parse TypeScript source into an AST
+    pub fn parse_str(&self, source: &str) -> Result<Program> {
+        self.parse_source(source).map(|(program, _)| program)
+    }
+
+    /// Parse TypeScript source, also returning the errors of statements that were skipped
+    fn parse_source(&self, source: &str) -> Result<(Program, Vec<CompilerError>)> {
+        // Tokenize and remember where each token starts
+        let mut lexer = Lexer::new(source.to_string());
         let tokens = lexer.tokenize()?;
 
         // Create parser and parse AST
-        let mut parser = Parser::new(tokens);
+        let mut parser = Parser::new(tokens).with_positions(lexer.positions().to_vec());
         let program = parser.parse()?;
+        Ok((program, parser.take_errors()))
+    }
 
-        // Create code generator
-        let mut generator = CodeGenerator::new(self.runtime);
-        let rust_code = generator.generate(&program)?;
+    /// Fold compile-time defines and eliminate dead branches
+    fn optimize_program(&self, program: &mut Program) {
+        Optimizer::new()
+            .with_defines(self.defines.clone())
+            .optimize(program);
+    }
 
-        // Write output
-        self.write_output(output, &rust_code)?;
+    /// Serialize the AST of TypeScript source as pretty-printed JSON
+    pub fn emit_ast_str(&self, source: &str) -> Result<String> {
+        let program = self.parse_str(source)?;
+        serde_json::to_string_pretty(&program).map_err(|e| {
+            CompilerError::internal_error(format!("Failed to serialize AST: {}", e))
+        })
+    }
+
+    /// Parse a TypeScript file and write its AST as JSON
+    ///
+    /// When `output` is `None` the JSON is printed to stdout.
+    pub fn emit_ast(&mut self, input: &Path, output: Option<&Path>) -> Result<()> {
+        let input_content = fs::read_to_string(input).map_err(CompilerError::Io)?;
+        let json = self.emit_ast_str(&input_content)?;
+
+        match output {
+            Some(output) => {
+                if let Some(parent) = output.parent() {
+                    fs::create_dir_all(parent).map_err(CompilerError::Io)?;
+                }
+                fs::write(output, json).map_err(CompilerError::Io)?;
+            }
+            None => println!("{}", json),
+        }
 
         Ok(())
     }
@@ -87,32 +217,48 @@ impl Compiler {
         // Create output directory if it doesn't exist
         fs::create_dir_all(output_dir).map_err(CompilerError::Io)?;
 
-        // Write main.rs
-        let main_rs_path = output_dir.join("src").join("main.rs");
-        fs::create_dir_all(main_rs_path.parent().unwrap()).map_err(CompilerError::Io)?;
-        fs::write(&main_rs_path, rust_code).map_err(CompilerError::Io)?;
+        // Without a `main` function the generated code is a library crate
+        let src_dir = output_dir.join("src");
+        fs::create_dir_all(&src_dir).map_err(CompilerError::Io)?;
+        let crate_root = if self.generator.emit_main { "main.rs" } else { "lib.rs" };
+        fs::write(src_dir.join(crate_root), rust_code).map_err(CompilerError::Io)?;
 
         // Write Cargo.toml
-        let cargo_toml = self.generate_cargo_toml();
+        let cargo_toml = self.generate_cargo_toml(
+            rust_code.contains("serde_json::"),
+            rust_code.contains("chrono::"),
+            rust_code.contains("regex::"),
+        );
         let cargo_toml_path = output_dir.join("Cargo.toml");
         fs::write(&cargo_toml_path, cargo_toml).map_err(CompilerError::Io)?;
 
-        // Write lib.rs if needed
-        let lib_rs_path = output_dir.join("src").join("lib.rs");
-        let lib_rs_content = self.generate_lib_rs();
-        fs::write(&lib_rs_path, lib_rs_content).map_err(CompilerError::Io)?;
+        // Write lib.rs next to main.rs
+        if self.generator.emit_main {
+            fs::write(src_dir.join("lib.rs"), self.generate_lib_rs()).map_err(CompilerError::Io)?;
+        }
 
         Ok(())
     }
 
     /// Generate Cargo.toml for the output project
-    fn generate_cargo_toml(&self) -> String {
+    fn generate_cargo_toml(&self, uses_json: bool, uses_chrono: bool, uses_regex: bool) -> String {
         let mut dependencies = vec![
             "serde = { version = \"1.0\", features = [\"derive\"] }".to_string(),
-            "serde_json = \"1.0\"".to_string(),
         ];
 
-        if self.runtime {
+        // `any` maps to `serde_json::Value` without the runtime
+        if uses_json {
+            dependencies.push("serde_json = \"1.0\"".to_string());
+        }
+        if uses_chrono {
+            dependencies.push("chrono = { version = \"0.4\", features = [\"serde\"] }".to_string());
+        }
+        // Regex literals compile to `regex::Regex`
+        if uses_regex {
+            dependencies.push("regex = \"1\"".to_string());
+        }
+
+        if self.generator.runtime {
             dependencies.push("anyhow = \"1.0\"".to_string());
             dependencies.push("thiserror = \"1.0\"".to_string());
         }
@@ -136,7 +282,7 @@ lto = true
 
     /// Generate lib.rs for the output project
     fn generate_lib_rs(&self) -> String {
-        if self.runtime {
+        if self.generator.runtime {
             r#"
 pub mod runtime;
 pub mod types;
@@ -158,6 +304,21 @@ pub use runtime::{Any, Unknown, TypeScriptObject};
 
     /// Compile multiple files
     pub fn compile_project(&mut self, input_dir: &Path, output_dir: &Path) -> Result<()> {
+        self.build_project(input_dir, output_dir, None)?;
+
+        // Generate project files
+        self.generate_project_files(output_dir)?;
+
+        Ok(())
+    }
+
+    /// Compile the project modules, regenerating only `changed` files when given
+    fn build_project(
+        &mut self,
+        input_dir: &Path,
+        output_dir: &Path,
+        changed: Option<&[PathBuf]>,
+    ) -> Result<()> {
         // Find all TypeScript files
         let ts_files = self.find_typescript_files(input_dir)?;
 
@@ -170,29 +331,368 @@ pub use runtime::{Any, Unknown, TypeScriptObject};
         // Create output directory
         fs::create_dir_all(output_dir).map_err(CompilerError::Io)?;
 
-        // Compile each file
-        for ts_file in ts_files {
+        // Parse every file up front so imports can be resolved across modules
+        let mut modules = Vec::new();
+        let mut files = HashMap::new();
+        let mut parse_warnings = Vec::new();
+        self.timings.clear();
+        for ts_file in &ts_files {
+            let start = Instant::now();
             let relative_path = ts_file
                 .strip_prefix(input_dir)
                 .map_err(|_| CompilerError::internal_error("Failed to strip prefix"))?;
 
-            let rust_file = output_dir.join(relative_path).with_extension("rs");
+            let source = fs::read_to_string(ts_file).map_err(CompilerError::Io)?;
+            let (mut program, skipped) = self.parse_source(&source)?;
+            parse_warnings.extend(skipped);
+            self.optimize_program(&mut program);
+            self.timings.insert(ts_file.clone(), start.elapsed());
+            let path = module_path(relative_path);
+            files.insert(path.clone(), relative_path.display().to_string());
+            modules.push((ts_file, path, program));
+        }
+
+        // Build the module graph
+        let known: HashSet<Vec<String>> = modules.iter().map(|(_, path, _)| path.clone()).collect();
+        let mut children: BTreeMap<Vec<String>, BTreeSet<String>> = BTreeMap::new();
+        for path in &known {
+            for depth in 0..path.len() {
+                let parent = &path[..depth];
+                if parent.is_empty() && matches!(path[0].as_str(), "main" | "lib") {
+                    continue;
+                }
+                children
+                    .entry(parent.to_vec())
+                    .or_default()
+                    .insert(path[depth].clone());
+            }
+        }
 
-            // Create directory for output file
+        // Resolve imports and reject cycles before generating anything
+        let mut graph = BTreeMap::new();
+        for (_, path, program) in &mut modules {
+            let targets = self.resolve_imports(path, program, &known);
+            graph.insert(path.clone(), targets);
+        }
+        detect_import_cycle(&graph, &files)?;
+
+        // Generate each module into src/
+        let src_dir = output_dir.join("src");
+        let root = if known.contains(&vec!["main".to_string()]) {
+            vec!["main".to_string()]
+        } else {
+            vec!["lib".to_string()]
+        };
+        let mut jobs = Vec::new();
+        let mut job_files = Vec::new();
+        for (ts_file, path, program) in &modules {
+            let declarations = if *path == root {
+                children.remove(&Vec::new())
+            } else {
+                children.remove(path)
+            };
+            // Parent modules are always regenerated so their declarations stay current
+            if declarations.is_none() && changed.is_some_and(|changed| !changed.contains(ts_file)) {
+                continue;
+            }
+
+            // Directories are created up front so worker threads never race on them
+            let rust_file = module_file(&src_dir, path);
             if let Some(parent) = rust_file.parent() {
                 fs::create_dir_all(parent).map_err(CompilerError::Io)?;
             }
+            jobs.push((program, declarations, rust_file));
+            job_files.push(*ts_file);
+        }
+        let (module_warnings, durations) = self.write_modules(&jobs)?;
+        parse_warnings.extend(module_warnings);
+        for (ts_file, duration) in job_files.into_iter().zip(durations) {
+            *self.timings.entry(ts_file.clone()).or_default() += duration;
+        }
+        self.warnings = self.check_strict(parse_warnings)?;
+
+        // Directories without a module file of their own still need declarations
+        for (path, names) in children {
+            let rust_file = if path.is_empty() {
+                module_file(&src_dir, &root)
+            } else {
+                src_dir.join(path.join("/")).join("mod.rs")
+            };
+            if let Some(parent) = rust_file.parent() {
+                fs::create_dir_all(parent).map_err(CompilerError::Io)?;
+            }
+            fs::write(&rust_file, module_declarations(&names)).map_err(CompilerError::Io)?;
+        }
+
+        Ok(())
+    }
+
+    /// Generate and write modules in parallel, reporting every failure
+    ///
+    /// Returns the warnings and how long each job took, in job order.
+    fn write_modules(
+        &self,
+        jobs: &[(&Program, Option<BTreeSet<String>>, PathBuf)],
+    ) -> Result<(Vec<CompilerError>, Vec<Duration>)> {
+        if jobs.is_empty() {
+            return Ok((Vec::new(), Vec::new()));
+        }
+
+        let workers = thread::available_parallelism().map_or(1, |n| n.get());
+        let chunk_size = jobs.len().div_ceil(workers);
+        // Project modules are library code and never get a `fn main`
+        let config = &GeneratorConfig {
+            emit_main: false,
+            ..self.generator.clone()
+        };
+
+        let results: Vec<Result<(Vec<CompilerError>, Duration)>> = thread::scope(|scope| {
+            let handles: Vec<_> = jobs
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|(program, declarations, rust_file)| {
+                                let start = Instant::now();
+                                write_module(config, program, declarations.as_ref(), rust_file)
+                                    .map(|warnings| (warnings, start.elapsed()))
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| {
+                    handle.join().unwrap_or_else(|_| {
+                        vec![Err(CompilerError::internal_error("Module generation thread panicked"))]
+                    })
+                })
+                .collect()
+        });
+
+        let mut warnings = Vec::new();
+        let mut durations = Vec::new();
+        let mut errors = Vec::new();
+        for result in results {
+            match result {
+                Ok((module_warnings, duration)) => {
+                    warnings.extend(module_warnings);
+                    durations.push(duration);
+                }
+                Err(e) => errors.push(e),
+            }
+        }
 
-            // Compile single file
-            self.compile(&ts_file, &rust_file)?;
+        if errors.is_empty() {
+            Ok((warnings, durations))
+        } else {
+            Err(CompilerError::multiple(errors))
         }
+    }
 
-        // Generate project files
-        self.generate_project_files(output_dir)?;
+    /// Watch a file or directory and recompile whenever sources change
+    pub fn watch(&mut self, input: &Path, output: &Path) -> Result<()> {
+        self.watch_with_timeout(input, output, None).map(|_| ())
+    }
+
+    /// Watch like `watch`, returning once no source changes for `timeout`
+    ///
+    /// Returns the files recompiled after the initial build.
+    pub fn watch_with_timeout(
+        &mut self,
+        input: &Path,
+        output: &Path,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<PathBuf>> {
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender).map_err(watch_error)?;
+        // A single file is watched through its directory, since editors often replace the file
+        let (watched, mode) = if input.is_dir() {
+            (input, RecursiveMode::Recursive)
+        } else {
+            let parent = input.parent().filter(|parent| !parent.as_os_str().is_empty());
+            (parent.unwrap_or(Path::new(".")), RecursiveMode::NonRecursive)
+        };
+        watcher.watch(watched, mode).map_err(watch_error)?;
+
+        let mut mtimes = HashMap::new();
+        if let Err(e) = self.recompile_changed(input, output, &mut mtimes) {
+            eprintln!("❌ {}", e);
+        }
+
+        let mut recompiled = Vec::new();
+        let mut deadline = timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            let event = match deadline {
+                Some(deadline) => events.recv_timeout(deadline.saturating_duration_since(Instant::now())).ok(),
+                None => events.recv().ok(),
+            };
+            let Some(event) = event else {
+                return Ok(recompiled);
+            };
+
+            // Reading the sources raises access events too; only writes are changes
+            let mut touched = HashSet::new();
+            let mut next = Some(event);
+            while let Some(result) = next {
+                let event = result.map_err(watch_error)?;
+                if event.kind.is_create() || event.kind.is_modify() {
+                    touched.extend(event.paths.iter().filter_map(|path| path.canonicalize().ok()));
+                }
+                // Debounce rapid saves by waiting until the writes settle
+                next = if touched.is_empty() {
+                    None
+                } else {
+                    events.recv_timeout(WATCH_DEBOUNCE).ok()
+                };
+            }
+            if touched.is_empty() {
+                continue;
+            }
+
+            let sources = if input.is_dir() {
+                self.find_typescript_files(input)?
+            } else {
+                vec![input.to_path_buf()]
+            };
+            let changed: Vec<PathBuf> = sources
+                .into_iter()
+                .filter(|source| source.canonicalize().is_ok_and(|source| touched.contains(&source)))
+                .collect();
+            if changed.is_empty() {
+                continue;
+            }
+
+            // Keep watching after a failed build
+            match self.recompile(input, output, changed.clone()) {
+                Ok(()) => recompiled.extend(changed),
+                Err(e) => eprintln!("❌ {}", e),
+            }
+            deadline = timeout.map(|timeout| Instant::now() + timeout);
+        }
+    }
+
+    /// Recompile the files that changed since the previous call
+    ///
+    /// The first call compiles everything and records modification times in `mtimes`.
+    pub fn recompile_changed(
+        &mut self,
+        input: &Path,
+        output: &Path,
+        mtimes: &mut HashMap<PathBuf, SystemTime>,
+    ) -> Result<Vec<PathBuf>> {
+        let changed = self.changed_files(input, mtimes)?;
+        self.recompile(input, output, changed.clone())?;
+        Ok(changed)
+    }
+
+    /// Recompile the given changed files and report how long each one took
+    fn recompile(&mut self, input: &Path, output: &Path, changed: Vec<PathBuf>) -> Result<()> {
+        if changed.is_empty() {
+            return Ok(());
+        }
+
+        let start = Instant::now();
+        if input.is_dir() {
+            self.build_project(input, output, Some(&changed))?;
+            if !output.join("Cargo.toml").exists() {
+                self.generate_project_files(output)?;
+            }
+        } else {
+            self.compile(input, output)?;
+        }
+        let elapsed = start.elapsed();
+
+        for file in &changed {
+            let time = self.timings.get(file).copied().unwrap_or(elapsed);
+            println!("🔄 Recompiled {} in {:.2?}", file.display(), time);
+        }
+        if changed.len() > 1 {
+            println!("   Rebuilt {} files in {:.2?}", changed.len(), elapsed);
+        }
+        for warning in &self.warnings {
+            eprintln!("⚠️  {}", warning);
+        }
 
         Ok(())
     }
 
+    /// Collect files whose modification time differs from the recorded one
+    fn changed_files(
+        &self,
+        input: &Path,
+        mtimes: &mut HashMap<PathBuf, SystemTime>,
+    ) -> Result<Vec<PathBuf>> {
+        let files = if input.is_dir() {
+            self.find_typescript_files(input)?
+        } else {
+            vec![input.to_path_buf()]
+        };
+
+        let mut changed = Vec::new();
+        for file in files {
+            let modified = fs::metadata(&file)
+                .and_then(|metadata| metadata.modified())
+                .map_err(CompilerError::Io)?;
+            if mtimes.insert(file.clone(), modified) != Some(modified) {
+                changed.push(file);
+            }
+        }
+
+        Ok(changed)
+    }
+
+    /// Rewrite relative import sources that point at project modules into crate paths
+    ///
+    /// Returns the modules this program imports.
+    fn resolve_imports(
+        &self,
+        path: &[String],
+        program: &mut Program,
+        known: &HashSet<Vec<String>>,
+    ) -> Vec<Vec<String>> {
+        let mut targets = Vec::new();
+        for statement in &mut program.statements {
+            if let Statement::ImportDeclaration(import) = statement {
+                if !import.source.starts_with('.') {
+                    continue;
+                }
+
+                let mut resolved = path[..path.len() - 1].to_vec();
+                for segment in import.source.split('/') {
+                    match segment {
+                        "." | "" => {}
+                        ".." => {
+                            resolved.pop();
+                        }
+                        _ => resolved.push(module_name(segment)),
+                    }
+                }
+
+                let mut index = resolved.clone();
+                index.push("index".to_string());
+                let target = if known.contains(&resolved) {
+                    resolved
+                } else if known.contains(&index) {
+                    index
+                } else {
+                    continue;
+                };
+
+                import.source = if target.len() == 1 && matches!(target[0].as_str(), "main" | "lib") {
+                    "crate".to_string()
+                } else {
+                    format!("crate::{}", target.join("::"))
+                };
+                targets.push(target);
+            }
+        }
+        targets
+    }
+
     /// Find all TypeScript files in directory
     fn find_typescript_files(&self, dir: &Path) -> Result<Vec<PathBuf>> {
         let mut ts_files = Vec::new();
@@ -216,7 +716,12 @@ pub use runtime::{Any, Unknown, TypeScriptObject};
     /// Generate project files
     fn generate_project_files(&self, output_dir: &Path) -> Result<()> {
         // Generate Cargo.toml
-        let cargo_toml = self.generate_cargo_toml();
+        let src_dir = output_dir.join("src");
+        let cargo_toml = self.generate_cargo_toml(
+            sources_contain(&src_dir, "serde_json::")?,
+            sources_contain(&src_dir, "chrono::")?,
+            sources_contain(&src_dir, "regex::")?,
+        );
         let cargo_toml_path = output_dir.join("Cargo.toml");
         fs::write(&cargo_toml_path, cargo_toml).map_err(CompilerError::Io)?;
 
@@ -296,7 +801,7 @@ Thumbs.db
             "TypeScript-Rust-Compiler v{}\nOptimization: {}\nRuntime: {}",
             Self::version(),
             self.optimize,
-            self.runtime
+            self.generator.runtime
         )
     }
 }
@@ -306,3 +811,128 @@ impl Default for Compiler {
         Self::new()
     }
 }
+
+/// Convert a path relative to the project root into a Rust module path
+fn module_path(relative_path: &Path) -> Vec<String> {
+    relative_path
+        .with_extension("")
+        .components()
+        .map(|c| module_name(&c.as_os_str().to_string_lossy()))
+        .collect()
+}
+
+/// Convert a file or import segment into a valid Rust module name
+fn module_name(segment: &str) -> String {
+    let stem = segment
+        .strip_suffix(".ts")
+        .or_else(|| segment.strip_suffix(".js"))
+        .unwrap_or(segment);
+    stem.replace(['-', '.'], "_")
+}
+
+/// Output file for a module path under the src directory
+fn module_file(src_dir: &Path, path: &[String]) -> PathBuf {
+    src_dir.join(path.join("/")).with_extension("rs")
+}
+
+/// Report a failure of the filesystem watcher
+fn watch_error(error: notify::Error) -> CompilerError {
+    CompilerError::internal_error(format!("Failed to watch sources: {}", error))
+}
+
+/// Generate one module and write it to disk
+fn write_module(
+    config: &GeneratorConfig,
+    program: &Program,
+    declarations: Option<&BTreeSet<String>>,
+    rust_file: &Path,
+) -> Result<Vec<CompilerError>> {
+    let mut generator = CodeGenerator::new(config.clone());
+    let rust_code = generator.generate(program)?;
+    let rust_code = match declarations {
+        Some(names) => format!("{}\n{}", module_declarations(names), rust_code),
+        None => rust_code,
+    };
+
+    fs::write(rust_file, rust_code).map_err(CompilerError::Io)?;
+    Ok(generator.take_warnings())
+}
+
+/// Whether any Rust source under `dir` contains `needle`
+fn sources_contain(dir: &Path, needle: &str) -> Result<bool> {
+    if !dir.is_dir() {
+        return Ok(false);
+    }
+    for entry in fs::read_dir(dir).map_err(CompilerError::Io)? {
+        let path = entry.map_err(CompilerError::Io)?.path();
+        let found = if path.is_dir() {
+            sources_contain(&path, needle)?
+        } else if path.extension().and_then(|s| s.to_str()) == Some("rs") {
+            fs::read_to_string(&path).map_err(CompilerError::Io)?.contains(needle)
+        } else {
+            false
+        };
+        if found {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Generate `mod` declarations for child modules
+fn module_declarations(names: &BTreeSet<String>) -> String {
+    names
+        .iter()
+        .map(|name| format!("pub mod {};\n", name))
+        .collect()
+}
+
+/// Visit state of a module during import cycle detection
+#[derive(Clone, Copy, PartialEq)]
+enum Visit {
+    InProgress,
+    Done,
+}
+
+/// Report the first import cycle in the module graph
+fn detect_import_cycle(
+    graph: &BTreeMap<Vec<String>, Vec<Vec<String>>>,
+    files: &HashMap<Vec<String>, String>,
+) -> Result<()> {
+    let mut state = HashMap::new();
+    let mut stack = Vec::new();
+    for module in graph.keys() {
+        visit_module(module, graph, files, &mut state, &mut stack)?;
+    }
+    Ok(())
+}
+
+/// Depth-first visit of a module and its imports
+fn visit_module<'a>(
+    module: &'a Vec<String>,
+    graph: &'a BTreeMap<Vec<String>, Vec<Vec<String>>>,
+    files: &HashMap<Vec<String>, String>,
+    state: &mut HashMap<&'a Vec<String>, Visit>,
+    stack: &mut Vec<&'a Vec<String>>,
+) -> Result<()> {
+    match state.get(module) {
+        Some(Visit::Done) => return Ok(()),
+        Some(Visit::InProgress) => {
+            let start = stack.iter().position(|m| *m == module).unwrap_or(0);
+            let mut cycle: Vec<String> = stack[start..].iter().map(|m| files[*m].clone()).collect();
+            cycle.push(files[module].clone());
+            return Err(CompilerError::circular_import(cycle));
+        }
+        None => {}
+    }
+
+    state.insert(module, Visit::InProgress);
+    stack.push(module);
+    for target in graph.get(module).into_iter().flatten() {
+        visit_module(target, graph, files, state, stack)?;
+    }
+    stack.pop();
+    state.insert(module, Visit::Done);
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+//! Compiler configuration loaded from JSON
+
+use crate::error::{CompilerError, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Compiler configuration file contents
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CompilerConfig {
+    /// Compile-time boolean constants, e.g. `{ "__DEV__": true }`
+    #[serde(default)]
+    pub define: HashMap<String, bool>,
+    /// Style of the generated Rust code
+    #[serde(default)]
+    pub generator: GeneratorConfig,
+}
+
+/// Options controlling the style of generated Rust code
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct GeneratorConfig {
+    /// Generate runtime support for TypeScript semantics
+    pub runtime: bool,
+    /// Map `number` to `i64` instead of `f64`
+    pub number_as_i64: bool,
+    /// Derive `Serialize` and `Deserialize` on generated types
+    pub derive_serde: bool,
+    /// Visibility of generated structs, e.g. `pub` or `pub(crate)`
+    pub struct_visibility: String,
+    /// Append a `fn main` when the output has items
+    pub emit_main: bool,
+    /// Rust type for `Date`, e.g. `chrono::DateTime<chrono::Utc>`
+    pub date_type: String,
+    /// Rename camelCase identifiers to snake_case
+    pub rename_to_snake_case: bool,
+    /// Re-indent the generated code
+    pub format: bool,
+}
+
+impl Default for GeneratorConfig {
+    fn default() -> Self {
+        Self {
+            runtime: false,
+            number_as_i64: false,
+            derive_serde: true,
+            struct_visibility: "pub".to_string(),
+            emit_main: false,
+            date_type: "std::time::SystemTime".to_string(),
+            rename_to_snake_case: false,
+            format: false,
+        }
+    }
+}
+
+impl CompilerConfig {
+    /// Parse a configuration from a JSON string
+    pub fn parse(source: &str) -> Result<Self> {
+        serde_json::from_str(source)
+            .map_err(|e| CompilerError::internal_error(format!("Invalid config: {}", e)))
+    }
+
+    /// Load a configuration from a JSON file
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let source = fs::read_to_string(path).map_err(CompilerError::Io)?;
+        Self::parse(&source)
+    }
+}
@@ -0,0 +1,62 @@
+//! Registry of decorators with a Rust equivalent
+
+use std::collections::HashMap;
+
+/// How a decorator changes the generated Rust item
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecoratorEffect {
+    /// Add traits to the item's `#[derive(...)]`
+    Derive(Vec<String>),
+    /// Add an attribute such as `#[deprecated]` to the item
+    Attribute(String),
+}
+
+/// Known decorators and their effect on generated code
+#[derive(Debug, Clone)]
+pub struct DecoratorRegistry {
+    effects: HashMap<String, DecoratorEffect>,
+}
+
+impl DecoratorRegistry {
+    /// Create a registry with no known decorators
+    pub fn empty() -> Self {
+        Self {
+            effects: HashMap::new(),
+        }
+    }
+
+    /// Create a registry with the built-in decorators
+    pub fn new() -> Self {
+        Self::empty()
+            .with_derive("serializable", &["Serialize", "Deserialize"])
+            .with_attribute("deprecated", "#[deprecated]")
+    }
+
+    /// Register a decorator that adds derives
+    pub fn with_derive(mut self, name: &str, traits: &[&str]) -> Self {
+        let traits = traits.iter().map(|t| t.to_string()).collect();
+        self.effects
+            .insert(name.to_string(), DecoratorEffect::Derive(traits));
+        self
+    }
+
+    /// Register a decorator that adds an attribute
+    pub fn with_attribute(mut self, name: &str, attribute: &str) -> Self {
+        self.effects.insert(
+            name.to_string(),
+            DecoratorEffect::Attribute(attribute.to_string()),
+        );
+        self
+    }
+
+    /// Look up the effect of a decorator by name
+    pub fn get(&self, name: &str) -> Option<&DecoratorEffect> {
+        self.effects.get(name)
+    }
+}
+
+impl Default for DecoratorRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -27,11 +27,26 @@ pub enum CompilerError {
     #[error("Generation error: {message}")]
     GenerationError { message: String },
 
+    #[error("Circular import: {}", cycle.join(" -> "))]
+    CircularImport { cycle: Vec<String> },
+
     #[error("Unsupported TypeScript feature: {feature}")]
     UnsupportedFeature { feature: String },
 
     #[error("Internal compiler error: {message}")]
     InternalError { message: String },
+
+    #[error("{} errors:\n{}", errors.len(), join_errors(errors))]
+    Multiple { errors: Vec<CompilerError> },
+}
+
+/// Render one error per line for a combined report
+fn join_errors(errors: &[CompilerError]) -> String {
+    errors
+        .iter()
+        .map(|e| format!("  {}", e))
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 impl CompilerError {
@@ -65,6 +80,11 @@ impl CompilerError {
         }
     }
 
+    /// Create a circular import error
+    pub fn circular_import(cycle: Vec<String>) -> Self {
+        Self::CircularImport { cycle }
+    }
+
     /// Create an unsupported feature error
     pub fn unsupported_feature(feature: impl Into<String>) -> Self {
         Self::UnsupportedFeature {
@@ -78,4 +98,13 @@ impl CompilerError {
             message: message.into(),
         }
     }
+
+    /// Combine several errors into one, unwrapping a single error
+    pub fn multiple(mut errors: Vec<CompilerError>) -> Self {
+        if errors.len() == 1 {
+            errors.remove(0)
+        } else {
+            Self::Multiple { errors }
+        }
+    }
 }
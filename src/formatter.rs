@@ -0,0 +1,170 @@
+//! Re-indentation of generated Rust code
+
+/// Width of one indentation level
+const INDENT: &str = "    ";
+
+/// Re-indent Rust code by bracket depth and collapse runs of blank lines
+///
+/// String literals and comments are skipped when counting brackets, and lines
+/// that continue a multi-line string or comment are left untouched.
+pub fn format_rust(code: &str) -> String {
+    let mut formatted = Vec::new();
+    let mut depth: usize = 0;
+    let mut context = Context::Code;
+    let mut previous_blank = true;
+
+    for line in code.lines() {
+        if context != Context::Code {
+            formatted.push(line.to_string());
+            context = scan_line(line, context).context;
+            previous_blank = false;
+            continue;
+        }
+
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            if !previous_blank {
+                formatted.push(String::new());
+            }
+            previous_blank = true;
+            continue;
+        }
+
+        let scan = scan_line(trimmed, Context::Code);
+        let level = depth.saturating_sub(scan.leading_closers);
+        formatted.push(format!("{}{}", INDENT.repeat(level), trimmed));
+        depth = (depth + scan.opened).saturating_sub(scan.closed);
+        context = scan.context;
+        previous_blank = false;
+    }
+
+    while formatted.last().is_some_and(|line| line.is_empty()) {
+        formatted.pop();
+    }
+    let mut code = formatted.join("\n");
+    code.push('\n');
+    code
+}
+
+/// What the scanner is inside of at a given point
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Context {
+    Code,
+    /// A string literal
+    Str,
+    /// A raw string literal closed by `"` and this many `#`
+    RawStr(usize),
+    /// A `/* */` comment nested this deep
+    BlockComment(usize),
+}
+
+/// Bracket counts for one line of code
+struct LineScan {
+    /// Closing brackets before any other code on the line
+    leading_closers: usize,
+    opened: usize,
+    closed: usize,
+    /// Where the line ends
+    context: Context,
+}
+
+/// Count the brackets of a line outside string literals and comments
+fn scan_line(line: &str, mut context: Context) -> LineScan {
+    let mut scan = LineScan {
+        leading_closers: 0,
+        opened: 0,
+        closed: 0,
+        context,
+    };
+    let mut leading = true;
+    let chars: Vec<char> = line.chars().collect();
+    let mut previous = ' ';
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match context {
+            Context::Code => {}
+            Context::Str => {
+                match c {
+                    '\\' => i += 1,
+                    '"' => context = Context::Code,
+                    _ => {}
+                }
+                i += 1;
+                continue;
+            }
+            Context::RawStr(hashes) => {
+                if c == '"' && chars.get(i + 1..i + 1 + hashes).is_some_and(|s| s.iter().all(|&h| h == '#')) {
+                    context = Context::Code;
+                    i += hashes;
+                }
+                i += 1;
+                continue;
+            }
+            Context::BlockComment(nesting) => {
+                if c == '*' && next == Some('/') {
+                    context = if nesting == 1 { Context::Code } else { Context::BlockComment(nesting - 1) };
+                    i += 1;
+                } else if c == '/' && next == Some('*') {
+                    context = Context::BlockComment(nesting + 1);
+                    i += 1;
+                }
+                i += 1;
+                continue;
+            }
+        }
+        match c {
+            '"' => context = Context::Str,
+            // Raw strings such as r#"a"b"# end only at a quote followed by their hashes
+            'r' if !(previous.is_alphanumeric() || previous == '_') => {
+                let hashes = chars[i + 1..].iter().take_while(|&&h| h == '#').count();
+                if chars.get(i + 1 + hashes) == Some(&'"') {
+                    context = Context::RawStr(hashes);
+                    i += hashes + 1;
+                }
+            }
+            '/' if next == Some('/') => break,
+            '/' if next == Some('*') => {
+                context = Context::BlockComment(1);
+                i += 2;
+                continue;
+            }
+            // Char literals such as '{' hold a bracket; labels like 'outer do not
+            '\'' => {
+                let literal_len = match next {
+                    Some('\\') => chars
+                        .get(i + 3..)
+                        .and_then(|rest| rest.iter().position(|&c| c == '\''))
+                        .map(|n| n + 3),
+                    Some(_) if chars.get(i + 2) == Some(&'\'') => Some(2),
+                    _ => None,
+                };
+                i += literal_len.unwrap_or(0);
+            }
+            '{' | '(' | '[' => scan.opened += 1,
+            '}' | ')' | ']' => {
+                scan.closed += 1;
+                if leading {
+                    scan.leading_closers += 1;
+                }
+                previous = c;
+                i += 1;
+                continue;
+            }
+            c if c.is_whitespace() => {
+                previous = c;
+                i += 1;
+                continue;
+            }
+            _ => {}
+        }
+        leading = false;
+        previous = c;
+        i += 1;
+    }
+
+    scan.context = context;
+    scan
+}
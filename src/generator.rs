@@ -1,9 +1,17 @@
 //! Rust code generator for TypeScript AST
 
 use crate::ast::*;
+use crate::config::GeneratorConfig;
+use crate::decorators::{DecoratorEffect, DecoratorRegistry};
 use crate::error::{CompilerError, Result};
+use crate::formatter::format_rust;
 use crate::lexer::Token;
-use crate::types::TypeMapper;
+use crate::semantic::{SemanticAnalyzer, SymbolType};
+use crate::types::{index_key_type, TypeMapper};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Name under which a module's default export can be imported
+const DEFAULT_EXPORT: &str = "DefaultExport";
 
 /// Rust code generator
 pub struct CodeGenerator {
@@ -14,118 +22,153 @@ pub struct CodeGenerator {
     functions: Vec<String>,
     enums: Vec<String>,
     modules: Vec<String>,
-    runtime_support: bool,
+    prototype_methods: HashMap<String, Vec<MethodDeclaration>>,
+    partial_eq_types: HashSet<String>,
+    object_types: HashMap<String, Vec<PropertySignature>>,
+    semantics: SemanticAnalyzer,
+    decorator_registry: DecoratorRegistry,
+    warnings: Vec<CompilerError>,
+    config: GeneratorConfig,
+    /// Whether the body being generated belongs to a `function*`
+    in_generator: bool,
+    /// Names of `const enum`s, whose members are referenced directly
+    const_enums: HashSet<String>,
+    /// Interfaces by name, so implementing classes can generate trait impls
+    interfaces: HashMap<String, InterfaceDeclaration>,
+    /// Loops enclosing the statement being generated, innermost last
+    loops: Vec<LoopContext>,
+}
+
+/// A loop that `continue` statements may target
+struct LoopContext {
+    label: Option<String>,
+    /// Code that must run before continuing, such as a `for` loop's update
+    before_continue: Option<String>,
 }
 
 impl CodeGenerator {
     /// Create a new code generator
-    pub fn new(runtime: bool) -> Self {
+    pub fn new(config: GeneratorConfig) -> Self {
         Self {
-            type_mapper: TypeMapper::new(runtime),
+            type_mapper: TypeMapper::new(config.runtime)
+                .with_integer_numbers(config.number_as_i64)
+                .with_date_type(config.date_type.clone())
+                .with_snake_case(config.rename_to_snake_case)
+                .with_serde_derives(config.derive_serde)
+                .with_struct_visibility(config.struct_visibility.clone()),
             imports: Vec::new(),
             structs: Vec::new(),
             traits: Vec::new(),
             functions: Vec::new(),
             enums: Vec::new(),
             modules: Vec::new(),
-            runtime_support: runtime,
+            prototype_methods: HashMap::new(),
+            partial_eq_types: HashSet::new(),
+            object_types: HashMap::new(),
+            semantics: SemanticAnalyzer::new(),
+            decorator_registry: DecoratorRegistry::new(),
+            warnings: Vec::new(),
+            config,
+            in_generator: false,
+            const_enums: HashSet::new(),
+            interfaces: HashMap::new(),
+            loops: Vec::new(),
         }
     }
 
-    /// Generate Rust code from TypeScript program
-    pub fn generate(&mut self, program: &Program) -> Result<String> {
-        let mut rust_code = String::new();
+    /// Use a custom decorator registry
+    pub fn with_decorator_registry(mut self, registry: DecoratorRegistry) -> Self {
+        self.decorator_registry = registry;
+        self
+    }
 
-        // Generate imports (will be updated after processing statements)
-        rust_code.push_str("use std::collections::HashMap;\n");
-        rust_code.push('\n');
+    /// Take the warnings collected during generation
+    pub fn take_warnings(&mut self) -> Vec<CompilerError> {
+        std::mem::take(&mut self.warnings)
+    }
 
-        // Generate runtime support if needed
-        if self.runtime_support {
-            rust_code.push_str(&self.generate_runtime_support());
-            rust_code.push('\n');
+    /// Generate Rust code from TypeScript program
+    pub fn generate(&mut self, program: &Program) -> Result<String> {
+        // Advisory semantic errors do not stop generation; they are reported as warnings
+        let semantic_errors = self.semantics.analyze_all(program);
+        self.warnings.extend(semantic_errors);
+        let fatal_errors = self.semantics.take_fatal_errors();
+        if !fatal_errors.is_empty() {
+            return Err(CompilerError::multiple(fatal_errors));
         }
 
-        // Process all statements
+        // Enums always derive PartialEq, so unions may compare them wherever they are declared
         for statement in &program.statements {
+            let statement = match statement {
+                Statement::ExportDeclaration(export) => &*export.declaration,
+                statement => statement,
+            };
             match statement {
-                Statement::VariableDeclaration(var) => {
-                    let var_code = self.generate_variable_declaration(var)?;
-                    self.functions.push(var_code);
-                }
-                Statement::FunctionDeclaration(func) => {
-                    let func_code = self.generate_function_declaration(func)?;
-                    self.functions.push(func_code);
+                Statement::EnumDeclaration(enum_decl) => {
+                    self.partial_eq_types.insert(enum_decl.name.clone());
                 }
-                Statement::ClassDeclaration(class) => {
-                    let (struct_code, impl_code) = self.generate_class_declaration(class)?;
-                    self.structs.push(struct_code);
-                    self.functions.push(impl_code);
+                Statement::ConstEnumDeclaration(enum_decl) => {
+                    self.const_enums.insert(enum_decl.name.clone());
                 }
+                _ => {}
+            }
+        }
+
+        // Record the properties of object-like types so mapped types can expand `keyof T`
+        for statement in &program.statements {
+            let statement = match statement {
+                Statement::ExportDeclaration(export) => &*export.declaration,
+                statement => statement,
+            };
+            let (name, members) = match statement {
                 Statement::InterfaceDeclaration(interface) => {
-                    let trait_code = self.generate_interface_declaration(interface)?;
-                    self.traits.push(trait_code);
-                }
-                Statement::TypeAlias(type_alias) => {
-                    let type_code = self.generate_type_alias_declaration(type_alias)?;
-                    self.structs.push(type_code);
-                }
-                Statement::EnumDeclaration(enum_decl) => {
-                    let enum_code = self.generate_enum_declaration(enum_decl)?;
-                    self.enums.push(enum_code);
-                }
-                Statement::ImportDeclaration(import) => {
-                    let import_code = self.generate_import_declaration(import)?;
-                    self.imports.push(import_code);
+                    self.interfaces.insert(interface.name.clone(), interface.clone());
+                    (&interface.name, &interface.body.members)
                 }
-                Statement::ExportDeclaration(export) => {
-                    // Exports are handled by making items public
-                    match &*export.declaration {
-                        Statement::ClassDeclaration(class) => {
-                            let (struct_code, impl_code) = self.generate_class_declaration(class)?;
-                            self.structs.push(struct_code);
-                            self.functions.push(impl_code);
-                        }
-                        Statement::InterfaceDeclaration(interface) => {
-                            let trait_code = self.generate_interface_declaration(interface)?;
-                            self.traits.push(trait_code);
-                        }
-                        Statement::FunctionDeclaration(func) => {
-                            let func_code = self.generate_function_declaration(func)?;
-                            self.functions.push(func_code);
-                        }
-                        Statement::TypeAlias(type_alias) => {
-                            let type_code = self.generate_type_alias_declaration(type_alias)?;
-                            self.structs.push(type_code);
-                        }
-                        Statement::EnumDeclaration(enum_decl) => {
-                            let enum_code = self.generate_enum_declaration(enum_decl)?;
-                            self.enums.push(enum_code);
-                        }
-                        _ => {
-                            // Handle other export types
-                        }
+                Statement::TypeAlias(type_alias) => match &type_alias.type_definition {
+                    Type::ObjectType(object) => (&type_alias.name, &object.members),
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            let properties = members
+                .iter()
+                .filter_map(|member| match member {
+                    ObjectTypeMember::Property(prop) => Some(prop.clone()),
+                    _ => None,
+                })
+                .collect();
+            self.object_types.insert(name.clone(), properties);
+        }
+
+        // Collect methods assigned through `Class.prototype.method = function() {}`
+        let class_names = declared_class_names(program);
+        for statement in &program.statements {
+            if let Statement::ExpressionStatement(expr_stmt) = statement {
+                if let Some((class, method)) = prototype_method(&expr_stmt.expression) {
+                    if class_names.contains(class) {
+                        self.prototype_methods
+                            .entry(class.to_string())
+                            .or_default()
+                            .push(method);
                     }
                 }
-                Statement::NamespaceDeclaration(namespace) => {
-                    let module_code = self.generate_namespace_declaration(namespace)?;
-                    self.modules.push(module_code);
-                }
-                Statement::ModuleDeclaration(module) => {
-                    let module_code = self.generate_module_declaration(module)?;
-                    self.modules.push(module_code);
-                }
-                Statement::ExpressionStatement(expr_stmt) => {
-                    let expr_code = self.generate_expression_statement(expr_stmt)?;
-                    self.functions.push(expr_code);
-                }
-                _ => {
-                    // Handle other statement types - log what we found
-                    println!("DEBUG: Unhandled statement type: {:?}", statement);
-                }
             }
         }
 
+        // Process all statements
+        for statement in &program.statements {
+            self.generate_item(statement, &class_names)?;
+        }
+
+        let mut rust_code = String::new();
+
+        // Generate runtime support if needed
+        if self.config.runtime {
+            rust_code.push_str(&self.generate_runtime_support());
+            rust_code.push('\n');
+        }
+
         // Combine all generated code in proper order
         rust_code.push_str(&self.structs.join("\n\n"));
         rust_code.push('\n');
@@ -137,50 +180,252 @@ impl CodeGenerator {
         rust_code.push('\n');
         rust_code.push_str(&self.modules.join("\n\n"));
 
-        // Add serde import if we have structs
-        if !self.structs.is_empty() {
-            rust_code.insert_str(0, "use serde::{Deserialize, Serialize};\n");
+        // Imports go last so they reflect everything the items use, including items inside modules
+        let imports = self.generate_imports();
+        if !imports.is_empty() {
+            rust_code.insert_str(0, &format!("{}\n\n", imports));
         }
 
         // Add main function if we have classes or functions
-        if !self.structs.is_empty() || !self.functions.is_empty() {
+        if self.config.emit_main && (!self.structs.is_empty() || !self.functions.is_empty()) {
             rust_code.push_str("\n\nfn main() {\n");
             rust_code.push_str("    // Example usage\n");
             rust_code.push_str("    println!(\"TypeScript to Rust compilation successful!\");\n");
             rust_code.push_str("}\n");
         }
 
+        if self.config.format {
+            rust_code = format_rust(&rust_code);
+        }
+
         Ok(rust_code)
     }
 
-    /// Generate imports
-    #[allow(dead_code)]
-    fn generate_imports(&self) -> String {
-        let mut imports = vec![
-            "use std::collections::HashMap;".to_string(),
-        ];
-        
-        // Only add serde if we have structs that need it
-        if !self.structs.is_empty() {
-            imports.push("use serde::{Deserialize, Serialize};".to_string());
+    /// Generate a top-level statement into the matching output section
+    fn generate_item(&mut self, statement: &Statement, class_names: &HashSet<String>) -> Result<()> {
+        match statement {
+            Statement::VariableDeclaration(var) => {
+                // `const Widget = class {}` declares a struct named after the binding
+                if let Some(class) = bound_class(var) {
+                    let (struct_code, impl_code) = self.generate_class_declaration(&class)?;
+                    self.structs.push(struct_code);
+                    self.functions.push(impl_code);
+                } else if let Some(func) = bound_function(var) {
+                    // Top-level function expressions become named functions
+                    let func_code = self.generate_function_declaration(&func)?;
+                    self.functions.push(func_code);
+                } else {
+                    let var_code = self.generate_variable_declaration(var)?;
+                    self.functions.push(var_code);
+                }
+            }
+            Statement::FunctionDeclaration(func) => {
+                let func_code = self.generate_function_declaration(func)?;
+                self.functions.push(func_code);
+            }
+            Statement::ClassDeclaration(class) => {
+                let (struct_code, impl_code) = self.generate_class_declaration(class)?;
+                self.structs.push(struct_code);
+                self.functions.push(impl_code);
+            }
+            Statement::InterfaceDeclaration(interface) => {
+                let trait_code = self.generate_interface_declaration(interface)?;
+                self.traits.push(trait_code);
+            }
+            Statement::TypeAlias(type_alias) => {
+                let type_code = self.generate_type_alias_declaration(type_alias)?;
+                self.structs.push(type_code);
+            }
+            Statement::EnumDeclaration(enum_decl) => {
+                let enum_code = self.generate_enum_declaration(enum_decl)?;
+                self.enums.push(enum_code);
+            }
+            Statement::ConstEnumDeclaration(enum_decl) => {
+                let const_code = self.generate_const_enum_declaration(enum_decl)?;
+                self.enums.push(const_code);
+            }
+            Statement::ImportDeclaration(import) => {
+                let import_code = self.generate_import_declaration(import)?;
+                self.imports.push(import_code);
+            }
+            Statement::ExportDeclaration(export) => {
+                // Exports are handled by making items public
+                match &*export.declaration {
+                    Statement::ClassDeclaration(class) => {
+                        let (struct_code, impl_code) = self.generate_class_declaration(class)?;
+                        self.structs.push(struct_code);
+                        self.functions.push(impl_code);
+                    }
+                    Statement::InterfaceDeclaration(interface) => {
+                        let trait_code = self.generate_interface_declaration(interface)?;
+                        self.traits.push(trait_code);
+                    }
+                    Statement::FunctionDeclaration(func) => {
+                        let func_code = self.generate_function_declaration(func)?;
+                        self.functions.push(func_code);
+                    }
+                    Statement::TypeAlias(type_alias) => {
+                        let type_code = self.generate_type_alias_declaration(type_alias)?;
+                        self.structs.push(type_code);
+                    }
+                    Statement::EnumDeclaration(enum_decl) => {
+                        let enum_code = self.generate_enum_declaration(enum_decl)?;
+                        self.enums.push(enum_code);
+                    }
+                    Statement::ConstEnumDeclaration(enum_decl) => {
+                        let const_code = self.generate_const_enum_declaration(enum_decl)?;
+                        self.enums.push(const_code);
+                    }
+                    Statement::VariableDeclaration(var)
+                        if bound_class(var).is_none() && bound_function(var).is_none() =>
+                    {
+                        let var_code = self.generate_exported_variable(var)?;
+                        self.functions.push(var_code);
+                    }
+                    declaration @ Statement::VariableDeclaration(_) => {
+                        self.generate_item(declaration, class_names)?;
+                    }
+                    _ => {
+                        // Handle other export types
+                    }
+                }
+            }
+            Statement::ExportDefaultDeclaration(export) => {
+                self.generate_export_default(export, class_names)?;
+            }
+            Statement::ExportNamedDeclaration(export) | Statement::ExportTypeDeclaration(export) => {
+                self.imports.extend(self.generate_export_named(export));
+            }
+            Statement::NamespaceDeclaration(namespace) => {
+                let module_code = self.generate_namespace_declaration(namespace)?;
+                self.modules.push(module_code);
+            }
+            Statement::ModuleDeclaration(module) => {
+                let module_code = self.generate_module_declaration(module)?;
+                self.modules.push(module_code);
+            }
+            Statement::DeclareStatement(declare) => {
+                self.generate_declare_statement(declare, class_names)?;
+            }
+            Statement::ExpressionStatement(expr_stmt) => {
+                // Prototype methods are folded into their class impl
+                if prototype_method(&expr_stmt.expression)
+                    .is_some_and(|(class, _)| class_names.contains(class))
+                {
+                    return Ok(());
+                }
+                let expr_code = self.generate_expression_statement(expr_stmt)?;
+                self.functions.push(expr_code);
+            }
+            _ => {
+                // Handle other statement types - log what we found
+                tracing::debug!("Unhandled statement type: {:?}", statement);
+            }
+        }
+        Ok(())
+    }
+
+    /// Generate stubs for an ambient declaration so other code can reference it
+    fn generate_declare_statement(
+        &mut self,
+        declare: &DeclareStatement,
+        class_names: &HashSet<String>,
+    ) -> Result<()> {
+        match &*declare.declaration {
+            Statement::FunctionDeclaration(func) => {
+                let params = self.generate_parameters(&func.parameters)?;
+                let return_type = match &func.return_type {
+                    Some(t) => format!(" -> {}", self.type_mapper.map_type(t)?),
+                    None => " -> ()".to_string(),
+                };
+                let (generic_params, where_clause) = self.generate_generics(&func.type_parameters)?;
+                self.functions.push(format!(
+                    "pub fn {}{}({}){}{}{{\n    unimplemented!()\n}}",
+                    self.type_mapper.rust_name(&func.name), generic_params, params, return_type, where_clause
+                ));
+            }
+            // An ambient value is defined elsewhere and a Rust static needs a value, so skip it
+            Statement::VariableDeclaration(var) => {
+                self.warnings.push(CompilerError::unsupported_feature(format!(
+                    "ambient variable '{}'; it is not generated",
+                    var.name
+                )));
+            }
+            // Bodyless class methods are already generated as `unimplemented!()`
+            declaration => self.generate_item(declaration, class_names)?,
         }
+        Ok(())
+    }
 
-        if self.runtime_support {
-            imports.push("use std::any::Any;".to_string());
-            imports.push("use std::boxed::Box;".to_string());
-            imports.push("use std::rc::Rc;".to_string());
-            imports.push("use std::sync::Arc;".to_string());
+    /// Generate a default export, reachable from other modules as `DefaultExport`
+    fn generate_export_default(
+        &mut self,
+        export: &ExportDefaultDeclaration,
+        class_names: &HashSet<String>,
+    ) -> Result<()> {
+        let exported = match export {
+            ExportDefaultDeclaration::Declaration(declaration) => {
+                self.generate_item(declaration, class_names)?;
+                declaration_name(declaration)
+            }
+            ExportDefaultDeclaration::Expression(Expression::Identifier(name)) => Some(name.as_str()),
+            ExportDefaultDeclaration::Expression(expression) => {
+                let var = VariableDeclaration {
+                    keyword: crate::lexer::Keyword::Const,
+                    name: DEFAULT_EXPORT.to_string(),
+                    type_annotation: None,
+                    initializer: Some(expression.clone()),
+                };
+                if bound_class(&var).is_some() || bound_function(&var).is_some() {
+                    // Anonymous classes and functions are declared under the default name
+                    self.generate_item(&Statement::VariableDeclaration(var), class_names)?;
+                } else {
+                    let constant = self
+                        .generate_variable_declaration(&var)?
+                        .replacen("let ", "pub const ", 1);
+                    self.functions
+                        .push(format!("#[allow(non_upper_case_globals)]\n{}", constant));
+                }
+                None
+            }
+        };
+
+        if let Some(name) = exported.filter(|name| *name != DEFAULT_EXPORT) {
+            self.imports.push(format!("pub use {} as {};", name, DEFAULT_EXPORT));
         }
+        Ok(())
+    }
 
-        imports.extend(self.imports.clone());
-        imports.join("\n")
+    /// Re-export renamed items with `pub use self::a as b;`
+    ///
+    /// Generated items are already `pub`, so same-name exports need no code.
+    fn generate_export_named(&self, export: &ExportNamedDeclaration) -> Vec<String> {
+        export
+            .specifiers
+            .iter()
+            .filter(|specifier| specifier.local != specifier.exported)
+            .map(|specifier| {
+                format!(
+                    "pub use self::{} as {};",
+                    self.type_mapper.rust_name(&specifier.local),
+                    self.type_mapper.rust_name(&specifier.exported)
+                )
+            })
+            .collect()
+    }
+
+    /// Generate the deduplicated, sorted imports used by the generated code
+    fn generate_imports(&self) -> String {
+        let mut imports: BTreeSet<&str> = self.imports.iter().flat_map(|import| import.lines()).collect();
+        imports.extend(self.type_mapper.imports());
+        imports.into_iter().collect::<Vec<_>>().join("\n")
     }
 
     /// Generate runtime support code
-    fn generate_runtime_support(&self) -> String {
-        r#"
+    fn generate_runtime_support(&mut self) -> String {
+        self.type_mapper.use_type("HashMap<String, Box<dyn Any>>");
+        let runtime = r#"
 // Runtime support for TypeScript semantics
-use std::any::Any;
 
 pub type AnyType = Box<dyn Any>;
 pub type UnknownType = Box<dyn Any>;
@@ -196,7 +441,7 @@ impl Symbol {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Union<T, U> {
     Left(T),
     Right(U),
@@ -232,42 +477,169 @@ impl TypeScriptObject for HashMap<String, Any> {
         self.remove(key).is_some()
     }
 }
-"#
-        .to_string()
+
+/// JavaScript `typeof` of a value
+pub fn get_type_of(value: &dyn Any) -> &'static str {
+    if let Some(inner) = value.downcast_ref::<Box<dyn Any>>() {
+        return get_type_of(inner.as_ref());
+    }
+    if value.is::<f64>() || value.is::<i32>() || value.is::<usize>() {
+        "number"
+    } else if value.is::<i64>() {
+        "bigint"
+    } else if value.is::<String>() || value.is::<&str>() {
+        "string"
+    } else if value.is::<bool>() {
+        "boolean"
+    } else if value.is::<Symbol>() {
+        "symbol"
+    } else if value.is::<()>() || value.is::<Option<()>>() {
+        "undefined"
+    } else {
+        "object"
+    }
+}
+"#;
+        let mut runtime = runtime.to_string();
+        // The runtime types are a fixed template, so drop their serde derives here
+        if self.type_mapper.serde_derives() {
+            self.type_mapper.derives(&[]);
+        } else {
+            runtime = runtime.replace(", Serialize, Deserialize", "");
+        }
+        // With integer numbers every TypeScript `number` is an `i64`
+        if self.config.number_as_i64 {
+            runtime = runtime.replace("value.is::<i64>() {\n        \"bigint\"", "value.is::<i64>() {\n        \"number\"");
+        }
+        runtime
     }
 
     /// Generate function declaration
     fn generate_function_declaration(&mut self, func: &FunctionDeclaration) -> Result<String> {
-        let name = &func.name;
+        let name = self.type_mapper.rust_name(&func.name);
         let params = self.generate_parameters(&func.parameters)?;
-        let return_type = if let Some(ref t) = func.return_type {
-            format!(" -> {}", self.type_mapper.map_type(t)?)
-        } else {
-            " -> ()".to_string()
+        if func.generator {
+            return self.generate_generator_function(func, &params);
+        }
+        let return_type = match func
+            .return_type
+            .clone()
+            .or_else(|| infer_return_type(&func.parameters, &func.body))
+        {
+            Some(t) => format!(" -> {}", self.type_mapper.map_type(&t)?),
+            None => " -> ()".to_string(),
         };
 
-        let body = self.generate_statement(&func.body)?;
+        let body = format!(
+            "{}{}",
+            self.generate_default_parameters(&func.parameters)?,
+            self.generate_statement(&func.body)?
+        );
 
         // Handle generic parameters
-        let generic_params = if func.type_parameters.is_empty() {
-            String::new()
-        } else {
-            let params: Vec<String> = func.type_parameters.iter().map(|p| p.name.clone()).collect();
-            format!("<{}>", params.join(", "))
+        let (generic_params, where_clause) = self.generate_generics(&func.type_parameters)?;
+
+        Ok(format!(
+            "pub fn {}{}({}){}{}{{\n    {}\n}}",
+            name, generic_params, params, return_type, where_clause, body
+        ))
+    }
+
+    /// Generate a `function*` that collects its yielded values into an iterator
+    fn generate_generator_function(&mut self, func: &FunctionDeclaration, params: &str) -> Result<String> {
+        let item_type = match func.return_type.as_ref().and_then(generator_item_type) {
+            Some(t) => self.type_mapper.map_type(t)?,
+            None => match first_yield_type(&func.body) {
+                Some(t) => self.type_mapper.map_type(&t)?,
+                None => self.type_mapper.use_type("Box<dyn Any>"),
+            },
         };
 
+        let was_generator = std::mem::replace(&mut self.in_generator, true);
+        let body = self.generate_statement(&func.body);
+        self.in_generator = was_generator;
+
+        let (generic_params, where_clause) = self.generate_generics(&func.type_parameters)?;
         Ok(format!(
-            "pub fn {}{}({}){}{{\n    {}\n}}",
-            name, generic_params, params, return_type, body
+            "pub fn {}{}({}) -> impl Iterator<Item = {}>{}{{\n    {}let mut yielded = Vec::new();\n    {}\n    yielded.into_iter()\n}}",
+            self.type_mapper.rust_name(&func.name),
+            generic_params,
+            params,
+            item_type,
+            where_clause,
+            self.generate_default_parameters(&func.parameters)?,
+            body?
         ))
     }
 
+    /// Generate generic parameters and a `where` clause for complex bounds
+    ///
+    /// A single trait bound stays inline as `<T: Bound>`; multiple bounds or
+    /// bounds with type arguments move into the `where` clause.
+    fn generate_generics(&mut self, type_parameters: &[TypeParameter]) -> Result<(String, String)> {
+        self.generate_generic_params(type_parameters, false)
+    }
+
+    /// Generate generics for a struct or trait declaration, where defaults like `<T = f64>` are allowed
+    fn generate_declaration_generics(&mut self, type_parameters: &[TypeParameter]) -> Result<(String, String)> {
+        self.generate_generic_params(type_parameters, true)
+    }
+
+    fn generate_generic_params(
+        &mut self,
+        type_parameters: &[TypeParameter],
+        with_defaults: bool,
+    ) -> Result<(String, String)> {
+        if type_parameters.is_empty() {
+            return Ok((String::new(), String::new()));
+        }
+
+        let mut params = Vec::new();
+        let mut predicates = Vec::new();
+        for param in type_parameters {
+            let mut bounds = Vec::new();
+            if let Some(ref constraint) = param.constraint {
+                collect_bounds(constraint, &mut bounds);
+            }
+            let mut param_code = match bounds.as_slice() {
+                [] => param.name.clone(),
+                [Type::Named(_)] => format!("{}: {}", param.name, self.type_mapper.map_type(bounds[0])?),
+                _ => {
+                    let bounds: Result<Vec<String>> =
+                        bounds.iter().map(|bound| self.type_mapper.map_type(bound)).collect();
+                    predicates.push(format!("    {}: {},", param.name, bounds?.join(" + ")));
+                    param.name.clone()
+                }
+            };
+            if let (true, Some(default)) = (with_defaults, &param.default) {
+                param_code.push_str(&format!(" = {}", self.type_mapper.map_type(default)?));
+            }
+            params.push(param_code);
+        }
+
+        let where_clause = if predicates.is_empty() {
+            String::new()
+        } else {
+            format!("\nwhere\n{}\n", predicates.join("\n"))
+        };
+        Ok((format!("<{}>", params.join(", ")), where_clause))
+    }
+
     /// Generate class declaration
     fn generate_class_declaration(&mut self, class: &ClassDeclaration) -> Result<(String, String)> {
         let name = &class.name;
         let mut fields = Vec::new();
         let mut methods = Vec::new();
         let mut has_constructor = false;
+        let properties: Vec<&PropertyDeclaration> = class
+            .body
+            .members
+            .iter()
+            .filter_map(|member| match member {
+                ClassMember::Property(prop) => Some(prop),
+                _ => None,
+            })
+            .collect();
 
         // Process class body
         for member in &class.body.members {
@@ -276,21 +648,31 @@ impl TypeScriptObject for HashMap<String, Any> {
                     let field_type = if let Some(ref t) = prop.type_ {
                         self.type_mapper.map_type(t)?
                     } else {
-                        "Box<dyn Any>".to_string()
+                        self.type_mapper.use_type("Box<dyn Any>")
                     };
 
-                    let field_name = &prop.name;
-                    let field_def = if prop.optional {
-                        format!("    pub {}: Option<{}>", field_name, field_type)
+                    let field_name = self.type_mapper.rust_name(&prop.name);
+                    let field_type = if prop.optional {
+                        format!("Option<{}>", field_type)
                     } else {
-                        format!("    pub {}: {}", field_name, field_type)
+                        field_type
                     };
 
+                    // Read-only fields are private and exposed through a getter
+                    let readonly = prop.modifiers.iter().any(|m| matches!(m, Modifier::Readonly));
+                    let visibility = if readonly { "" } else { "pub " };
+                    if readonly {
+                        methods.push(format!(
+                            "    pub fn {0}(&self) -> &{1} {{\n        &self.{0}\n    }}",
+                            field_name, field_type
+                        ));
+                    }
+
                     // Add initialization if there's an initializer
-                    let mut field_with_init = field_def;
+                    let mut field_with_init = format!("    {}{}: {}", visibility, field_name, field_type);
                     if let Some(ref initializer) = prop.initializer {
                         let init_value = self.generate_expression(initializer)?;
-                        field_with_init = format!("    pub {}: {} = {}", field_name, field_type, init_value);
+                        field_with_init = format!("{} = {}", field_with_init, init_value);
                     }
 
                     fields.push(field_with_init);
@@ -301,8 +683,16 @@ impl TypeScriptObject for HashMap<String, Any> {
                 }
                 ClassMember::Constructor(constructor) => {
                     has_constructor = true;
-                    let constructor_code = self.generate_constructor_declaration(constructor)?;
+                    let constructor_code = self.generate_constructor_declaration(constructor, &properties)?;
                     methods.push(constructor_code);
+
+                    // Parameter properties (`constructor(private x: number)`) declare fields
+                    for param in parameter_properties(&constructor.parameters) {
+                        if !properties.iter().any(|prop| prop.name == param.name) {
+                            let field_type = self.generate_member_parameter_type(param)?;
+                            fields.push(format!("    pub {}: {}", self.type_mapper.rust_name(&param.name), field_type));
+                        }
+                    }
                 }
                 ClassMember::Getter(getter) => {
                     let getter_code = self.generate_getter_declaration(getter)?;
@@ -313,8 +703,11 @@ impl TypeScriptObject for HashMap<String, Any> {
                     methods.push(setter_code);
                 }
                 ClassMember::Decorator(decorator) => {
-                    // For now, just add a comment about the decorator
-                    methods.push(format!("    // Decorator: {}", decorator));
+                    let description = self.describe_decorator(decorator)?;
+                    self.warnings.push(CompilerError::unsupported_feature(format!(
+                        "decorator @{} without a decorated member",
+                        description
+                    )));
                 }
                 _ => {
                     // Handle other member types
@@ -322,6 +715,12 @@ impl TypeScriptObject for HashMap<String, Any> {
             }
         }
 
+        // Add methods assigned through the prototype
+        for method in self.prototype_methods.remove(name).unwrap_or_default() {
+            let method_code = self.generate_method_declaration(&method)?;
+            methods.push(method_code);
+        }
+
         // Add default constructor if none exists
         if !has_constructor {
             let default_constructor = format!(
@@ -338,36 +737,230 @@ impl TypeScriptObject for HashMap<String, Any> {
         }
 
         // Handle generic parameters for class
-        let generic_params = if class.type_parameters.is_empty() {
+        let (generic_params, where_clause) = self.generate_generics(&class.type_parameters)?;
+        let type_arguments = if class.type_parameters.is_empty() {
             String::new()
         } else {
             let params: Vec<String> = class.type_parameters.iter().map(|p| p.name.clone()).collect();
             format!("<{}>", params.join(", "))
         };
+        let separator = if where_clause.is_empty() { " " } else { "" };
+        let (declaration_params, _) = self.generate_declaration_generics(&class.type_parameters)?;
+
+        let mut derives = self.type_mapper.derives(&["Debug", "Clone"]);
+        let attributes = self.apply_decorators(&class.decorators, &mut derives)?;
 
         let struct_code = format!(
-            "#[derive(Debug, Clone, Serialize, Deserialize)]\npub struct {}{} {{\n{}\n}}",
-            name, generic_params,
+            "{}#[derive({})]\n{} struct {}{}{}{}{{\n{}\n}}",
+            attributes, derives.join(", "), self.type_mapper.struct_visibility(), name, declaration_params,
+            where_clause, separator,
             fields.join(",\n")
         );
 
-        let impl_code = format!("impl {}{} {{\n{}\n}}", generic_params, name, methods.join("\n\n"));
+        let mut impl_code = format!(
+            "impl{} {}{}{}{}{{\n{}\n}}",
+            generic_params, name, type_arguments, where_clause, separator, methods.join("\n\n")
+        );
+
+        // Implemented interfaces get a trait impl alongside the inherent one
+        let self_type = format!("{}{}", name, type_arguments);
+        for implemented in &class.implements {
+            if let Some(trait_impl) =
+                self.generate_trait_impl(class, implemented, &generic_params, &self_type, &where_clause)?
+            {
+                impl_code.push_str("\n\n");
+                impl_code.push_str(&trait_impl);
+            }
+        }
 
         Ok((struct_code, impl_code))
     }
 
+    /// Implement an interface's trait for a class by forwarding to its fields and methods
+    fn generate_trait_impl(
+        &mut self,
+        class: &ClassDeclaration,
+        implemented: &Type,
+        generic_params: &str,
+        self_type: &str,
+        where_clause: &str,
+    ) -> Result<Option<String>> {
+        let (trait_name, type_arguments) = match implemented {
+            Type::Named(name) => (name, &[][..]),
+            Type::GenericNamed {
+                name,
+                type_arguments,
+            } => (name, type_arguments.as_slice()),
+            _ => return Ok(None),
+        };
+        let Some(interface) = self.interfaces.get(trait_name).cloned() else {
+            return Ok(None);
+        };
+
+        // The interface's type parameters stand for the arguments given in `implements`
+        let bindings: HashMap<&str, &Type> = interface
+            .type_parameters
+            .iter()
+            .map(|param| param.name.as_str())
+            .zip(type_arguments)
+            .collect();
+        // Class fields by name, with whether each holds an `Option`
+        let mut fields = HashMap::new();
+        let mut class_methods = HashSet::new();
+        for member in &class.body.members {
+            match member {
+                ClassMember::Property(prop) => {
+                    fields.insert(prop.name.as_str(), prop.optional);
+                }
+                ClassMember::Method(method) => {
+                    class_methods.insert(method.name.as_str());
+                }
+                ClassMember::Constructor(constructor) => {
+                    fields.extend(
+                        parameter_properties(&constructor.parameters).map(|p| (p.name.as_str(), p.optional)),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        let mut methods = Vec::new();
+        for member in &interface.body.members {
+            match member {
+                ObjectTypeMember::Property(prop) if prop.optional => {
+                    let prop_type = match &prop.type_ {
+                        Some(t) => self.type_mapper.map_type(&substitute_type_parameters(t, &bindings))?,
+                        None => self.type_mapper.use_type("Box<dyn Any>"),
+                    };
+                    let field = self.type_mapper.rust_name(&prop.name);
+                    let field_optional = fields.get(prop.name.as_str()).copied();
+                    // Without a field the getter keeps the trait's default body
+                    if let Some(optional) = field_optional {
+                        let getter = if optional {
+                            format!("self.{}.clone()", field)
+                        } else {
+                            format!("Some(self.{}.clone())", field)
+                        };
+                        methods.push(format!(
+                            "    fn {}(&self) -> Option<{}> {{\n        {}\n    }}",
+                            self.type_mapper.rust_name(&format!("get_{}", prop.name)),
+                            prop_type,
+                            getter
+                        ));
+                    }
+                    if !prop.readonly {
+                        let setter = match field_optional {
+                            Some(true) => format!("self.{} = value;", field),
+                            Some(false) => format!("if let Some(value) = value {{\n            self.{} = value;\n        }}", field),
+                            None => "unimplemented!()".to_string(),
+                        };
+                        methods.push(format!(
+                            "    fn {}(&mut self, value: Option<{}>) {{\n        {}\n    }}",
+                            self.type_mapper.rust_name(&format!("set_{}", prop.name)),
+                            prop_type,
+                            setter
+                        ));
+                    }
+                }
+                ObjectTypeMember::Property(prop) => {
+                    let prop_type = match &prop.type_ {
+                        Some(t) => self.type_mapper.map_type(&substitute_type_parameters(t, &bindings))?,
+                        None => self.type_mapper.use_type("Box<dyn Any>"),
+                    };
+                    let has_field = fields.contains_key(prop.name.as_str());
+                    let field = self.type_mapper.rust_name(&prop.name);
+                    let getter = if has_field {
+                        format!("self.{}.clone()", field)
+                    } else {
+                        "unimplemented!()".to_string()
+                    };
+                    methods.push(format!(
+                        "    fn {}(&self) -> {} {{\n        {}\n    }}",
+                        self.type_mapper.rust_name(&format!("get_{}", prop.name)),
+                        prop_type,
+                        getter
+                    ));
+                    if !prop.readonly {
+                        let setter = if has_field {
+                            format!("self.{} = value;", field)
+                        } else {
+                            "unimplemented!()".to_string()
+                        };
+                        methods.push(format!(
+                            "    fn {}(&mut self, value: {}) {{\n        {}\n    }}",
+                            self.type_mapper.rust_name(&format!("set_{}", prop.name)),
+                            prop_type,
+                            setter
+                        ));
+                    }
+                }
+                // Optional methods keep the trait's default bodies
+                ObjectTypeMember::Method(method) if !method.optional => {
+                    let parameters: Vec<Parameter> = method
+                        .parameters
+                        .iter()
+                        .map(|param| Parameter {
+                            type_: param
+                                .type_
+                                .as_ref()
+                                .map(|t| Box::new(substitute_type_parameters(t, &bindings))),
+                            ..param.clone()
+                        })
+                        .collect();
+                    let params = self.generate_parameters(&parameters)?;
+                    let receiver = if params.is_empty() {
+                        "&self".to_string()
+                    } else {
+                        format!("&self, {}", params)
+                    };
+                    let return_type = match &method.return_type {
+                        Some(t) => self.type_mapper.map_type(&substitute_type_parameters(t, &bindings))?,
+                        None => "()".to_string(),
+                    };
+                    let name = self.type_mapper.rust_name(&method.name);
+                    let body = if class_methods.contains(method.name.as_str()) {
+                        let arguments: Vec<String> = std::iter::once("self".to_string())
+                            .chain(parameters.iter().map(|param| self.type_mapper.rust_name(&param.name)))
+                            .collect();
+                        format!("Self::{}({})", name, arguments.join(", "))
+                    } else {
+                        "unimplemented!()".to_string()
+                    };
+                    methods.push(format!(
+                        "    fn {}({}) -> {} {{\n        {}\n    }}",
+                        name, receiver, return_type, body
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        let separator = if where_clause.is_empty() { " " } else { "" };
+        Ok(Some(format!(
+            "impl{} {} for {}{}{}{{\n{}\n}}",
+            generic_params,
+            self.type_mapper.map_type(implemented)?,
+            self_type,
+            where_clause,
+            separator,
+            methods.join("\n\n")
+        )))
+    }
+
     /// Generate interface declaration as trait
     fn generate_interface_declaration(&mut self, interface: &InterfaceDeclaration) -> Result<String> {
         let name = &interface.name;
         let mut methods = Vec::new();
 
+        // Extended interfaces become supertraits
+        let mut supertraits = interface
+            .extends
+            .iter()
+            .map(|extended| self.type_mapper.map_type(extended))
+            .collect::<Result<Vec<_>>>()?;
+
         // Handle generic parameters
-        let generic_params = if interface.type_parameters.is_empty() {
-            String::new()
-        } else {
-            let params: Vec<String> = interface.type_parameters.iter().map(|p| p.name.clone()).collect();
-            format!("<{}>", params.join(", "))
-        };
+        let (generic_params, where_clause) = self.generate_declaration_generics(&interface.type_parameters)?;
 
         for member in &interface.body.members {
             match member {
@@ -375,24 +968,58 @@ impl TypeScriptObject for HashMap<String, Any> {
                     let prop_type = if let Some(ref t) = prop.type_ {
                         self.type_mapper.map_type(t)?
                     } else {
-                        "Box<dyn Any>".to_string()
+                        self.type_mapper.use_type("Box<dyn Any>")
                     };
 
-                    // Add getter and setter methods
-                    methods.push(format!("    fn get_{}(&self) -> {};", prop.name, prop_type));
-                    if !prop.readonly {
-                        methods.push(format!("    fn set_{}(&mut self, value: {});", prop.name, prop_type));
+                    // Add getter and setter methods; optional properties read as absent by default,
+                    // but setting one can't be a no-op, so the setter stays required
+                    let getter = self.type_mapper.rust_name(&format!("get_{}", prop.name));
+                    let setter = self.type_mapper.rust_name(&format!("set_{}", prop.name));
+                    if prop.optional {
+                        methods.push(format!(
+                            "    fn {}(&self) -> Option<{}> {{\n        None\n    }}",
+                            getter, prop_type
+                        ));
+                        if !prop.readonly {
+                            methods.push(format!("    fn {}(&mut self, value: Option<{}>);", setter, prop_type));
+                        }
+                    } else {
+                        methods.push(format!("    fn {}(&self) -> {};", getter, prop_type));
+                        if !prop.readonly {
+                            methods.push(format!("    fn {}(&mut self, value: {});", setter, prop_type));
+                        }
                     }
                 }
                 ObjectTypeMember::Method(method) => {
                     let params = self.generate_parameters(&method.parameters)?;
-                    let return_type = if let Some(ref t) = method.return_type {
-                        format!(" -> {}", self.type_mapper.map_type(t)?)
+                    let receiver = if params.is_empty() {
+                        "&self".to_string()
                     } else {
-                        " -> ()".to_string()
+                        format!("&self, {}", params)
                     };
 
-                    let method_sig = format!("    fn {}(&self, {}){};", method.name, params, return_type);
+                    // Optional methods get a default body so implementors may omit them
+                    let return_type = match &method.return_type {
+                        Some(Type::Void) if method.optional => None,
+                        return_type => return_type.as_ref(),
+                    };
+                    let name = self.type_mapper.rust_name(&method.name);
+                    let method_sig = match (return_type, method.optional) {
+                        (Some(t), true) => format!(
+                            "    fn {}({}) -> Option<{}> {{\n        None\n    }}",
+                            name,
+                            receiver,
+                            self.type_mapper.map_type(t)?
+                        ),
+                        (None, true) => format!("    fn {}({}) {{}}", name, receiver),
+                        (Some(t), false) => format!(
+                            "    fn {}({}) -> {};",
+                            name,
+                            receiver,
+                            self.type_mapper.map_type(t)?
+                        ),
+                        (None, false) => format!("    fn {}({}) -> ();", name, receiver),
+                    };
                     methods.push(method_sig);
                 }
                 ObjectTypeMember::Call(call) => {
@@ -415,9 +1042,11 @@ impl TypeScriptObject for HashMap<String, Any> {
                     )?;
                     let value_type = self.type_mapper.map_type(&index.type_)?;
 
-                    methods.push(format!("    fn index_get(&self, key: {}) -> {};", key_type, value_type));
+                    // Implementors support `value[key]` through the std indexing traits
+                    let key = index_key_type(&key_type, "&'a ");
+                    supertraits.push(format!("for<'a> std::ops::Index<{}, Output = {}>", key, value_type));
                     if !index.readonly {
-                        methods.push(format!("    fn index_set(&mut self, key: {}, value: {});", key_type, value_type));
+                        supertraits.push(format!("for<'a> std::ops::IndexMut<{}>", key));
                     }
                 }
                 ObjectTypeMember::Construct(construct) => {
@@ -433,117 +1062,497 @@ impl TypeScriptObject for HashMap<String, Any> {
             }
         }
 
-        Ok(format!("pub trait {}{} {{\n{}\n}}", name, generic_params, methods.join("\n")))
+        let separator = if where_clause.is_empty() { " " } else { "" };
+        let bounds = if supertraits.is_empty() {
+            String::new()
+        } else {
+            format!(": {}", supertraits.join(" + "))
+        };
+        Ok(format!(
+            "pub trait {}{}{}{}{}{{\n{}\n}}",
+            name, generic_params, bounds, where_clause, separator, methods.join("\n")
+        ))
     }
 
     /// Generate type alias declaration
     fn generate_type_alias_declaration(&mut self, type_alias: &TypeAlias) -> Result<String> {
         let name = &type_alias.name;
-        let type_def = self.type_mapper.map_type(&type_alias.type_definition)?;
-        Ok(format!("pub type {} = {};", name, type_def))
-    }
+        let definition = self.resolve_utility_type(&type_alias.type_definition)?;
+
+        // Unions of named and primitive types become a named enum
+        if let Type::Union { .. } = definition {
+            let mut members = Vec::new();
+            flatten_union(&definition, &mut members);
+            if members.iter().all(|member| matches!(member, Type::Literal(_))) {
+                return Ok(self.generate_literal_enum(name, &members));
+            }
+            if members.iter().all(|member| union_variant_name(member).is_some()) {
+                return self.generate_union_enum(name, &members);
+            }
+        }
 
-    /// Generate intersection struct for object types
-    #[allow(dead_code)]
-    fn generate_intersection_struct(&mut self, name: &str, left: &Type, right: &Type) -> Result<String> {
-        let mut fields = Vec::new();
-        
-        // Extract fields from left object type
-        if let Type::ObjectType(left_obj) = left {
-            for member in &left_obj.members {
-                if let ObjectTypeMember::Property(prop) = member {
-                    let field_type = if let Some(ref t) = prop.type_ {
-                        self.type_mapper.map_type(t)?
-                    } else {
-                        "Box<dyn Any>".to_string()
-                    };
-                    fields.push(format!("    pub {}: {}", prop.name, field_type));
-                }
+        // Intersections of object types become one struct with every member's properties
+        if let Type::Intersection { .. } = definition {
+            if let Some(properties) = self.intersection_properties(&definition) {
+                return self.generate_intersection_struct(name, &properties);
             }
         }
-        
-        // Extract fields from right object type
-        if let Type::ObjectType(right_obj) = right {
-            for member in &right_obj.members {
-                if let ObjectTypeMember::Property(prop) = member {
-                    let field_type = if let Some(ref t) = prop.type_ {
-                        self.type_mapper.map_type(t)?
-                    } else {
-                        "Box<dyn Any>".to_string()
-                    };
-                    fields.push(format!("    pub {}: {}", prop.name, field_type));
+
+        // Object types become a struct of their properties and a trait of their methods
+        if let Type::ObjectType(object) = &definition {
+            let definition = self.type_mapper.map_object_type_definition(name, object)?;
+            return Ok(match definition.impl_code {
+                Some(impl_code) => format!("{}\n\n{}", definition.struct_code, impl_code),
+                None => definition.struct_code,
+            });
+        }
+
+        // `{ [K in keyof T]: V }` over a known T becomes a struct with T's keys
+        if let Type::Mapped(mapped) = &definition {
+            if let Some(properties) = self.mapped_properties(mapped) {
+                return self.generate_mapped_struct(name, mapped, &properties);
+            }
+        }
+
+        let type_def = self.type_mapper.map_type(&definition)?;
+        Ok(format!("pub type {} = {};", name, type_def))
+    }
+
+    /// Resolve `Exclude`, `Extract` and `NonNullable` over unions into the remaining union
+    ///
+    /// Other types are returned unchanged.
+    fn resolve_utility_type(&self, t: &Type) -> Result<Type> {
+        let Type::GenericNamed { name, type_arguments } = t else {
+            return Ok(t.clone());
+        };
+        let members = match (name.as_str(), type_arguments.as_slice()) {
+            ("Exclude" | "Extract", [source, filter]) => {
+                let filter = self.union_members(filter)?;
+                let keep = name == "Extract";
+                self.union_members(source)?
+                    .into_iter()
+                    .filter(|member| filter.contains(member) == keep)
+                    .collect()
+            }
+            ("NonNullable", [source]) => self
+                .union_members(source)?
+                .into_iter()
+                .filter(|member| !matches!(member, Type::Null | Type::Undefined))
+                .collect(),
+            _ => return Ok(t.clone()),
+        };
+        Ok(union_of(members).unwrap_or(Type::Never))
+    }
+
+    /// Members of a union, expanding type aliases and nested utility types
+    fn union_members(&self, t: &Type) -> Result<Vec<Type>> {
+        match t {
+            Type::Union { left, right } => {
+                let mut members = self.union_members(left)?;
+                members.extend(self.union_members(right)?);
+                Ok(members)
+            }
+            Type::Parenthesized(inner) => self.union_members(inner),
+            Type::GenericNamed { .. } => match self.resolve_utility_type(t)? {
+                Type::GenericNamed { name, .. } => Err(CompilerError::generation_error(format!(
+                    "Cannot resolve {} as a union",
+                    name
+                ))),
+                resolved => self.union_members(&resolved),
+            },
+            Type::Named(name) => match self.semantics.get_symbol(name).map(|symbol| &symbol.symbol_type) {
+                Some(SymbolType::Type(definition)) => self.union_members(definition),
+                Some(_) => Ok(vec![t.clone()]),
+                None => Err(CompilerError::generation_error(format!(
+                    "Cannot resolve type '{}' as a union",
+                    name
+                ))),
+            },
+            Type::Never => Ok(Vec::new()),
+            _ => Ok(vec![t.clone()]),
+        }
+    }
+
+    /// Properties of the known type a mapped type iterates with `keyof`
+    fn mapped_properties(&self, mapped: &MappedType) -> Option<Vec<PropertySignature>> {
+        self.object_types.get(keyof_target(mapped)?).cloned()
+    }
+
+    /// Generate a struct with one field per key of a mapped type
+    ///
+    /// `?` and `readonly` modifiers add or remove those flags; without one the key keeps
+    /// its flags from the source type. Read-only fields are private and get a getter.
+    fn generate_mapped_struct(
+        &mut self,
+        name: &str,
+        mapped: &MappedType,
+        properties: &[PropertySignature],
+    ) -> Result<String> {
+        let mut fields = Vec::new();
+        let mut getters = Vec::new();
+        for prop in properties {
+            // `T[K]` is the key's own type in T
+            let value_type = match mapped.type_.as_ref() {
+                Type::IndexedAccess { object, index }
+                    if type_name(object) == keyof_target(mapped)
+                        && type_name(index) == Some(mapped.type_parameter.name.as_str()) =>
+                {
+                    prop.type_.clone().unwrap_or(Type::Any)
                 }
+                value_type => value_type.clone(),
+            };
+            let mut field_type = self.type_mapper.map_type(&value_type)?;
+            if mapped.optional.unwrap_or(prop.optional) {
+                field_type = format!("Option<{}>", field_type);
+            }
+
+            let field = self.type_mapper.rust_name(&prop.name);
+            if mapped.readonly.unwrap_or(prop.readonly) {
+                fields.push(format!("    {}: {}", field, field_type));
+                getters.push(format!(
+                    "    pub fn {0}(&self) -> &{1} {{\n        &self.{0}\n    }}",
+                    field, field_type
+                ));
+            } else {
+                fields.push(format!("    pub {}: {}", field, field_type));
             }
         }
-        
-        Ok(format!(
-            "#[derive(Debug, Clone, Serialize, Deserialize)]\npub struct {} {{\n{}\n}}",
+
+        let mut code = format!(
+            "#[derive({})]\n{} struct {} {{\n{}\n}}",
+            self.type_mapper.derives(&["Debug", "Clone"]).join(", "),
+            self.type_mapper.struct_visibility(),
             name,
             fields.join(",\n")
-        ))
+        );
+        if !getters.is_empty() {
+            code.push_str(&format!("\n\nimpl {} {{\n{}\n}}", name, getters.join("\n\n")));
+        }
+        Ok(code)
     }
 
-    /// Generate constructor declaration
-    fn generate_constructor_declaration(&mut self, constructor: &ConstructorDeclaration) -> Result<String> {
-        let mut params = Vec::new();
-        for param in &constructor.parameters {
-            let param_type = if let Some(ref t) = param.type_ {
-                self.type_mapper.map_type(t)?
-            } else {
-                "Box<dyn Any>".to_string()
+    /// Generate a named enum with one variant per union member
+    fn generate_union_enum(&mut self, name: &str, members: &[&Type]) -> Result<String> {
+        // (variant name, payload type)
+        let mut variants: Vec<(String, Option<String>)> = Vec::new();
+        for member in members {
+            let variant = union_variant_name(member).unwrap_or_default();
+            if variants.iter().any(|(existing, _)| *existing == variant) {
+                continue;
+            }
+            let payload = match member {
+                Type::Null | Type::Undefined => None,
+                _ => Some(self.type_mapper.map_type(member)?),
             };
-            let param_name = &param.name;
-            params.push(format!("{}: {}", param_name, param_type));
+            variants.push((variant, payload));
         }
-        
-        let _body = if let Some(ref body) = constructor.body {
-            self.generate_statement(body)?
+
+        let body = variants
+            .iter()
+            .map(|(variant, payload)| match payload {
+                Some(payload) => format!("    {}({})", variant, payload),
+                None => format!("    {}", variant),
+            })
+            .collect::<Vec<_>>()
+            .join(",\n");
+
+        // Derive PartialEq only when every payload supports it
+        let incomparable = variants
+            .iter()
+            .filter_map(|(_, payload)| payload.as_ref())
+            .find(|payload| !self.supports_partial_eq(payload));
+        let mut code = if let Some(payload) = incomparable {
+            self.warnings.push(CompilerError::unsupported_feature(format!(
+                "comparing {} values; its {} payload has no PartialEq",
+                name, payload
+            )));
+            format!(
+                "#[derive({})]\npub enum {} {{\n{}\n}}",
+                self.type_mapper.derives(&["Debug", "Clone"]).join(", "),
+                name, body
+            )
         } else {
-            "// Empty constructor".to_string()
+            self.partial_eq_types.insert(name.to_string());
+            format!(
+                "#[derive({})]\npub enum {} {{\n{}\n}}",
+                self.type_mapper.derives(&["Debug", "Clone", "PartialEq"]).join(", "),
+                name, body
+            )
         };
-        
-        // Generate struct initialization based on constructor body
-        let mut field_assignments = Vec::new();
-        
-        // Check if constructor has assignment statements
-        if let Some(ref body) = constructor.body {
-            if let Statement::BlockStatement(block) = body {
-                for stmt in &block.statements {
-                    if let Statement::ExpressionStatement(expr_stmt) = stmt {
-                        if let Expression::Assignment(assignment) = &expr_stmt.expression {
-                            if let Expression::Member(member) = &*assignment.left {
-                                if let Expression::This(_) = &*member.object {
-                                    if let Expression::Identifier(field_name) = &*member.property {
-                                        let init_value = self.generate_expression(&assignment.right)?;
-                                        field_assignments.push(format!("            {}: {}", field_name, init_value));
-                                    }
-                                }
-                            }
-                        }
-                    }
+
+        // Each payload converts into its variant, unless two variants share a payload type
+        for (variant, payload) in &variants {
+            let Some(payload) = payload else {
+                continue;
+            };
+            if variants.iter().filter(|(_, other)| other.as_ref() == Some(payload)).count() > 1 {
+                continue;
+            }
+            code.push_str(&format!(
+                "\n\nimpl From<{1}> for {0} {{\n    fn from(value: {1}) -> Self {{\n        Self::{2}(value)\n    }}\n}}",
+                name, payload, variant
+            ));
+        }
+        Ok(code)
+    }
+
+    /// Generate a fieldless enum for a union of literal types
+    ///
+    /// Variants are renamed to their literal so serde keeps the original values.
+    fn generate_literal_enum(&mut self, name: &str, members: &[&Type]) -> String {
+        let mut values: Vec<String> = Vec::new();
+        let mut variants: Vec<String> = Vec::new();
+        for member in members {
+            let Type::Literal(literal) = member else {
+                continue;
+            };
+            let value = match literal {
+                Literal::String(value) => value.clone(),
+                Literal::Number(value) | Literal::RadixNumber(value, _) => value.to_string(),
+                Literal::Boolean(value) => value.to_string(),
+                Literal::BigInt(digits) => digits.clone(),
+                _ => continue,
+            };
+            if values.contains(&value) {
+                continue;
+            }
+            let mut variant = literal_variant_name(&value);
+            while variants.contains(&variant) {
+                variant.push('_');
+            }
+            values.push(value);
+            variants.push(variant);
+        }
+
+        let body = values
+            .iter()
+            .zip(&variants)
+            .map(|(value, variant)| {
+                if self.type_mapper.serde_derives() {
+                    format!("    #[serde(rename = {:?})]\n    {}", value, variant)
+                } else {
+                    format!("    {}", variant)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",\n");
+
+        self.partial_eq_types.insert(name.to_string());
+        format!(
+            "#[derive({})]\npub enum {} {{\n{}\n}}",
+            self.type_mapper.derives(&["Debug", "Clone", "Copy", "PartialEq", "Eq"]).join(", "),
+            name, body
+        )
+    }
+
+    /// Whether a generated Rust type is known to implement PartialEq
+    fn supports_partial_eq(&self, rust_type: &str) -> bool {
+        if let Some(inner) = rust_type
+            .strip_prefix("Vec<")
+            .or_else(|| rust_type.strip_prefix("Option<"))
+            .and_then(|rest| rest.strip_suffix('>'))
+        {
+            return self.supports_partial_eq(inner);
+        }
+        matches!(rust_type, "f64" | "i64" | "String" | "bool" | "()")
+            || self.partial_eq_types.contains(rust_type)
+    }
+
+    /// Properties of an intersection whose members are all object types
+    fn intersection_properties(&self, intersection: &Type) -> Option<Vec<PropertySignature>> {
+        let mut members = Vec::new();
+        collect_intersection_members(intersection, &mut members);
+        let mut properties: Vec<PropertySignature> = Vec::new();
+        for member in members {
+            let member_properties = match member {
+                Type::ObjectType(object) => object_properties(object),
+                Type::Named(name) => self.object_types.get(name)?.clone(),
+                _ => return None,
+            };
+            // A later member redeclaring a property keeps the first field
+            for prop in member_properties {
+                if !properties.iter().any(|existing| existing.name == prop.name) {
+                    properties.push(prop);
                 }
             }
         }
-        
-        // If no assignments found, use property initializers
-        if field_assignments.is_empty() {
-            // This will be handled by the default constructor generation
-            field_assignments.push("            // No explicit initializers found".to_string());
+        Some(properties)
+    }
+
+    /// Generate intersection struct for object types
+    fn generate_intersection_struct(&mut self, name: &str, properties: &[PropertySignature]) -> Result<String> {
+        let mut fields = Vec::new();
+        for prop in properties {
+            let mut field_type = if let Some(ref t) = prop.type_ {
+                self.type_mapper.map_type(t)?
+            } else {
+                self.type_mapper.use_type("Box<dyn Any>")
+            };
+            if prop.optional {
+                field_type = format!("Option<{}>", field_type);
+            }
+            fields.push(format!("    pub {}: {}", self.type_mapper.rust_name(&prop.name), field_type));
         }
-        
+
+        Ok(format!(
+            "#[derive({})]\n{} struct {} {{\n{}\n}}",
+            self.type_mapper.derives(&["Debug", "Clone"]).join(", "),
+            self.type_mapper.struct_visibility(),
+            name,
+            fields.join(",\n")
+        ))
+    }
+
+    /// Generate constructor declaration
+    fn generate_constructor_declaration(
+        &mut self,
+        constructor: &ConstructorDeclaration,
+        properties: &[&PropertyDeclaration],
+    ) -> Result<String> {
+        let params = self.generate_member_parameters(&constructor.parameters)?;
+
+        // Collect `this.field = value` assignments from the constructor body
+        let mut assignments = Vec::new();
+        if let Some(ref body) = constructor.body {
+            self.collect_field_assignments(body, &mut assignments)?;
+        }
+
+        // Every field gets a value: assigned, a parameter property, its initializer or a default
+        let mut field_assignments = Vec::new();
+        let mut initialized = HashSet::new();
+        for prop in properties {
+            let value = match assignments.iter().find(|(field, _)| field == &prop.name) {
+                Some((_, value)) => value.clone(),
+                None => match prop.initializer {
+                    Some(ref initializer) => self.generate_expression(initializer)?,
+                    None => "Default::default()".to_string(),
+                },
+            };
+            initialized.insert(prop.name.clone());
+            field_assignments.push(field_initializer(&self.type_mapper.rust_name(&prop.name), &value));
+        }
+        for param in parameter_properties(&constructor.parameters) {
+            if initialized.insert(param.name.clone()) {
+                let value = match assignments.iter().find(|(field, _)| field == &param.name) {
+                    Some((_, value)) => value.clone(),
+                    None => self.type_mapper.rust_name(&param.name),
+                };
+                field_assignments.push(field_initializer(&self.type_mapper.rust_name(&param.name), &value));
+            }
+        }
+        for (field, value) in &assignments {
+            if initialized.insert(field.clone()) {
+                field_assignments.push(field_initializer(&self.type_mapper.rust_name(field), value));
+            }
+        }
+
         let initialization = if field_assignments.is_empty() {
             "        Self {}".to_string()
         } else {
             format!("        Self {{\n{}\n        }}", field_assignments.join(",\n"))
         };
-        
-        let decorators_str = if constructor.decorators.is_empty() {
-            String::new()
+
+        let decorators_str = self.generate_member_decorators(&constructor.decorators)?;
+
+        Ok(format!("{}{}    pub fn new({}) -> Self {{\n{}\n    }}", decorators_str, "    ", params, initialization))
+    }
+
+    /// Collect field assignments that always run in a constructor body
+    ///
+    /// Assignments in nested blocks are included; an `if`/`else` that assigns the
+    /// same field in both branches becomes an `if` expression.
+    fn collect_field_assignments(
+        &mut self,
+        statement: &Statement,
+        assignments: &mut Vec<(String, String)>,
+    ) -> Result<()> {
+        match statement {
+            Statement::BlockStatement(block) => {
+                for stmt in &block.statements {
+                    self.collect_field_assignments(stmt, assignments)?;
+                }
+            }
+            Statement::ExpressionStatement(expr_stmt) => {
+                if let Expression::Assignment(assignment) = &expr_stmt.expression {
+                    if let Some(field) = this_field(&assignment.left) {
+                        let value = self.generate_expression(&assignment.right)?;
+                        assignments.retain(|(name, _)| name != field);
+                        assignments.push((field.to_string(), value));
+                    }
+                }
+            }
+            Statement::IfStatement(if_stmt) => {
+                let Some(ref alternate) = if_stmt.alternate else {
+                    return Ok(());
+                };
+                let mut consequent = Vec::new();
+                self.collect_field_assignments(&if_stmt.consequent, &mut consequent)?;
+                let mut otherwise = Vec::new();
+                self.collect_field_assignments(alternate, &mut otherwise)?;
+
+                let condition = self.generate_expression(&if_stmt.condition)?;
+                for (field, then_value) in consequent {
+                    if let Some((_, else_value)) = otherwise.iter().find(|(name, _)| name == &field) {
+                        let value = format!("if {} {{ {} }} else {{ {} }}", condition, then_value, else_value);
+                        assignments.retain(|(name, _)| name != &field);
+                        assignments.push((field, value));
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Rust type of a parameter, wrapping optional parameters in `Option`
+    fn generate_parameter_type(&mut self, param: &Parameter) -> Result<String> {
+        let param_type = self.parameter_value_type(param)?;
+        // Rust has no default arguments, so defaulted parameters are optional
+        if param.optional || param.initializer.is_some() {
+            Ok(format!("Option<{}>", param_type))
         } else {
-            format!("    // Decorators: {}\n", constructor.decorators.join(", "))
-        };
+            Ok(param_type)
+        }
+    }
+
+    /// Rust type of a method or constructor parameter
+    ///
+    /// Calls on an instance can't be matched to their class, so their arguments
+    /// can't be wrapped in `Some`; defaulted parameters stay required instead.
+    fn generate_member_parameter_type(&mut self, param: &Parameter) -> Result<String> {
+        if param.optional || param.initializer.is_none() {
+            self.generate_parameter_type(param)
+        } else {
+            self.parameter_value_type(param)
+        }
+    }
+
+    /// Generate method or constructor parameters, warning about dropped defaults
+    fn generate_member_parameters(&mut self, parameters: &[Parameter]) -> Result<String> {
+        let mut param_strings = Vec::new();
+        for param in parameters {
+            if param.initializer.is_some() && !param.optional {
+                self.warnings.push(CompilerError::unsupported_feature(format!(
+                    "default value of method parameter '{}'; the argument is required",
+                    param.name
+                )));
+            }
+            let param_type = self.generate_member_parameter_type(param)?;
+            param_strings.push(format!("{}: {}", self.type_mapper.rust_name(&param.name), param_type));
+        }
+        Ok(param_strings.join(", "))
+    }
 
-        Ok(format!("{}{}    pub fn new({}) -> Self {{\n{}\n    }}", decorators_str, "    ", params.join(", "), initialization))
+    /// Rust type of a parameter's value, from its annotation or default
+    fn parameter_value_type(&mut self, param: &Parameter) -> Result<String> {
+        let default_type = param.initializer.as_ref().and_then(literal_type);
+        if let Some(t) = param.type_.as_deref().or(default_type.as_ref()) {
+            self.type_mapper.map_type(t)
+        } else if param.rest {
+            Ok(self.type_mapper.use_type("Vec<Box<dyn Any>>"))
+        } else {
+            Ok(self.type_mapper.use_type("Box<dyn Any>"))
+        }
     }
 
     /// Generate getter declaration
@@ -552,22 +1561,31 @@ impl TypeScriptObject for HashMap<String, Any> {
         let return_type = if let Some(ref t) = getter.type_ {
             self.type_mapper.map_type(t)?
         } else {
-            "Box<dyn Any>".to_string()
+            self.type_mapper.use_type("Box<dyn Any>")
         };
         
-        let body = if let Some(ref body) = getter.body {
-            self.generate_statement(body)?
-        } else {
-            "// Empty getter".to_string()
+        let body = match getter.body {
+            // A lone `return this.x;` becomes the tail expression `self.x.clone()`
+            Some(Statement::BlockStatement(ref block)) if block.statements.len() == 1 => {
+                match &block.statements[0] {
+                    Statement::ReturnStatement(ReturnStatement { argument: Some(argument) }) => {
+                        let expr = self.generate_expression(argument)?;
+                        if this_field(argument).is_some() {
+                            format!("{}.clone()", expr)
+                        } else {
+                            expr
+                        }
+                    }
+                    statement => self.generate_statement(statement)?,
+                }
+            }
+            Some(ref body) => self.generate_statement(body)?,
+            None => "// Empty getter".to_string(),
         };
         
-        let decorators_str = if getter.decorators.is_empty() {
-            String::new()
-        } else {
-            format!("    // Decorators: {}\n", getter.decorators.join(", "))
-        };
+        let decorators_str = self.generate_member_decorators(&getter.decorators)?;
 
-        Ok(format!("{}{}    pub fn {}(&self) -> {} {{\n{}\n    }}", decorators_str, "    ", name, return_type, body))
+        Ok(format!("{}{}    pub fn {}(&self) -> {} {{\n        {}\n    }}", decorators_str, "    ", name, return_type, body))
     }
 
     /// Generate setter declaration
@@ -576,7 +1594,7 @@ impl TypeScriptObject for HashMap<String, Any> {
         let param_type = if let Some(ref t) = setter.parameter.type_ {
             self.type_mapper.map_type(t)?
         } else {
-            "Box<dyn Any>".to_string()
+            self.type_mapper.use_type("Box<dyn Any>")
         };
         
         let body = if let Some(ref body) = setter.body {
@@ -585,13 +1603,9 @@ impl TypeScriptObject for HashMap<String, Any> {
             "// Empty setter".to_string()
         };
         
-        let decorators_str = if setter.decorators.is_empty() {
-            String::new()
-        } else {
-            format!("    // Decorators: {}\n", setter.decorators.join(", "))
-        };
+        let decorators_str = self.generate_member_decorators(&setter.decorators)?;
 
-        Ok(format!("{}{}    pub fn set_{}(&mut self, value: {}) {{\n{}\n    }}", decorators_str, "    ", name, param_type, body))
+        Ok(format!("{}{}    pub fn set_{}(&mut self, {}: {}) {{\n        {}\n    }}", decorators_str, "    ", name, setter.parameter.name, param_type, body))
     }
 
     /// Generate block statement
@@ -605,6 +1619,41 @@ impl TypeScriptObject for HashMap<String, Any> {
         Ok(statements.join("\n"))
     }
 
+    /// Generate a `const enum` as one constant per member, prefixed with the enum name
+    fn generate_const_enum_declaration(&mut self, enum_decl: &EnumDeclaration) -> Result<String> {
+        let mut constants = Vec::new();
+        let mut next_value = 0.0;
+
+        for member in &enum_decl.members {
+            let (ty, value) = match &member.initializer {
+                Some(Expression::Literal(Literal::String(s))) => {
+                    ("&str".to_string(), format!("{:?}", s))
+                }
+                Some(Expression::Literal(Literal::Number(n))) => {
+                    next_value = n + 1.0;
+                    let value = self.generate_literal(&Literal::Number(*n))?;
+                    (self.type_mapper.number_type().to_string(), value)
+                }
+                Some(init) => {
+                    let value = self.generate_expression(init)?;
+                    (self.type_mapper.number_type().to_string(), value)
+                }
+                None => {
+                    let value = self.generate_literal(&Literal::Number(next_value))?;
+                    next_value += 1.0;
+                    (self.type_mapper.number_type().to_string(), value)
+                }
+            };
+            constants.push(format!(
+                "pub const {}: {} = {};",
+                const_enum_member(&enum_decl.name, &member.name),
+                ty,
+                value
+            ));
+        }
+
+        Ok(constants.join("\n"))
+    }
 
     /// Generate enum declaration
     fn generate_enum_declaration(&mut self, enum_decl: &EnumDeclaration) -> Result<String> {
@@ -639,8 +1688,20 @@ impl TypeScriptObject for HashMap<String, Any> {
                         }
                         Expression::Literal(Literal::Number(n)) => {
                             const_definitions.push(format!(
-                                "pub const {}: f64 = {};",
-                                variant_name, n
+                                "pub const {}: {} = {};",
+                                variant_name,
+                                self.type_mapper.number_type(),
+                                n
+                            ));
+                            enum_variants.push(format!("    {}", variant_name));
+                        }
+                        Expression::Literal(Literal::RadixNumber(_, text)) => {
+                            const_definitions.push(format!(
+                                "pub const {}: {} = {} as {};",
+                                variant_name,
+                                self.type_mapper.number_type(),
+                                text,
+                                self.type_mapper.number_type()
                             ));
                             enum_variants.push(format!("    {}", variant_name));
                         }
@@ -660,10 +1721,13 @@ impl TypeScriptObject for HashMap<String, Any> {
             }
             if !enum_variants.is_empty() {
                 result.push_str(&format!(
-                    "#[derive(Debug, Clone, Serialize, Deserialize)]\npub enum {} {{\n{}\n}}",
+                    "#[derive({})]\npub enum {} {{\n{}\n}}",
+                    self.type_mapper.derives(&["Debug", "Clone", "PartialEq"]).join(", "),
                     name,
                     enum_variants.join(",\n")
                 ));
+                result.push_str("\n\n");
+                result.push_str(&generate_enum_string_conversions(enum_decl));
             }
             Ok(result)
         } else {
@@ -674,7 +1738,8 @@ impl TypeScriptObject for HashMap<String, Any> {
             }
 
             Ok(format!(
-                "#[derive(Debug, Clone, Serialize, Deserialize)]\npub enum {} {{\n{}\n}}",
+                "#[derive({})]\npub enum {} {{\n{}\n}}",
+                self.type_mapper.derives(&["Debug", "Clone", "PartialEq"]).join(", "),
                 name,
                 variants.join(",\n")
             ))
@@ -684,57 +1749,130 @@ impl TypeScriptObject for HashMap<String, Any> {
     /// Generate variable declaration
     fn generate_variable_declaration(&mut self, var: &VariableDeclaration) -> Result<String> {
         let name = &var.name;
+        // Only bindings assigned after their declaration need `mut`
+        let binding = if var.keyword != crate::lexer::Keyword::Const && self.semantics.is_reassigned(var) {
+            "let mut"
+        } else {
+            "let"
+        };
+
+        // Closures have unnameable types, so let Rust infer them
+        if let Some(Expression::Function(func)) = &var.initializer {
+            return Ok(format!(
+                "{} {} = {};",
+                binding,
+                self.type_mapper.rust_name(name),
+                self.generate_function_expression(func)?
+            ));
+        }
+
+        let var_type = self.variable_type(var)?;
+
+        let initializer = match (&var.type_annotation, &var.initializer) {
+            // An object literal typed as an object type alias builds its struct
+            (Some(Type::Named(type_name)), Some(Expression::Object(object)))
+                if matches!(
+                    self.semantics.get_symbol(type_name).map(|symbol| &symbol.symbol_type),
+                    Some(SymbolType::Type(Type::ObjectType(_)))
+                ) =>
+            {
+                format!(" = {}", self.generate_struct_literal(&var_type, object)?)
+            }
+            (_, Some(init)) => format!(" = {}", self.generate_expression(init)?),
+            (_, None) => String::new(),
+        };
+
+        Ok(format!("{} {}: {}{};", binding, self.type_mapper.rust_name(name), var_type, initializer))
+    }
+
+    /// Rust type of a variable, from its annotation or inferred from its initializer
+    fn variable_type(&mut self, var: &VariableDeclaration) -> Result<String> {
         let var_type = if let Some(ref t) = var.type_annotation {
             self.type_mapper.map_type(t)?
         } else {
             // Try to infer type from initializer
             if let Some(ref init) = var.initializer {
                 match init {
-                    Expression::Literal(Literal::String(_)) => "String".to_string(),
-                    Expression::Literal(Literal::Number(_)) => "f64".to_string(),
-                    Expression::Literal(Literal::Boolean(_)) => "bool".to_string(),
-                    Expression::Array(_) => "Vec<Box<dyn Any>>".to_string(),
-                    Expression::Object(_) => "HashMap<String, Box<dyn Any>>".to_string(),
-                    Expression::New(new_expr) => {
-                        // Try to get the type from the constructor
-                        if let Expression::Identifier(callee) = &*new_expr.callee {
-                            format!("Box<{}>", callee)
-                        } else {
-                            "Box<dyn Any>".to_string()
+                    Expression::Literal(Literal::String(_)) | Expression::Template(_) => "String".to_string(),
+                    Expression::Literal(Literal::Number(_) | Literal::RadixNumber(..)) | Expression::Update(_) => {
+                        self.type_mapper.number_type().to_string()
+                    }
+                    // `a ?? b` has the type of its fallback
+                    Expression::Logical(logical) if logical.operator == Token::NullishCoalescing => {
+                        match literal_type(&logical.right) {
+                            Some(t) => self.type_mapper.map_type(&t)?,
+                            None => self.type_mapper.use_type("Box<dyn Any>"),
                         }
-                    },
+                    }
+                    Expression::Literal(Literal::Boolean(_)) | Expression::Logical(_) => "bool".to_string(),
+                    Expression::AsExpression(AsExpression { type_, .. })
+                    | Expression::TypeAssertion(TypeAssertion { type_, .. }) => self.type_mapper.map_type(type_)?,
+                    Expression::Conditional(conditional) => {
+                        match literal_type(&conditional.consequent).or_else(|| literal_type(&conditional.alternate)) {
+                            Some(t) => self.type_mapper.map_type(&t)?,
+                            None => self.type_mapper.use_type("Box<dyn Any>"),
+                        }
+                    }
+                    Expression::Literal(Literal::BigInt(_)) => "i64".to_string(),
+                    Expression::Literal(Literal::RegExp(..)) => "regex::Regex".to_string(),
+                    Expression::Array(_) => self.type_mapper.use_type("Vec<Box<dyn Any>>"),
+                    Expression::Object(_) => self.type_mapper.use_type("HashMap<String, Box<dyn Any>>"),
+                    Expression::New(new_expr) => self.new_expression_type(new_expr)?,
                     Expression::Call(call) => {
-                        // Try to infer return type from function name
-                        if let Expression::Identifier(callee) = &*call.callee {
-                            match callee.as_str() {
-                                "greet" => "String".to_string(),
-                                "add" => "f64".to_string(),
-                                _ => "Box<dyn Any>".to_string(),
+                        // Try to infer return type from an IIFE or the function name
+                        if let Some(return_type) = iife_return_type(call) {
+                            self.type_mapper.map_type(&return_type)?
+                        } else if let Expression::Identifier(callee) = &*call.callee {
+                            // Use the declared return type of a known function
+                            match self.semantics.get_symbol(callee).map(|symbol| &symbol.symbol_type) {
+                                Some(SymbolType::Function(signature)) => {
+                                    self.type_mapper.map_type(&signature.return_type)?
+                                }
+                                _ => self.type_mapper.use_type("Box<dyn Any>"),
                             }
                         } else {
-                            "Box<dyn Any>".to_string()
+                            self.type_mapper.use_type("Box<dyn Any>")
                         }
                     },
-                    _ => "Box<dyn Any>".to_string(),
+                    _ => self.type_mapper.use_type("Box<dyn Any>"),
                 }
             } else {
-                "Box<dyn Any>".to_string()
+                self.type_mapper.use_type("Box<dyn Any>")
             }
         };
+        Ok(var_type)
+    }
 
-        let initializer = if let Some(ref init) = var.initializer {
-            format!(" = {}", self.generate_expression(init)?)
-        } else {
-            String::new()
+    /// Generate an exported variable as a `pub const`, or a lazily initialized `pub static`
+    fn generate_exported_variable(&mut self, var: &VariableDeclaration) -> Result<String> {
+        let name = self.type_mapper.rust_name(&var.name);
+        let Some(init) = &var.initializer else {
+            return Err(CompilerError::unsupported_feature(format!(
+                "exported variable '{}' without an initializer",
+                var.name
+            )));
         };
+        if let Expression::Literal(Literal::String(value)) = init {
+            return Ok(format!("pub const {}: &str = {:?};", name, value));
+        }
 
-        Ok(format!("let {}: {}{};", name, var_type, initializer))
+        let var_type = self.variable_type(var)?;
+        let value = self.generate_expression(init)?;
+        if matches!(init, Expression::Literal(_)) {
+            Ok(format!("pub const {}: {} = {};", name, var_type, value))
+        } else {
+            Ok(format!(
+                "pub static {}: std::sync::LazyLock<{}> = std::sync::LazyLock::new(|| {});",
+                name, var_type, value
+            ))
+        }
     }
 
     /// Generate import declaration
     fn generate_import_declaration(&mut self, import: &ImportDeclaration) -> Result<String> {
         let source = &import.source;
         let mut import_parts = Vec::new();
+        let mut uses = Vec::new();
 
         for specifier in &import.specifiers {
             match specifier {
@@ -745,21 +1883,34 @@ impl TypeScriptObject for HashMap<String, Any> {
                         import_parts.push(format!("{} as {}", named.imported, named.name));
                     }
                 }
+                // Project modules expose their default export as `DefaultExport`
+                ImportSpecifier::Default(default)
+                    if source.starts_with("crate") && default.name != DEFAULT_EXPORT =>
+                {
+                    import_parts.push(format!("{} as {}", DEFAULT_EXPORT, default.name));
+                }
                 ImportSpecifier::Default(default) => {
                     import_parts.push(default.name.clone());
                 }
                 ImportSpecifier::Namespace(namespace) => {
-                    import_parts.push(format!("* as {}", namespace.name));
+                    // `import * as fs from "fs"` binds the module itself
+                    if namespace.name == *source {
+                        uses.push(format!("use {};", source));
+                    } else {
+                        uses.push(format!("use {} as {};", source, namespace.name));
+                    }
                 }
             }
         }
 
-        if import_parts.is_empty() {
+        if import_parts.is_empty() && uses.is_empty() {
             // Default import without named imports
-            Ok(format!("use {};", source))
-        } else {
-            Ok(format!("use {}::{{{}}};", source, import_parts.join(", ")))
+            return Ok(format!("use {};", source));
+        }
+        if !import_parts.is_empty() {
+            uses.insert(0, format!("use {}::{{{}}};", source, import_parts.join(", ")));
         }
+        Ok(uses.join("\n"))
     }
 
     /// Generate export
@@ -773,29 +1924,66 @@ impl TypeScriptObject for HashMap<String, Any> {
     /// Generate namespace declaration as module
     fn generate_namespace_declaration(&mut self, namespace: &NamespaceDeclaration) -> Result<String> {
         let name = &namespace.name;
-        let body = self.generate_statement(&namespace.body)?;
+        let body = self.generate_module_body(&namespace.body)?;
         Ok(format!("pub mod {} {{\n{}\n}}", name, body))
     }
 
     /// Generate module declaration
     fn generate_module_declaration(&mut self, module: &ModuleDeclaration) -> Result<String> {
         let name = &module.name;
-        let body = self.generate_statement(&module.body)?;
+        let body = self.generate_module_body(&module.body)?;
         Ok(format!("pub mod {} {{\n{}\n}}", name, body))
     }
 
+    /// Generate the items declared in a namespace or module body
+    fn generate_module_body(&mut self, body: &Statement) -> Result<String> {
+        let statements = match body {
+            Statement::BlockStatement(block) => &block.statements[..],
+            statement => std::slice::from_ref(statement),
+        };
+
+        // Generate into empty sections so the items land inside the module
+        let outer = (
+            std::mem::take(&mut self.structs),
+            std::mem::take(&mut self.traits),
+            std::mem::take(&mut self.enums),
+            std::mem::take(&mut self.functions),
+            std::mem::take(&mut self.modules),
+        );
+        let result = statements
+            .iter()
+            .try_for_each(|statement| self.generate_item(statement, &HashSet::new()));
+        let items = [
+            std::mem::replace(&mut self.structs, outer.0),
+            std::mem::replace(&mut self.traits, outer.1),
+            std::mem::replace(&mut self.enums, outer.2),
+            std::mem::replace(&mut self.functions, outer.3),
+            std::mem::replace(&mut self.modules, outer.4),
+        ]
+        .concat();
+        result?;
+
+        let mut code = String::from("    use super::*;\n");
+        for item in items {
+            code.push('\n');
+            for line in item.lines() {
+                if !line.is_empty() {
+                    code.push_str("    ");
+                    code.push_str(line);
+                }
+                code.push('\n');
+            }
+        }
+        Ok(code.trim_end().to_string())
+    }
+
     /// Generate method declaration
     fn generate_method_declaration(&mut self, method: &MethodDeclaration) -> Result<String> {
-        let name = &method.name;
-        let _params = self.generate_parameters(&method.parameters)?;
+        let name = &self.type_mapper.rust_name(&method.name);
+        let params = self.generate_member_parameters(&method.parameters)?;
         let return_type = if let Some(ref t) = method.return_type {
             let rust_type = self.type_mapper.map_type(t)?;
-            // For owned types like String, return cloned values
-            if rust_type == "String" {
-                format!(" -> {}", rust_type)
-            } else {
-                format!(" -> {}", rust_type)
-            }
+            format!(" -> {}", rust_type)
         } else {
             " -> ()".to_string()
         };
@@ -806,18 +1994,18 @@ impl TypeScriptObject for HashMap<String, Any> {
             "unimplemented!()".to_string()
         };
 
-        let decorators_str = if method.decorators.is_empty() {
-            String::new()
-        } else {
-            format!("    // Decorators: {}\n", method.decorators.join(", "))
-        };
+        let decorators_str = self.generate_member_decorators(&method.decorators)?;
 
         // Build the method signature
         let mut method_sig = String::new();
         method_sig.push_str(&decorators_str);
         method_sig.push_str("    pub fn ");
         method_sig.push_str(name);
-        method_sig.push_str("(&self)");
+        if params.is_empty() {
+            method_sig.push_str("(&self)");
+        } else {
+            method_sig.push_str(&format!("(&self, {})", params));
+        }
         method_sig.push_str(&return_type);
         method_sig.push_str(" {\n        ");
         method_sig.push_str(&body);
@@ -826,6 +2014,80 @@ impl TypeScriptObject for HashMap<String, Any> {
         Ok(method_sig)
     }
 
+    /// Apply registered decorators, returning the attributes they add
+    ///
+    /// Unknown decorators are reported as warnings.
+    fn apply_decorators(&mut self, decorators: &[Decorator], derives: &mut Vec<String>) -> Result<String> {
+        let mut attributes = String::new();
+        for decorator in decorators {
+            match self.decorator_registry.get(&decorator.name) {
+                Some(DecoratorEffect::Derive(traits)) => {
+                    for t in traits {
+                        if !derives.contains(t) {
+                            derives.push(t.clone());
+                        }
+                    }
+                }
+                Some(DecoratorEffect::Attribute(attribute)) => {
+                    attributes.push_str(attribute);
+                    attributes.push('\n');
+                }
+                None => {
+                    let description = self.describe_decorator(decorator)?;
+                    self.warnings.push(CompilerError::unsupported_feature(format!(
+                        "decorator @{}",
+                        description
+                    )));
+                }
+            }
+        }
+        Ok(attributes)
+    }
+
+    /// Generate attributes for registered member decorators
+    ///
+    /// Decorators without a member-level effect are kept as a comment and reported as warnings.
+    fn generate_member_decorators(&mut self, decorators: &[Decorator]) -> Result<String> {
+        let mut attributes = String::new();
+        let mut unsupported = Vec::new();
+        for decorator in decorators {
+            match self.decorator_registry.get(&decorator.name) {
+                Some(DecoratorEffect::Attribute(attribute)) => {
+                    attributes.push_str(&format!("    {}\n", attribute));
+                }
+                _ => {
+                    let description = self.describe_decorator(decorator)?;
+                    self.warnings.push(CompilerError::unsupported_feature(format!(
+                        "decorator @{} on a class member",
+                        description
+                    )));
+                    unsupported.push(description);
+                }
+            }
+        }
+
+        if !unsupported.is_empty() {
+            attributes.push_str(&format!("    // Decorators: {}\n", unsupported.join(", ")));
+        }
+        Ok(attributes)
+    }
+
+    /// Describe a decorator as it was written, e.g. `Route("/users")`
+    fn describe_decorator(&mut self, decorator: &Decorator) -> Result<String> {
+        if decorator.arguments.is_empty() {
+            return Ok(decorator.name.clone());
+        }
+
+        let mut arguments = Vec::new();
+        for argument in &decorator.arguments {
+            arguments.push(match argument {
+                Expression::Literal(Literal::String(s)) => format!("\"{}\"", s),
+                argument => self.generate_expression(argument)?,
+            });
+        }
+        Ok(format!("{}({})", decorator.name, arguments.join(", ")))
+    }
+
     /// Generate method signature
     #[allow(dead_code)]
     fn generate_method_signature(&mut self, method: &MethodSignature) -> Result<String> {
@@ -845,26 +2107,28 @@ impl TypeScriptObject for HashMap<String, Any> {
         let mut param_strings = Vec::new();
 
         for param in parameters {
-            let param_type = if let Some(ref t) = param.type_ {
-                self.type_mapper.map_type(t)?
-            } else {
-                "Box<dyn Any>".to_string()
-            };
-
-            let param_def = if param.optional {
-                format!("{}: Option<{}>", param.name, param_type)
-            } else {
-                format!("{}: {}", param.name, param_type)
-            };
-
-            param_strings.push(param_def);
+            let param_type = self.generate_parameter_type(param)?;
+            param_strings.push(format!("{}: {}", self.type_mapper.rust_name(&param.name), param_type));
         }
 
         let result = param_strings.join(", ");
-        println!("DEBUG: generate_parameters result: '{}'", result);
+        tracing::debug!("generate_parameters result: '{}'", result);
         Ok(result)
     }
 
+    /// Generate `let x = x.unwrap_or(default);` for each defaulted parameter
+    fn generate_default_parameters(&mut self, parameters: &[Parameter]) -> Result<String> {
+        let mut preamble = String::new();
+        for param in parameters {
+            if let Some(ref default) = param.initializer {
+                let default = self.generate_expression(default)?;
+                let name = self.type_mapper.rust_name(&param.name);
+                preamble.push_str(&format!("let {} = {}.unwrap_or({});\n    ", name, name, default));
+            }
+        }
+        Ok(preamble)
+    }
+
     /// Generate expression statement
     fn generate_expression_statement(&mut self, expr_stmt: &ExpressionStatement) -> Result<String> {
         let expr = self.generate_expression(&expr_stmt.expression)?;
@@ -881,6 +2145,10 @@ impl TypeScriptObject for HashMap<String, Any> {
                 }
                 Ok(statements.join("\n    "))
             }
+            // Rust has no `++`, so a standalone update is a compound assignment
+            Statement::ExpressionStatement(ExpressionStatement {
+                expression: Expression::Update(update),
+            }) => Ok(format!("{};", self.generate_update_statement(update)?)),
             Statement::ExpressionStatement(expr_stmt) => {
                 let expr = self.generate_expression(&expr_stmt.expression)?;
                 // Clean up TODO expressions
@@ -891,6 +2159,8 @@ impl TypeScriptObject for HashMap<String, Any> {
                 };
                 Ok(format!("{};", clean_expr))
             }
+            // A generator's return ends the sequence
+            Statement::ReturnStatement(_) if self.in_generator => Ok("return yielded.into_iter();".to_string()),
             Statement::ReturnStatement(ret) => {
                 if let Some(ref arg) = ret.argument {
                     let expr = self.generate_expression(arg)?;
@@ -898,8 +2168,8 @@ impl TypeScriptObject for HashMap<String, Any> {
                     let clean_expr = if expr.contains("TODO") {
                         "unimplemented!()".to_string()
                     } else {
-                        // For member expressions accessing fields, clone if needed
-                        if expr.starts_with("self.") {
+                        // Clone fields read through `this`; method calls already return owned values
+                        if this_field(arg).is_some() {
                             format!("{}.clone()", expr)
                         } else {
                             expr
@@ -914,6 +2184,18 @@ impl TypeScriptObject for HashMap<String, Any> {
                 let var_code = self.generate_variable_declaration(var)?;
                 Ok(var_code)
             },
+            Statement::ForOfStatement(for_of) => self.generate_for_of_statement(for_of, None),
+            Statement::ForStatement(for_stmt) => self.generate_for_statement(for_stmt, None),
+            Statement::DoWhileStatement(do_while) => self.generate_do_while_statement(do_while, None),
+            Statement::ForInStatement(for_in) => self.generate_for_in_statement(for_in, None),
+            Statement::LabeledStatement(labeled) => self.generate_labeled_statement(labeled),
+            Statement::BreakStatement(break_stmt) => Ok(match &break_stmt.label {
+                Some(label) => format!("break '{};", label),
+                None => "break;".to_string(),
+            }),
+            Statement::ContinueStatement(continue_stmt) => self.generate_continue_statement(continue_stmt),
+            Statement::IfStatement(if_stmt) => self.generate_if_statement(if_stmt),
+            Statement::ThrowStatement(throw) => self.generate_throw_statement(throw),
             _ => {
                 // Handle other statement types
                 Ok("// TODO: Implement statement".to_string())
@@ -921,13 +2203,211 @@ impl TypeScriptObject for HashMap<String, Any> {
         }
     }
 
-    /// Generate expression
-    fn generate_expression(&mut self, expression: &Expression) -> Result<String> {
-        match expression {
-            Expression::Literal(literal) => self.generate_literal(literal),
-            Expression::Identifier(ident) => Ok(ident.clone()),
-            Expression::Binary(binary) => self.generate_binary_expression(binary),
+    /// Generate an if statement, keeping `else if` chains flat
+    fn generate_if_statement(&mut self, if_stmt: &IfStatement) -> Result<String> {
+        let condition = self.generate_expression(&if_stmt.condition)?;
+        let consequent = self.generate_statement(&if_stmt.consequent)?;
+        let mut code = format!("if {} {{\n        {}\n    }}", condition, consequent);
+        match &if_stmt.alternate {
+            Some(Statement::IfStatement(else_if)) => {
+                code.push_str(" else ");
+                code.push_str(&self.generate_if_statement(else_if)?);
+            }
+            Some(alternate) => {
+                let alternate = self.generate_statement(alternate)?;
+                code.push_str(&format!(" else {{\n        {}\n    }}", alternate));
+            }
+            None => {}
+        }
+        Ok(code)
+    }
+
+    /// Generate a labeled loop, or a labeled block for other statements
+    fn generate_labeled_statement(&mut self, labeled: &LabeledStatement) -> Result<String> {
+        let label = labeled.label.as_str();
+        match &*labeled.body {
+            Statement::ForStatement(for_stmt) => self.generate_for_statement(for_stmt, Some(label)),
+            Statement::ForOfStatement(for_of) => self.generate_for_of_statement(for_of, Some(label)),
+            Statement::DoWhileStatement(do_while) => self.generate_do_while_statement(do_while, Some(label)),
+            Statement::ForInStatement(for_in) => self.generate_for_in_statement(for_in, Some(label)),
+            body => Ok(format!("'{}: {{\n        {}\n    }}", label, self.generate_statement(body)?)),
+        }
+    }
+
+    /// Generate `throw` as a panic carrying the error message
+    fn generate_throw_statement(&mut self, throw: &ThrowStatement) -> Result<String> {
+        let message = match &throw.argument {
+            // `new Error(msg)` panics with the message itself
+            Expression::New(new_expr)
+                if matches!(&*new_expr.callee, Expression::Identifier(name) if name.ends_with("Error")) =>
+            {
+                match new_expr.arguments.first() {
+                    Some(Expression::Literal(Literal::String(message))) => format!("{:?}", message),
+                    Some(message) => self.generate_expression(message)?,
+                    None => "\"\"".to_string(),
+                }
+            }
+            Expression::Literal(Literal::String(message)) => format!("{:?}", message),
+            argument => self.generate_expression(argument)?,
+        };
+        Ok(format!("panic!(\"{{}}\", {});", message))
+    }
+
+    /// Generate `for (init; test; update)` as a scoped `while` loop
+    fn generate_for_statement(&mut self, for_stmt: &ForStatement, label: Option<&str>) -> Result<String> {
+        let init = match for_stmt.init.as_deref() {
+            Some(init) => self.generate_statement(init)?,
+            None => String::new(),
+        };
+        let header = match &for_stmt.condition {
+            Some(condition) => format!("while {}", self.generate_expression(condition)?),
+            None => "loop".to_string(),
+        };
+        let header = format!("{}{}", loop_label(label), header);
+        let update = match &for_stmt.update {
+            Some(Expression::Update(update)) => Some(format!("{};", self.generate_update_statement(update)?)),
+            Some(update) => Some(format!("{};", self.generate_expression(update)?)),
+            None => None,
+        };
+        // The update also runs before every `continue`, which would otherwise skip it
+        let mut body = self.generate_loop_body(&for_stmt.body, label, update.clone())?;
+        if let Some(update) = update {
+            body = format!("{}\n            {}", body, update);
+        }
+
+        Ok(format!(
+            "{{\n        {}\n        {} {{\n            {}\n        }}\n    }}",
+            init, header, body
+        ))
+    }
+
+    /// Generate a loop body, recording the loop for the `continue` statements inside it
+    fn generate_loop_body(
+        &mut self,
+        body: &Statement,
+        label: Option<&str>,
+        before_continue: Option<String>,
+    ) -> Result<String> {
+        self.loops.push(LoopContext {
+            label: label.map(str::to_string),
+            before_continue,
+        });
+        let body = self.generate_statement(body);
+        self.loops.pop();
+        body
+    }
+
+    /// Generate `continue`, first running whatever the target loop needs before its next iteration
+    fn generate_continue_statement(&self, continue_stmt: &ContinueStatement) -> Result<String> {
+        let (target, jump) = match &continue_stmt.label {
+            Some(label) => (
+                self.loops.iter().rev().find(|l| l.label.as_deref() == Some(label)),
+                format!("continue '{};", label),
+            ),
+            None => (self.loops.last(), "continue;".to_string()),
+        };
+        // Rust only allows `continue` to target a loop, not a labeled block
+        let target = target.ok_or_else(|| {
+            CompilerError::generation_error(match &continue_stmt.label {
+                Some(label) => format!("'continue {}' does not target an enclosing loop", label),
+                None => "'continue' outside of a loop".to_string(),
+            })
+        })?;
+        match &target.before_continue {
+            Some(before_continue) => Ok(format!("{}\n            {}", before_continue, jump)),
+            None => Ok(jump),
+        }
+    }
+
+    /// Generate `do body while (condition)` as a `loop` that checks its condition last
+    fn generate_do_while_statement(&mut self, do_while: &DoWhileStatement, label: Option<&str>) -> Result<String> {
+        let condition = self.generate_expression(&do_while.condition)?;
+        // `continue` must still check the condition before the next iteration
+        let check = format!(
+            "if !{} {{\n            break{};\n        }}",
+            condition,
+            label.map(|label| format!(" '{}", label)).unwrap_or_default()
+        );
+        let body = self.generate_loop_body(&do_while.body, label, Some(check))?;
+        Ok(format!(
+            "{}loop {{\n        {}\n        if !{} {{\n            break;\n        }}\n    }}",
+            loop_label(label),
+            body,
+            condition
+        ))
+    }
+
+    /// Generate for...in loop over the keys of a `HashMap`
+    fn generate_for_in_statement(&mut self, for_in: &ForInStatement, label: Option<&str>) -> Result<String> {
+        let name = self.type_mapper.rust_name(&for_in.name);
+        let object = self.generate_expression(&for_in.object)?;
+        let body = self.generate_loop_body(&for_in.body, label, None)?;
+        Ok(format!(
+            "{}for {} in {}.keys() {{\n        {}\n    }}",
+            loop_label(label),
+            name,
+            object,
+            body
+        ))
+    }
+
+    /// Generate for...of loop
+    fn generate_for_of_statement(&mut self, for_of: &ForOfStatement, label: Option<&str>) -> Result<String> {
+        let binding = generate_binding_pattern(&for_of.binding);
+
+        // arr.entries() yields index/value pairs
+        let iterable = match &for_of.iterable {
+            Expression::Call(call) if call.arguments.is_empty() => match &*call.callee {
+                Expression::Member(member)
+                    if matches!(&*member.property, Expression::Identifier(p) if p == "entries") =>
+                {
+                    format!("{}.iter().enumerate()", self.generate_expression(&member.object)?)
+                }
+                _ => format!("{}.iter()", self.generate_expression(&for_of.iterable)?),
+            },
+            iterable => format!("{}.iter()", self.generate_expression(iterable)?),
+        };
+
+        let body = self.generate_loop_body(&for_of.body, label, None)?;
+        Ok(format!(
+            "{}for {} in {} {{\n        {}\n    }}",
+            loop_label(label),
+            binding,
+            iterable,
+            body
+        ))
+    }
+
+    /// Generate expression
+    fn generate_expression(&mut self, expression: &Expression) -> Result<String> {
+        match expression {
+            Expression::Literal(literal) => self.generate_literal(literal),
+            Expression::Identifier(ident) => Ok(self.type_mapper.rust_name(ident)),
+            Expression::Binary(binary) => self.generate_binary_expression(binary),
+            Expression::Logical(logical) => self.generate_logical_expression(logical),
+            Expression::Conditional(conditional) => self.generate_conditional_expression(conditional),
+            Expression::Yield(yield_expr) => self.generate_yield_expression(yield_expr),
+            // `?.` maps over an `Option`; on any other value it is a plain access
+            Expression::Optional(optional) if self.semantics.is_optional(expression) => {
+                self.generate_optional_access(&optional.expression)
+            }
+            Expression::Optional(optional) => self.generate_expression(&optional.expression),
+            Expression::NonNull(non_null) => {
+                Ok(format!("{}.unwrap()", self.generate_expression(&non_null.expression)?))
+            }
+            Expression::AsExpression(as_expr) => self.generate_type_cast(&as_expr.expression, &as_expr.type_),
+            Expression::TypeAssertion(assertion) => self.generate_type_cast(&assertion.expression, &assertion.type_),
+            Expression::Parenthesized(parenthesized) => match &*parenthesized.expression {
+                // Binary and logical expressions are already parenthesized
+                inner @ (Expression::Binary(_) | Expression::Logical(_)) => self.generate_expression(inner),
+                inner => Ok(format!("({})", self.generate_expression(inner)?)),
+            },
             Expression::Unary(unary) => self.generate_unary_expression(unary),
+            Expression::Update(update) => self.generate_update_expression(update),
+            // Accesses after a `?.` on an `Option` continue mapping over it
+            Expression::Call(_) | Expression::Member(_) if self.semantics.is_optional(expression) => {
+                self.generate_optional_access(expression)
+            }
             Expression::Call(call) => self.generate_call_expression(call),
             Expression::Member(member) => self.generate_member_expression(member),
             Expression::Array(array) => self.generate_array_expression(array),
@@ -938,6 +2418,17 @@ impl TypeScriptObject for HashMap<String, Any> {
             Expression::This(_) => Ok("self".to_string()),
             Expression::Super(_) => Ok("super".to_string()),
             Expression::Arrow(arrow) => self.generate_arrow_function(arrow),
+            Expression::Function(func) => self.generate_function_expression(func),
+            Expression::Class(_) => Err(CompilerError::unsupported_feature(
+                "class expressions outside a variable declaration",
+            )),
+            Expression::Spread(_) => Err(CompilerError::unsupported_feature(
+                "spread arguments outside a rest parameter",
+            )),
+            Expression::MetaProperty(meta) => Err(CompilerError::unsupported_feature(format!(
+                "{}.{} has no Rust equivalent",
+                meta.meta, meta.property
+            ))),
             _ => {
                 // Handle other expression types
                 Ok("// TODO: Implement expression".to_string())
@@ -951,7 +2442,7 @@ impl TypeScriptObject for HashMap<String, Any> {
         match unary.operator {
             Token::Keyword(crate::lexer::Keyword::Typeof) => {
                 // For typeof operator, we'll generate a runtime type check
-                Ok(format!("get_type_of({})", argument))
+                Ok(format!("get_type_of(&{})", argument))
             }
             Token::Not => Ok(format!("!{}", argument)),
             Token::Minus => Ok(format!("-{}", argument)),
@@ -960,61 +2451,350 @@ impl TypeScriptObject for HashMap<String, Any> {
         }
     }
 
+    /// Generate `++x`/`x--` as a compound assignment statement
+    fn generate_update_statement(&mut self, update: &UpdateExpression) -> Result<String> {
+        let argument = self.generate_expression(&update.argument)?;
+        let operator = match update.operator {
+            Token::Increment => "+=",
+            Token::Decrement => "-=",
+            ref operator => {
+                return Err(CompilerError::generation_error(format!(
+                    "Unsupported update operator: {:?}",
+                    operator
+                )))
+            }
+        };
+        let one = if self.config.number_as_i64 { "1" } else { "1.0" };
+        Ok(format!("{} {} {}", argument, operator, one))
+    }
+
+    /// Generate `++x`/`x--` used as a value
+    fn generate_update_expression(&mut self, update: &UpdateExpression) -> Result<String> {
+        let argument = self.generate_expression(&update.argument)?;
+        let statement = self.generate_update_statement(update)?;
+        if update.prefix {
+            Ok(format!("{{ {}; {} }}", statement, argument))
+        } else {
+            Ok(format!("{{ let previous = {}; {}; previous }}", argument, statement))
+        }
+    }
+
     /// Generate literal
     fn generate_literal(&self, literal: &Literal) -> Result<String> {
         match literal {
             Literal::String(s) => Ok(format!("\"{}\".to_string()", s)),
-            Literal::Number(n) => Ok(format!("{}.0", n)),
+            Literal::Number(n) if n.fract() == 0.0 && self.config.number_as_i64 => Ok(n.to_string()),
+            // `i64` can't hold a fraction, and truncating would change the value
+            Literal::Number(n) if self.config.number_as_i64 => Err(CompilerError::unsupported_feature(format!(
+                "non-integer number {} when numbers map to i64",
+                n
+            ))),
+            Literal::Number(n) if n.fract() == 0.0 => Ok(format!("{}.0", n)),
+            Literal::Number(n) => Ok(n.to_string()),
+            Literal::RadixNumber(_, text) if self.config.number_as_i64 => Ok(text.clone()),
+            // Keep the radix as written; the integer literal converts to f64
+            Literal::RadixNumber(_, text) => Ok(format!("({} as f64)", text)),
+            Literal::RegExp(pattern, flags) => {
+                // Flags without a Rust equivalent (g, y, u) don't change matching
+                let inline_flags: String = flags.chars().filter(|f| matches!(f, 'i' | 'm' | 's')).collect();
+                let pattern = if inline_flags.is_empty() {
+                    pattern.clone()
+                } else {
+                    format!("(?{}){}", inline_flags, pattern)
+                };
+                Ok(format!("regex::Regex::new({:?}).unwrap()", pattern))
+            }
+            // bigint maps to i64
+            Literal::BigInt(digits) => Ok(format!("{}i64", digits)),
             Literal::Boolean(b) => Ok(b.to_string()),
             Literal::Null => Ok("None".to_string()),
             Literal::Undefined => Ok("None".to_string()),
-            _ => Ok("// TODO: Implement literal".to_string()),
         }
     }
 
     /// Generate binary expression
     fn generate_binary_expression(&mut self, binary: &BinaryExpression) -> Result<String> {
+        // `"key" in obj` checks for a property key
+        if binary.operator == Token::Keyword(crate::lexer::Keyword::In) {
+            if !self.semantics.is_map_key_check(binary) {
+                return Err(CompilerError::unsupported_feature(
+                    "`in` on a value that is not a Map or Record",
+                ));
+            }
+            let key = match &*binary.left {
+                Expression::Literal(Literal::String(s)) => format!("\"{}\"", s),
+                left => format!("&{}", self.generate_expression(left)?),
+            };
+            let object = self.generate_expression(&binary.right)?;
+            return Ok(format!("{}.contains_key({})", object, key));
+        }
+
+        // `x instanceof B` checks the concrete type behind a dynamic value
+        if binary.operator == Token::Keyword(crate::lexer::Keyword::Instanceof) {
+            let value = self.generate_expression(&binary.left)?;
+            let class = self.generate_expression(&binary.right)?;
+            return Ok(format!("{}.downcast_ref::<{}>().is_some()", value, class));
+        }
+
         let left = self.generate_expression(&binary.left)?;
         let right = self.generate_expression(&binary.right)?;
         let operator = self.map_operator(&binary.operator)?;
         Ok(format!("({} {} {})", left, operator, right))
     }
 
+    /// Generate `a ? b : c` as an if expression, chaining nested ternaries with `else if`
+    fn generate_conditional_expression(&mut self, conditional: &ConditionalExpression) -> Result<String> {
+        let test = self.generate_expression(&conditional.test)?;
+        let consequent = self.generate_expression(&conditional.consequent)?;
+        let alternate = match &*conditional.alternate {
+            Expression::Conditional(nested) => self.generate_conditional_expression(nested)?,
+            alternate => format!("{{ {} }}", self.generate_expression(alternate)?),
+        };
+        Ok(format!("if {} {{ {} }} else {}", test, consequent, alternate))
+    }
+
+    /// Generate `yield` as a push onto the generator's collected values
+    fn generate_yield_expression(&mut self, yield_expr: &YieldExpression) -> Result<String> {
+        if !self.in_generator {
+            return Err(CompilerError::semantic_error("yield is only valid inside a generator function"));
+        }
+        let value = match &yield_expr.argument {
+            Some(argument) => self.generate_expression(argument)?,
+            None => "Default::default()".to_string(),
+        };
+        if yield_expr.delegate {
+            Ok(format!("yielded.extend({})", value))
+        } else {
+            Ok(format!("yielded.push({})", value))
+        }
+    }
+
+    /// Generate `x as T`: a cast for primitives, a downcast for dynamic values
+    fn generate_type_cast(&mut self, expression: &Expression, type_: &Type) -> Result<String> {
+        let value = self.generate_expression(expression)?;
+        let target = self.type_mapper.map_type(type_)?;
+        let from_string = self.semantics.is_string_cast_operand(expression);
+        match target.as_str() {
+            // Strings are parsed, like `Number(s)`, rather than cast
+            "f64" | "i64" if from_string => Ok(format!(
+                "{}.parse::<{}>().expect(\"value is not a {}\")",
+                value, target, target
+            )),
+            "f64" | "i64" => Ok(format!("({} as {})", value, target)),
+            "String" if from_string => Ok(value),
+            "String" => Ok(format!("{}.to_string()", value)),
+            "bool" | "Box<dyn Any>" | "serde_json::Value" => Ok(value),
+            _ => Ok(format!(
+                "*{}.downcast::<{}>().expect(\"value is not a {}\")",
+                value, target, target
+            )),
+        }
+    }
+
+    /// Generate `&&`/`||`, parenthesized like binary expressions
+    fn generate_logical_expression(&mut self, logical: &LogicalExpression) -> Result<String> {
+        if logical.operator == Token::NullishCoalescing {
+            return match &*logical.left {
+                // `null ?? x` is always `x`
+                Expression::Literal(Literal::Null | Literal::Undefined) => self.generate_expression(&logical.right),
+                left if self.semantics.is_optional(left) => Ok(format!(
+                    "{}.unwrap_or({})",
+                    self.generate_expression(left)?,
+                    self.generate_expression(&logical.right)?
+                )),
+                // Values that are never absent are used as they are
+                left => self.generate_expression(left),
+            };
+        }
+        let left = self.generate_expression(&logical.left)?;
+        let right = self.generate_expression(&logical.right)?;
+        let operator = self.map_operator(&logical.operator)?;
+        Ok(format!("({} {} {})", left, operator, right))
+    }
 
     /// Generate assignment expression
     fn generate_assignment_expression(&mut self, assignment: &AssignmentExpression) -> Result<String> {
         let left = self.generate_expression(&assignment.left)?;
+
+        // Appending a string literal to a String needs push_str
+        if assignment.operator == crate::lexer::Token::PlusAssign {
+            if let Expression::Literal(Literal::String(s)) = &*assignment.right {
+                return Ok(format!("{}.push_str(\"{}\")", left, s));
+            }
+        }
+
         let right = self.generate_expression(&assignment.right)?;
         let operator = match assignment.operator {
             crate::lexer::Token::Assign => "=",
-            _ => "=", // Default to assignment
+            crate::lexer::Token::PlusAssign => "+=",
+            crate::lexer::Token::MinusAssign => "-=",
+            crate::lexer::Token::MultiplyAssign => "*=",
+            crate::lexer::Token::DivideAssign => "/=",
+            crate::lexer::Token::ModuloAssign => "%=",
+            ref operator => {
+                return Err(CompilerError::generation_error(format!(
+                    "Unsupported assignment operator: {:?}",
+                    operator
+                )))
+            }
         };
         Ok(format!("{} {} {}", left, operator, right))
     }
 
+    /// Generate a member access or call on an `Option` as a `map` over its value
+    fn generate_optional_access(&mut self, access: &Expression) -> Result<String> {
+        let present = Box::new(Expression::Identifier("present".to_string()));
+        let (object, mapped) = match access {
+            Expression::Member(member) => (
+                &member.object,
+                Expression::Member(MemberExpression {
+                    object: present,
+                    ..member.clone()
+                }),
+            ),
+            Expression::Call(call) => {
+                let callee = match &*call.callee {
+                    Expression::Optional(optional) => &*optional.expression,
+                    callee => callee,
+                };
+                match callee {
+                    // `a?.m()` calls a method of the value
+                    Expression::Member(member) if self.semantics.is_optional(&member.object) => (
+                        &member.object,
+                        Expression::Call(CallExpression {
+                            callee: Box::new(Expression::Member(MemberExpression {
+                                object: present,
+                                ..member.clone()
+                            })),
+                            arguments: call.arguments.clone(),
+                        }),
+                    ),
+                    _ => (
+                        &call.callee,
+                        Expression::Call(CallExpression {
+                            callee: present,
+                            arguments: call.arguments.clone(),
+                        }),
+                    ),
+                }
+            }
+            access => return self.generate_expression(access),
+        };
+
+        let object = self.generate_expression(object)?;
+        let value = self.generate_expression(&mapped)?;
+        // Fields are cloned out of the borrowed value
+        let value = if matches!(mapped, Expression::Member(_)) {
+            format!("{}.clone()", value)
+        } else {
+            value
+        };
+        Ok(format!("{}.as_ref().map(|present| {})", object, value))
+    }
+
     /// Generate call expression
     fn generate_call_expression(&mut self, call: &CallExpression) -> Result<String> {
-        let callee = self.generate_expression(&call.callee)?;
+        let parameters = match &*call.callee {
+            Expression::Identifier(callee) => match self.semantics.get_symbol(callee).map(|symbol| &symbol.symbol_type) {
+                Some(SymbolType::Function(signature)) => signature.parameters.clone(),
+                _ => Vec::new(),
+            },
+            _ => Vec::new(),
+        };
+        // Arguments filling a rest parameter are collected into one `Vec`
+        let rest_index = parameters.iter().position(|param| param.rest);
         let mut args = Vec::new();
-        for arg in &call.arguments {
-            args.push(self.generate_expression(arg)?);
+        let split = rest_index.unwrap_or(call.arguments.len()).min(call.arguments.len());
+        for (index, arg) in call.arguments[..split].iter().enumerate() {
+            let arg = self.generate_expression(arg)?;
+            match parameters.get(index) {
+                Some(param) if param.optional || param.initializer.is_some() => args.push(format!("Some({})", arg)),
+                _ => args.push(arg),
+            }
+        }
+        // Omitted optional and defaulted arguments are passed as `None`
+        for param in parameters.iter().skip(split) {
+            if param.rest {
+                args.push(self.generate_rest_arguments(&call.arguments[split..])?);
+                break;
+            }
+            args.push("None".to_string());
         }
+
+        // IIFE: call the closure in place
+        if let Some(function) = immediately_invoked(&call.callee) {
+            let closure = self.generate_expression(function)?;
+            return Ok(format!("({})({})", closure, args.join(", ")));
+        }
+
+        let callee = self.generate_expression(&call.callee)?;
         
-        // Special handling for console.log
-        if callee == "console.log" {
+        // Console output goes to stdout, errors and warnings to stderr
+        let print_macro = match callee.as_str() {
+            "console.log" | "console.info" | "console.debug" => Some("println"),
+            "console.error" | "console.warn" => Some("eprintln"),
+            _ => None,
+        };
+        if let Some(print_macro) = print_macro {
             if args.len() == 1 {
-                Ok(format!("println!(\"{{}}\", {});", args[0]))
+                Ok(format!("{}!(\"{{}}\", {})", print_macro, args[0]))
             } else {
                 let format_string = args.iter().map(|_| "{}").collect::<Vec<_>>().join(" ");
-                Ok(format!("println!(\"{}\", {});", format_string, args.join(", ")))
+                Ok(format!("{}!(\"{}\", {})", print_macro, format_string, args.join(", ")))
             }
         } else {
             Ok(format!("{}({})", callee, args.join(", ")))
         }
     }
 
+    /// Generate the `Vec` passed for a rest parameter, expanding spread arguments
+    fn generate_rest_arguments(&mut self, arguments: &[Expression]) -> Result<String> {
+        if let [Expression::Spread(spread)] = arguments {
+            return Ok(format!("{}.clone()", self.generate_expression(&spread.argument)?));
+        }
+
+        let mut chunks = Vec::new();
+        let mut elements = Vec::new();
+        for argument in arguments {
+            if let Expression::Spread(spread) = argument {
+                if !elements.is_empty() {
+                    chunks.push(format!("vec![{}]", elements.join(", ")));
+                    elements.clear();
+                }
+                chunks.push(format!("{}.clone()", self.generate_expression(&spread.argument)?));
+            } else {
+                elements.push(self.generate_expression(argument)?);
+            }
+        }
+        if chunks.is_empty() {
+            return Ok(format!("vec![{}]", elements.join(", ")));
+        }
+        if !elements.is_empty() {
+            chunks.push(format!("vec![{}]", elements.join(", ")));
+        }
+        Ok(format!("[{}].concat()", chunks.join(", ")))
+    }
+
     /// Generate member expression
     fn generate_member_expression(&mut self, member: &MemberExpression) -> Result<String> {
+        // import.meta.url has a compile-time equivalent in file!()
+        if let (Expression::MetaProperty(meta), Expression::Identifier(property)) =
+            (&*member.object, &*member.property)
+        {
+            if meta.meta == "import" && meta.property == "meta" && property == "url" {
+                return Ok("format!(\"file://{}\", file!())".to_string());
+            }
+        }
+
+        // Const enum members are inlined as top-level constants
+        if let (Expression::Identifier(object), Expression::Identifier(property)) =
+            (&*member.object, &*member.property)
+        {
+            if !member.computed && self.const_enums.contains(object) {
+                return Ok(const_enum_member(object, property));
+            }
+        }
+
         let object = self.generate_expression(&member.object)?;
         let property = self.generate_expression(&member.property)?;
 
@@ -1042,7 +2822,7 @@ impl TypeScriptObject for HashMap<String, Any> {
                         elements.push(element_code);
                     }
                     _ => {
-                        elements.push(format!("Box::new({}) as Box<dyn Any>", element_code));
+                        elements.push(format!("Box::new({}) as {}", element_code, self.type_mapper.use_type("Box<dyn Any>")));
                     }
                 }
             } else {
@@ -1054,58 +2834,67 @@ impl TypeScriptObject for HashMap<String, Any> {
 
     /// Generate object expression
     fn generate_object_expression(&mut self, object: &ObjectExpression) -> Result<String> {
-        let mut fields = Vec::new();
+        let mut entries = Vec::new();
         for property in &object.properties {
-            let key = self.generate_expression(&property.key)?;
+            let key = self.generate_property_key(&property.key)?;
             let value = self.generate_expression(&property.value)?;
 
-            // Wrap values in Box::new for dynamic typing
-            let wrapped_value = match &property.value {
-                Expression::Literal(_) | Expression::Identifier(_) => value,
-                _ => format!("Box::new({}) as Box<dyn Any>", value),
-            };
+            // Values are boxed for dynamic typing
+            entries.push(format!("({:?}.to_string(), Box::new({}) as {})", key, value, self.type_mapper.use_type("Box<dyn Any>")));
+        }
+        Ok(format!("{}::from([\n        {}\n    ])", self.type_mapper.use_type("HashMap"), entries.join(",\n        ")))
+    }
+
+    /// Generate an object literal as a literal of a known struct
+    fn generate_struct_literal(&mut self, name: &str, object: &ObjectExpression) -> Result<String> {
+        let mut fields = Vec::new();
+        for property in &object.properties {
+            let key = self.generate_property_key(&property.key)?;
+            // Methods of an object type live in its impl block
+            if property.method {
+                self.warnings.push(CompilerError::unsupported_feature(format!(
+                    "method {} in a {} literal",
+                    key, name
+                )));
+                continue;
+            }
+            let field = self.type_mapper.rust_name(&key);
+            if property.shorthand {
+                fields.push(field);
+            } else {
+                let value = self.generate_expression(&property.value)?;
+                fields.push(format!("{}: {}", field, value));
+            }
+        }
+        Ok(format!("{} {{ {} }}", name, fields.join(", ")))
+    }
 
-            fields.push(format!("\"{}\".to_string(): {}", key, wrapped_value));
+    /// Name of an object literal key
+    fn generate_property_key(&mut self, key: &Expression) -> Result<String> {
+        match key {
+            Expression::Identifier(name) | Expression::Literal(Literal::String(name)) => Ok(name.clone()),
+            key => self.generate_expression(key),
         }
-        Ok(format!("{{\n        {}\n    }}", fields.join(",\n        ")))
     }
 
     /// Generate template literal
     fn generate_template_literal(&mut self, template: &TemplateLiteral) -> Result<String> {
-        // For now, handle simple template literals without expressions
-        if template.expressions.is_empty() && !template.quasis.is_empty() {
-            let raw_string = &template.quasis[0].value;
-            // Simple string replacement for common patterns
-            if raw_string.contains("${name}") {
-                Ok(format!("format!(\"Hello, {{}}!\", name)"))
-            } else if raw_string == "Hello, ${name}!" {
-                Ok(format!("format!(\"Hello, {{}}!\", name)"))
-            } else {
-                Ok(format!("\"{}\"", raw_string))
-            }
-        } else {
-            // Generate format! macro for template literals with interpolation
-            let mut format_parts = Vec::new();
-            let mut args = Vec::new();
-            
-            for (i, quasi) in template.quasis.iter().enumerate() {
-                format_parts.push(quasi.value.clone());
-                
-                // Add expression if it exists
-                if i < template.expressions.len() {
-                    let expr = self.generate_expression(&template.expressions[i])?;
-                    args.push(expr);
-                    format_parts.push("{}".to_string());
-                }
-            }
-            
-            let format_string = format_parts.join("");
-            if args.is_empty() {
-                Ok(format!("\"{}\"", format_string))
-            } else {
-                Ok(format!("format!(\"{}\", {})", format_string, args.join(", ")))
+        if template.expressions.is_empty() {
+            let value: String = template.quasis.iter().map(|quasi| quasi.value.as_str()).collect();
+            return Ok(format!("{:?}.to_string()", value));
+        }
+
+        // Interleave the quasis with `{}` placeholders for the expressions
+        let mut format_string = String::new();
+        let mut args = Vec::new();
+        for (i, quasi) in template.quasis.iter().enumerate() {
+            format_string.push_str(&quasi.value.replace('{', "{{").replace('}', "}}"));
+            if let Some(expression) = template.expressions.get(i) {
+                args.push(self.generate_expression(expression)?);
+                format_string.push_str("{}");
             }
         }
+        Ok(format!("format!({:?}, {})", format_string, args.join(", ")))
     }
 
     /// Generate new expression
@@ -1115,7 +2904,62 @@ impl TypeScriptObject for HashMap<String, Any> {
         for arg in &new_expr.arguments {
             args.push(self.generate_expression(arg)?);
         }
-        Ok(format!("Box::new({}::new({}))", callee, args.join(", ")))
+
+        // Built-in constructors map to their std equivalents
+        match callee.as_str() {
+            "Map" | "Set" if args.is_empty() => Ok(format!("Hash{}::new()", callee)),
+            "Map" => Ok(format!("{}::from_iter({})", self.type_mapper.use_type("HashMap"), self.generate_map_entries(&new_expr.arguments[0], &args[0])?)),
+            "Set" => Ok(format!("{}::from_iter({})", self.type_mapper.use_type("HashSet"), args[0])),
+            "Array" => match (&new_expr.arguments[..], &args[..]) {
+                ([], _) => Ok("Vec::new()".to_string()),
+                ([Expression::Literal(Literal::Number(n))], _) if n.fract() == 0.0 => {
+                    Ok(format!("Vec::with_capacity({})", n))
+                }
+                ([_], [length]) => Ok(format!("Vec::with_capacity({} as usize)", length)),
+                _ => Ok(format!("vec![{}]", args.join(", "))),
+            },
+            "Error" => Ok(args.into_iter().next().unwrap_or_else(|| "String::new()".to_string())),
+            _ => Ok(format!("Box::new({}::new({}))", callee, args.join(", "))),
+        }
+    }
+
+    /// Entries passed to `new Map(...)`, turning `[key, value]` pairs into tuples
+    fn generate_map_entries(&mut self, entries: &Expression, generated: &str) -> Result<String> {
+        let Expression::Array(array) = entries else {
+            // Another map or an iterator of tuples
+            return Ok(generated.to_string());
+        };
+        let mut pairs = Vec::new();
+        for element in &array.elements {
+            let Some(Expression::Array(pair)) = element else {
+                return Err(CompilerError::unsupported_feature("Map entries that are not [key, value] literals"));
+            };
+            let [Some(key), Some(value)] = &pair.elements[..] else {
+                return Err(CompilerError::unsupported_feature("Map entries that are not [key, value] literals"));
+            };
+            pairs.push(format!("({}, {})", self.generate_expression(key)?, self.generate_expression(value)?));
+        }
+        Ok(format!("[{}]", pairs.join(", ")))
+    }
+
+    /// Rust type of a value created with `new`
+    fn new_expression_type(&mut self, new_expr: &NewExpression) -> Result<String> {
+        let Expression::Identifier(callee) = &*new_expr.callee else {
+            return Ok(self.type_mapper.use_type("Box<dyn Any>"));
+        };
+        match callee.as_str() {
+            "Error" => Ok("String".to_string()),
+            "Map" | "Set" | "Array" if !new_expr.type_arguments.is_empty() => {
+                self.type_mapper.map_type(&Type::GenericNamed {
+                    name: callee.clone(),
+                    type_arguments: new_expr.type_arguments.clone(),
+                })
+            }
+            "Map" => Ok(self.type_mapper.use_type("HashMap<String, Box<dyn Any>>")),
+            "Set" => Ok(self.type_mapper.use_type("HashSet<Box<dyn Any>>")),
+            "Array" => Ok(self.type_mapper.use_type("Vec<Box<dyn Any>>")),
+            _ => Ok(format!("Box<{}>", callee)),
+        }
     }
 
     /// Map operator
@@ -1125,8 +2969,12 @@ impl TypeScriptObject for HashMap<String, Any> {
             crate::lexer::Token::Minus => Ok("-".to_string()),
             crate::lexer::Token::Multiply => Ok("*".to_string()),
             crate::lexer::Token::Divide => Ok("/".to_string()),
+            crate::lexer::Token::Modulo => Ok("%".to_string()),
             crate::lexer::Token::Equal => Ok("==".to_string()),
             crate::lexer::Token::NotEqual => Ok("!=".to_string()),
+            // Strict equality matches Rust's `==` for primitives; objects compare by value
+            crate::lexer::Token::StrictEqual => Ok("==".to_string()),
+            crate::lexer::Token::StrictNotEqual => Ok("!=".to_string()),
             crate::lexer::Token::LessThan => Ok("<".to_string()),
             crate::lexer::Token::GreaterThan => Ok(">".to_string()),
             crate::lexer::Token::LessEqual => Ok("<=".to_string()),
@@ -1143,9 +2991,457 @@ impl TypeScriptObject for HashMap<String, Any> {
     }
 
     /// Generate arrow function expression
-    fn generate_arrow_function(&mut self, _arrow: &ArrowFunctionExpression) -> Result<String> {
-        // For now, generate a simple closure
-        // TODO: Implement proper arrow function generation
-        Ok("|| { unimplemented!() }".to_string())
+    fn generate_arrow_function(&mut self, arrow: &ArrowFunctionExpression) -> Result<String> {
+        let params = self.generate_parameters(&arrow.parameters)?;
+        let return_type = match arrow
+            .return_type
+            .as_deref()
+            .cloned()
+            .or_else(|| infer_return_type(&arrow.parameters, &arrow.body))
+        {
+            Some(t) => format!(" -> {}", self.type_mapper.map_type(&t)?),
+            None => String::new(),
+        };
+
+        let defaults = self.generate_default_parameters(&arrow.parameters)?;
+
+        // An expression body is the closure's value
+        if let Statement::ExpressionStatement(expr_stmt) = &*arrow.body {
+            let body = self.generate_expression(&expr_stmt.expression)?;
+            return Ok(format!("|{}|{} {{ {}{} }}", params, return_type, defaults, body));
+        }
+
+        let body = format!("{}{}", defaults, self.generate_statement(&arrow.body)?);
+        Ok(format!("|{}|{} {{\n    {}\n}}", params, return_type, body))
+    }
+
+    /// Generate function expression as a closure
+    fn generate_function_expression(&mut self, func: &FunctionExpression) -> Result<String> {
+        let params = self.generate_parameters(&func.parameters)?;
+        let return_type = match func
+            .return_type
+            .clone()
+            .or_else(|| infer_return_type(&func.parameters, &func.body))
+        {
+            Some(t) => format!(" -> {}", self.type_mapper.map_type(&t)?),
+            None => String::new(),
+        };
+        let body = format!(
+            "{}{}",
+            self.generate_default_parameters(&func.parameters)?,
+            self.generate_statement(&func.body)?
+        );
+        Ok(format!("|{}|{} {{\n    {}\n}}", params, return_type, body))
+    }
+}
+
+/// Class declaration for `const Name = class { ... }`, named after the binding
+fn bound_class(var: &VariableDeclaration) -> Option<ClassDeclaration> {
+    let Some(Expression::Class(class)) = &var.initializer else {
+        return None;
+    };
+    Some(ClassDeclaration {
+        name: var.name.clone(),
+        type_parameters: class.type_parameters.clone(),
+        extends: class.extends.clone(),
+        implements: class.implements.clone(),
+        body: class.body.clone(),
+        decorators: Vec::new(),
+    })
+}
+
+/// Function declaration for `const name = function() { ... }`, named after the binding
+fn bound_function(var: &VariableDeclaration) -> Option<FunctionDeclaration> {
+    let Some(Expression::Function(func)) = &var.initializer else {
+        return None;
+    };
+    Some(FunctionDeclaration {
+        name: var.name.clone(),
+        type_parameters: func.type_parameters.clone(),
+        parameters: func.parameters.clone(),
+        return_type: func.return_type.clone(),
+        body: func.body.clone(),
+        generator: func.generator,
+    })
+}
+
+/// Infer a missing return type from the first `return` of a literal or typed parameter
+///
+/// An expression body, as in `() => 1`, counts as a return.
+fn infer_return_type(parameters: &[Parameter], body: &Statement) -> Option<Type> {
+    let returned: Vec<&Expression> = match body {
+        Statement::BlockStatement(block) => block
+            .statements
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::ReturnStatement(ReturnStatement { argument: Some(argument) }) => Some(argument),
+                _ => None,
+            })
+            .collect(),
+        Statement::ExpressionStatement(expr_stmt) => vec![&expr_stmt.expression],
+        _ => Vec::new(),
+    };
+    returned.into_iter().find_map(|argument| match argument {
+        Expression::Identifier(name) => parameters
+            .iter()
+            .find(|param| &param.name == name)
+            .and_then(|param| param.type_.as_deref().cloned()),
+        argument => literal_type(argument),
+    })
+}
+
+/// `as_str`/`from_str` for a string enum, using each member's string value or its name
+fn generate_enum_string_conversions(enum_decl: &EnumDeclaration) -> String {
+    let values: Vec<(&str, &str)> = enum_decl
+        .members
+        .iter()
+        .map(|member| match &member.initializer {
+            Some(Expression::Literal(Literal::String(value))) => (member.name.as_str(), value.as_str()),
+            _ => (member.name.as_str(), member.name.as_str()),
+        })
+        .collect();
+    let to_str: Vec<String> = values
+        .iter()
+        .map(|(variant, value)| format!("            Self::{} => {:?},", variant, value))
+        .collect();
+    let from_str: Vec<String> = values
+        .iter()
+        .map(|(variant, value)| format!("            {:?} => Some(Self::{}),", value, variant))
+        .collect();
+    format!(
+        "impl {} {{\n    pub fn as_str(&self) -> &str {{\n        match self {{\n{}\n        }}\n    }}\n\n    pub fn from_str(s: &str) -> Option<Self> {{\n        match s {{\n{}\n            _ => None,\n        }}\n    }}\n}}",
+        enum_decl.name,
+        to_str.join("\n"),
+        from_str.join("\n")
+    )
+}
+
+/// Item type of a `Generator<T>`/`Iterator<T>` style return annotation
+fn generator_item_type(return_type: &Type) -> Option<&Type> {
+    match return_type {
+        Type::GenericNamed { name, type_arguments }
+            if matches!(
+                name.as_str(),
+                "Generator" | "Iterator" | "IterableIterator" | "Iterable"
+            ) =>
+        {
+            type_arguments.first()
+        }
+        _ => None,
+    }
+}
+
+/// Type of the first literal yielded directly in a generator body
+fn first_yield_type(body: &Statement) -> Option<Type> {
+    let Statement::BlockStatement(block) = body else {
+        return None;
+    };
+    block.statements.iter().find_map(|statement| match statement {
+        Statement::ExpressionStatement(ExpressionStatement {
+            expression: Expression::Yield(yield_expr),
+        }) if !yield_expr.delegate => yield_expr.argument.as_deref().and_then(literal_type),
+        _ => None,
+    })
+}
+
+/// Type of a literal expression
+fn literal_type(expression: &Expression) -> Option<Type> {
+    match expression {
+        Expression::Literal(Literal::String(_)) | Expression::Template(_) => Some(Type::String),
+        Expression::Literal(Literal::Number(_) | Literal::RadixNumber(..)) => Some(Type::Number),
+        Expression::Literal(Literal::Boolean(_)) => Some(Type::Boolean),
+        _ => None,
+    }
+}
+
+/// Name of the top-level constant generated for a `const enum` member
+fn const_enum_member(enum_name: &str, member: &str) -> String {
+    format!("{}_{}", enum_name, member)
+}
+
+/// Closure called immediately, as in `(() => { ... })()`
+fn immediately_invoked(callee: &Expression) -> Option<&Expression> {
+    match callee {
+        Expression::Parenthesized(paren) => immediately_invoked(&paren.expression),
+        Expression::Arrow(_) | Expression::Function(_) => Some(callee),
+        _ => None,
+    }
+}
+
+/// Return type of an immediately invoked function expression, if it can be inferred
+fn iife_return_type(call: &CallExpression) -> Option<Type> {
+    match immediately_invoked(&call.callee)? {
+        Expression::Arrow(arrow) => arrow
+            .return_type
+            .as_deref()
+            .cloned()
+            .or_else(|| infer_return_type(&arrow.parameters, &arrow.body)),
+        Expression::Function(func) => func
+            .return_type
+            .clone()
+            .or_else(|| infer_return_type(&func.parameters, &func.body)),
+        _ => None,
+    }
+}
+
+/// Names of classes declared at the top level of a program
+fn declared_class_names(program: &Program) -> HashSet<String> {
+    program
+        .statements
+        .iter()
+        .filter_map(|statement| match statement {
+            Statement::ClassDeclaration(class) => Some(class.name.clone()),
+            Statement::VariableDeclaration(var) => bound_class(var).map(|class| class.name),
+            Statement::ExportDeclaration(export) => match &*export.declaration {
+                Statement::ClassDeclaration(class) => Some(class.name.clone()),
+                _ => None,
+            },
+            Statement::ExportDefaultDeclaration(export) => match &**export {
+                ExportDefaultDeclaration::Declaration(Statement::ClassDeclaration(class)) => {
+                    Some(class.name.clone())
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Match `Class.prototype.method = function() {}` and build the equivalent method
+fn prototype_method(expression: &Expression) -> Option<(&str, MethodDeclaration)> {
+    let Expression::Assignment(assignment) = expression else {
+        return None;
+    };
+    let (Expression::Member(member), Expression::Function(func)) =
+        (&*assignment.left, &*assignment.right)
+    else {
+        return None;
+    };
+    let (Expression::Member(prototype), Expression::Identifier(method_name)) =
+        (&*member.object, &*member.property)
+    else {
+        return None;
+    };
+    match (&*prototype.object, &*prototype.property) {
+        (Expression::Identifier(class), Expression::Identifier(property))
+            if property == "prototype" && !member.computed =>
+        {
+            Some((
+                class.as_str(),
+                MethodDeclaration {
+                    name: method_name.clone(),
+                    optional: false,
+                    type_parameters: func.type_parameters.clone(),
+                    parameters: func.parameters.clone(),
+                    return_type: func.return_type.clone(),
+                    body: Some((*func.body).clone()),
+                    modifiers: Vec::new(),
+                    decorators: Vec::new(),
+                },
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Loop label prefix such as `'outer: `
+fn loop_label(label: Option<&str>) -> String {
+    label.map(|label| format!("'{}: ", label)).unwrap_or_default()
+}
+
+/// Generate a Rust pattern for a binding
+fn generate_binding_pattern(pattern: &BindingPattern) -> String {
+    match pattern {
+        BindingPattern::Identifier(name) => name.clone(),
+        BindingPattern::Array(elements) => {
+            let elements: Vec<String> = elements
+                .iter()
+                .map(|element| element.as_ref().map_or("_".to_string(), generate_binding_pattern))
+                .collect();
+            format!("({})", elements.join(", "))
+        }
+    }
+}
+
+/// Collect the members of a (possibly nested) union type
+fn flatten_union<'a>(t: &'a Type, members: &mut Vec<&'a Type>) {
+    match t {
+        Type::Union { left, right } => {
+            flatten_union(left, members);
+            flatten_union(right, members);
+        }
+        _ => members.push(t),
+    }
+}
+
+/// Rebuild a union from its members
+fn union_of(members: Vec<Type>) -> Option<Type> {
+    members.into_iter().reduce(|left, right| Type::Union {
+        left: Box::new(left),
+        right: Box::new(right),
+    })
+}
+
+/// Collect the members of a (possibly nested) intersection type
+fn collect_intersection_members<'a>(t: &'a Type, members: &mut Vec<&'a Type>) {
+    match t {
+        Type::Intersection { left, right } => {
+            collect_intersection_members(left, members);
+            collect_intersection_members(right, members);
+        }
+        _ => members.push(t),
+    }
+}
+
+/// Property signatures declared by an object type
+fn object_properties(object: &ObjectType) -> Vec<PropertySignature> {
+    object
+        .members
+        .iter()
+        .filter_map(|member| match member {
+            ObjectTypeMember::Property(prop) => Some(prop.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// PascalCase enum variant name for a literal value, e.g. `"north-east"` becomes `NorthEast`
+fn literal_variant_name(value: &str) -> String {
+    let mut variant = String::new();
+    let mut capitalize = true;
+    for ch in value.chars() {
+        if !ch.is_alphanumeric() {
+            capitalize = true;
+        } else if capitalize {
+            variant.extend(ch.to_uppercase());
+            capitalize = false;
+        } else {
+            variant.push(ch);
+        }
+    }
+    match variant.chars().next() {
+        None => "Empty".to_string(),
+        Some(first) if first.is_ascii_digit() => format!("Value{}", variant),
+        Some(_) => variant,
+    }
+}
+
+/// Enum variant name for a union member, if it can become a variant
+fn union_variant_name(t: &Type) -> Option<String> {
+    match t {
+        Type::Named(name) => Some(name.clone()),
+        Type::String => Some("String".to_string()),
+        Type::Number => Some("Number".to_string()),
+        Type::Boolean => Some("Boolean".to_string()),
+        Type::Null => Some("Null".to_string()),
+        Type::Undefined => Some("Undefined".to_string()),
+        _ => None,
+    }
+}
+
+/// Flatten an intersection constraint into its trait bounds
+fn collect_bounds<'a>(constraint: &'a Type, bounds: &mut Vec<&'a Type>) {
+    match constraint {
+        Type::Intersection { left, right } => {
+            collect_bounds(left, bounds);
+            collect_bounds(right, bounds);
+        }
+        // Only named types can become trait bounds
+        Type::Named(_) | Type::Qualified(_) | Type::Generic(_) | Type::GenericNamed { .. } => {
+            bounds.push(constraint)
+        }
+        _ => {}
+    }
+}
+
+/// Constructor parameters declared with an access modifier
+fn parameter_properties(parameters: &[Parameter]) -> impl Iterator<Item = &Parameter> {
+    parameters.iter().filter(|param| !param.modifiers.is_empty())
+}
+
+/// Field name assigned by `this.field = ...`
+fn this_field(target: &Expression) -> Option<&str> {
+    match target {
+        Expression::Member(member) if !member.computed && matches!(&*member.object, Expression::This(_)) => {
+            match &*member.property {
+                Expression::Identifier(field) => Some(field),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Name of a declared class, function, interface or enum
+fn declaration_name(statement: &Statement) -> Option<&str> {
+    match statement {
+        Statement::ClassDeclaration(class) => Some(&class.name),
+        Statement::FunctionDeclaration(func) => Some(&func.name),
+        Statement::InterfaceDeclaration(interface) => Some(&interface.name),
+        Statement::EnumDeclaration(enum_decl) | Statement::ConstEnumDeclaration(enum_decl) => {
+            Some(&enum_decl.name)
+        }
+        _ => None,
+    }
+}
+
+/// Struct literal field, using shorthand when the value is a same-named variable
+fn field_initializer(field: &str, value: &str) -> String {
+    if field == value {
+        format!("            {}", field)
+    } else {
+        format!("            {}: {}", field, value)
+    }
+}
+
+/// Name of the type a mapped type iterates with `keyof`
+fn keyof_target(mapped: &MappedType) -> Option<&str> {
+    match mapped.constraint.as_deref() {
+        Some(Type::KeyOf(target)) => type_name(target),
+        _ => None,
     }
 }
+
+/// Name of a plain named type
+fn type_name(type_: &Type) -> Option<&str> {
+    match type_ {
+        Type::Named(name) => Some(name),
+        _ => None,
+    }
+}
+
+/// Replace type parameters with the types bound to them
+fn substitute_type_parameters(t: &Type, bindings: &HashMap<&str, &Type>) -> Type {
+    let substitute = |t: &Type| Box::new(substitute_type_parameters(t, bindings));
+    match t {
+        Type::Named(name) => bindings.get(name.as_str()).map_or_else(|| t.clone(), |bound| (*bound).clone()),
+        Type::GenericNamed {
+            name,
+            type_arguments,
+        } => Type::GenericNamed {
+            name: name.clone(),
+            type_arguments: type_arguments
+                .iter()
+                .map(|arg| substitute_type_parameters(arg, bindings))
+                .collect(),
+        },
+        Type::Array(element) => Type::Array(substitute(element)),
+        Type::Tuple(types) => Type::Tuple(
+            types
+                .iter()
+                .map(|t| substitute_type_parameters(t, bindings))
+                .collect(),
+        ),
+        Type::Union { left, right } => Type::Union {
+            left: substitute(left),
+            right: substitute(right),
+        },
+        Type::Intersection { left, right } => Type::Intersection {
+            left: substitute(left),
+            right: substitute(right),
+        },
+        Type::Parenthesized(inner) => Type::Parenthesized(substitute(inner)),
+        _ => t.clone(),
+    }
+}
+
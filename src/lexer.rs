@@ -8,8 +8,14 @@ use serde::{Deserialize, Serialize};
 pub enum Token {
     // Literals
     Number(f64),
+    /// Hex, octal or binary literal with its source text, e.g. `0xFF`
+    RadixNumber(f64, String),
+    /// BigInt literal digits without the `n` suffix
+    BigInt(String),
     String(String),
     TemplateLiteral(String),
+    /// Template literal with interpolations: the quasis and each `${}`'s tokens
+    TemplateExpression(Vec<String>, Vec<Vec<Token>>),
     Boolean(bool),
     Null,
     Undefined,
@@ -41,6 +47,9 @@ pub enum Token {
     MinusAssign,
     MultiplyAssign,
     DivideAssign,
+    ModuloAssign,
+    Increment, // ++
+    Decrement, // --
     Union, // |
     Intersection, // &
 
@@ -54,8 +63,13 @@ pub enum Token {
     Semicolon,
     Comma,
     Dot,
+    Ellipsis,
     Colon,
     QuestionMark,
+    /// `?.` optional chaining
+    QuestionDot,
+    /// `??` nullish coalescing
+    NullishCoalescing,
     At,
     RegExp(String, String), // pattern, flags
 
@@ -87,8 +101,6 @@ pub enum Keyword {
     Module,
     Import,
     Export,
-    From,
-    As,
     Default,
 
     // Control flow
@@ -96,7 +108,6 @@ pub enum Keyword {
     Else,
     Switch,
     Case,
-    DefaultCase,
     For,
     While,
     Do,
@@ -127,7 +138,7 @@ pub enum Keyword {
     // Async
     Async,
     Await,
-    Promise,
+    Yield,
 
     // Types
     Any,
@@ -142,68 +153,107 @@ pub enum Keyword {
     Symbol,
     BigInt,
     Object,
+
+    // Other
+    True,
+    False,
+    In,
+    Instanceof,
+    Typeof,
+    Keyof,
+    Declare,
+    Global,
+
+    // Former variants kept for compatibility; these words lex as identifiers
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
+    From,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
+    As,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
+    DefaultCase,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
+    Promise,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Array,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Tuple,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Union,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Intersection,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Literal,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Mapped,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Conditional,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Template,
-
-    // Utility types
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Partial,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Required,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Pick,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Omit,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Record,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Exclude,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Extract,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     NonNullable,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Parameters,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     ReturnType,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     InstanceType,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     ThisParameterType,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     OmitThisParameter,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     ThisType,
-
-    // Other
-    True,
-    False,
-    In,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Of,
-    Instanceof,
-    Typeof,
-    Keyof,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Key,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Is,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Asserts,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Infer,
-    Declare,
+    #[deprecated(note = "never produced by the lexer; the word lexes as an identifier")]
     Ambient,
-    Global,
 }
-
 /// Lexer for TypeScript code
 pub struct Lexer {
-    input: String,
+    chars: Vec<char>,
     position: usize,
     line: usize,
     column: usize,
+    /// Line and column where each token produced by `tokenize` starts
+    positions: Vec<(usize, usize)>,
 }
 
 impl Lexer {
     /// Create a new lexer
     pub fn new(input: String) -> Self {
         Self {
-            input,
+            chars: input.chars().collect(),
             position: 0,
             line: 1,
             column: 1,
+            positions: Vec::new(),
         }
     }
-    
+
     /// Create a UTF-8 compatible lexer
+    #[deprecated(note = "use `Lexer::new`, which handles UTF-8 input")]
     pub fn new_utf8(input: String) -> Self {
         Self::new(input)
     }
@@ -211,9 +261,10 @@ impl Lexer {
     /// Tokenize the input string
     pub fn tokenize(&mut self) -> Result<Vec<Token>> {
         let mut tokens = Vec::new();
+        self.positions.clear();
 
-        while self.position < self.input.len() {
-            match self.next_token()? {
+        while self.position < self.chars.len() {
+            match self.next_token(tokens.last())? {
                 Some(token) => {
                     tokens.push(token);
                 }
@@ -222,16 +273,23 @@ impl Lexer {
         }
 
         tokens.push(Token::EOF);
+        self.positions.push((self.line, self.column));
         Ok(tokens)
     }
 
-    /// Get the next token
-    fn next_token(&mut self) -> Result<Option<Token>> {
+    /// Line and column of each token from the last `tokenize`, in token order
+    pub fn positions(&self) -> &[(usize, usize)] {
+        &self.positions
+    }
+
+    /// Get the next token; `previous` decides whether `/` starts a regex
+    fn next_token(&mut self, previous: Option<&Token>) -> Result<Option<Token>> {
         self.skip_whitespace();
 
-        if self.position >= self.input.len() {
+        if self.position >= self.chars.len() {
             return Ok(None);
         }
+        self.positions.push((self.line, self.column));
 
         let ch = self.current_char();
         let token = match ch {
@@ -241,7 +299,7 @@ impl Lexer {
                     Ok(Some(Token::PlusAssign))
                 } else if self.peek_char() == Some('+') {
                     self.advance();
-                    Ok(Some(Token::Plus)) // ++ operator
+                    Ok(Some(Token::Increment))
                 } else {
                     Ok(Some(Token::Plus))
                 }
@@ -250,9 +308,9 @@ impl Lexer {
                 if self.peek_char() == Some('=') {
                     self.advance();
                     Ok(Some(Token::MinusAssign))
-                } else if self.peek_char() == Some('>') {
+                } else if self.peek_char() == Some('-') {
                     self.advance();
-                    Ok(Some(Token::Arrow))
+                    Ok(Some(Token::Decrement))
                 } else {
                     Ok(Some(Token::Minus))
                 }
@@ -266,22 +324,25 @@ impl Lexer {
                 }
             }
             '/' => {
-                if self.peek_char() == Some('=') {
+                // Check if this is the start of a regular expression
+                if regex_allowed_after(previous) {
+                    // parse_regex manages position itself
+                    return self.parse_regex();
+                } else if self.peek_char() == Some('=') {
                     self.advance();
                     Ok(Some(Token::DivideAssign))
-                } else if self.peek_char() == Some('/') {
-                    self.advance();
-                    self.skip_line_comment();
-                    Ok(None)
-                } else if self.peek_char() == Some('*') {
-                    self.advance();
-                    self.skip_block_comment();
-                    Ok(None)
                 } else {
                     Ok(Some(Token::Divide))
                 }
+            },
+            '%' => {
+                if self.peek_char() == Some('=') {
+                    self.advance();
+                    Ok(Some(Token::ModuloAssign))
+                } else {
+                    Ok(Some(Token::Modulo))
+                }
             }
-            '%' => Ok(Some(Token::Modulo)),
             '=' => {
                 if self.peek_char() == Some('=') {
                     self.advance();
@@ -351,15 +412,30 @@ impl Lexer {
             ']' => Ok(Some(Token::RightBracket)),
             ';' => Ok(Some(Token::Semicolon)),
             ',' => Ok(Some(Token::Comma)),
+            '.' if self.peek_char() == Some('.') && self.chars.get(self.position + 2) == Some(&'.') => {
+                self.advance();
+                self.advance();
+                Ok(Some(Token::Ellipsis))
+            }
             '.' => Ok(Some(Token::Dot)),
             ':' => Ok(Some(Token::Colon)),
+            '?' if self.peek_char() == Some('?') => {
+                self.advance();
+                Ok(Some(Token::NullishCoalescing))
+            }
+            // `a ?.5 : b` is a conditional, not optional chaining
+            '?' if self.peek_char() == Some('.')
+                && !self.chars.get(self.position + 2).is_some_and(|c| c.is_ascii_digit()) =>
+            {
+                self.advance();
+                Ok(Some(Token::QuestionDot))
+            }
             '?' => Ok(Some(Token::QuestionMark)),
             '@' => Ok(Some(Token::At)), // Add support for @ decorator symbol
             '"' | '\'' => Ok(self.parse_string()?),
             '`' => Ok(self.parse_template_literal()?),
             '0'..='9' => Ok(self.parse_number()?),
-            'a'..='z' | 'A'..='Z' | '_' | '$' => Ok(self.parse_identifier_or_keyword()?),
-            _ if ch.is_alphabetic() || ch.is_alphanumeric() => Ok(self.parse_identifier_or_keyword()?),
+            _ if ch.is_alphabetic() || ch == '_' || ch == '$' => Ok(self.parse_identifier_or_keyword()?),
             _ => {
                 return Err(CompilerError::parse_error(
                     self.line,
@@ -371,16 +447,16 @@ impl Lexer {
 
         // Only advance for simple tokens that don't manage position themselves
         match ch {
-            'a'..='z' | 'A'..='Z' | '_' | '$' => {
-                // parse_identifier_or_keyword manages position itself
-            }
             '0'..='9' => {
                 // parse_number manages position itself
             }
             '"' | '\'' => {
                 // parse_string manages position itself
             }
-            _ if ch.is_alphabetic() || ch.is_alphanumeric() => {
+            '`' => {
+                // parse_template_literal manages position itself
+            }
+            _ if ch.is_alphabetic() || ch == '_' || ch == '$' => {
                 // parse_identifier_or_keyword manages position itself
             }
             _ => {
@@ -393,18 +469,17 @@ impl Lexer {
 
     /// Get current character
     fn current_char(&self) -> char {
-        self.input.chars().nth(self.position).unwrap_or('\0')
+        self.chars.get(self.position).copied().unwrap_or('\0')
     }
-    
 
     /// Peek at next character
     fn peek_char(&self) -> Option<char> {
-        self.input.chars().nth(self.position + 1)
+        self.chars.get(self.position + 1).copied()
     }
 
     /// Advance position
     fn advance(&mut self) {
-        if self.position < self.input.len() {
+        if self.position < self.chars.len() {
             let ch = self.current_char();
             if ch == '\n' {
                 self.line += 1;
@@ -412,15 +487,13 @@ impl Lexer {
             } else {
                 self.column += 1;
             }
-            
-            // Simple advance by one character position
             self.position += 1;
         }
     }
 
     /// Skip whitespace
     fn skip_whitespace(&mut self) {
-        while self.position < self.input.len() {
+        while self.position < self.chars.len() {
             let ch = self.current_char();
             if ch.is_whitespace() {
                 self.advance();
@@ -428,14 +501,14 @@ impl Lexer {
                 // Skip line comment
                 self.advance(); // skip first /
                 self.advance(); // skip second /
-                while self.position < self.input.len() && self.current_char() != '\n' {
+                while self.position < self.chars.len() && self.current_char() != '\n' {
                     self.advance();
                 }
             } else if ch == '/' && self.peek_char() == Some('*') {
                 // Skip block comment
                 self.advance(); // skip /
                 self.advance(); // skip *
-                while self.position < self.input.len() {
+                while self.position < self.chars.len() {
                     if self.current_char() == '*' && self.peek_char() == Some('/') {
                         self.advance(); // skip *
                         self.advance(); // skip /
@@ -449,42 +522,32 @@ impl Lexer {
         }
     }
 
-    /// Skip line comment
-    fn skip_line_comment(&mut self) -> Option<Token> {
-        while self.position < self.input.len() && self.current_char() != '\n' {
-            self.advance();
-        }
-        None
-    }
-
-    /// Skip block comment
-    fn skip_block_comment(&mut self) -> Option<Token> {
-        while self.position < self.input.len() {
-            if self.current_char() == '*' && self.peek_char() == Some('/') {
-                self.advance();
-                self.advance();
-                break;
-            }
-            self.advance();
-        }
-        None
-    }
-
     /// Parse string literal
     fn parse_string(&mut self) -> Result<Option<Token>> {
         let quote = self.current_char();
         let mut value = String::new();
         self.advance();
 
-        while self.position < self.input.len() {
+        while self.position < self.chars.len() {
             let ch = self.current_char();
             if ch == quote {
                 self.advance();
                 return Ok(Some(Token::String(value)));
             } else if ch == '\\' {
                 self.advance();
-                if self.position < self.input.len() {
+                if self.position < self.chars.len() {
                     let escaped = self.current_char();
+                    if matches!(escaped, 'x' | 'u') {
+                        let (length, decoded) = scan_hex_escape(&self.chars[self.position..])
+                            .map_err(|message| {
+                                CompilerError::parse_error(self.line, self.column, message)
+                            })?;
+                        value.push(decoded);
+                        for _ in 0..length {
+                            self.advance();
+                        }
+                        continue;
+                    }
                     value.push(match escaped {
                         'n' => '\n',
                         't' => '\t',
@@ -511,45 +574,56 @@ impl Lexer {
 
     /// Parse template literal
     fn parse_template_literal(&mut self) -> Result<Option<Token>> {
+        let mut quasis = Vec::new();
+        let mut expressions = Vec::new();
         let mut value = String::new();
-        self.advance(); // consume opening backtick
+        self.advance();
 
-        while self.position < self.input.len() {
+        while self.position < self.chars.len() {
             let ch = self.current_char();
             if ch == '`' {
                 self.advance();
-                return Ok(Some(Token::TemplateLiteral(value)));
+                if expressions.is_empty() {
+                    return Ok(Some(Token::TemplateLiteral(value)));
+                }
+                quasis.push(value);
+                return Ok(Some(Token::TemplateExpression(quasis, expressions)));
             } else if ch == '\\' {
                 self.advance();
-                if self.position < self.input.len() {
+                if self.position < self.chars.len() {
                     let escaped = self.current_char();
+                    if matches!(escaped, 'x' | 'u') {
+                        let (length, decoded) = scan_hex_escape(&self.chars[self.position..])
+                            .map_err(|message| {
+                                CompilerError::parse_error(self.line, self.column, message)
+                            })?;
+                        value.push(decoded);
+                        for _ in 0..length {
+                            self.advance();
+                        }
+                        continue;
+                    }
                     value.push(match escaped {
                         'n' => '\n',
                         't' => '\t',
                         'r' => '\r',
                         '\\' => '\\',
                         '`' => '`',
-                        '$' => '$',
                         _ => escaped,
                     });
                     self.advance();
                 }
-            } else if ch == '$' && self.position + 1 < self.input.len() && self.input.chars().nth(self.position + 1) == Some('{') {
-                // Handle ${} interpolation - include the full ${} in the string for now
-                value.push('$');
+            } else if ch == '$' && self.peek_char() == Some('{') {
+                // Lex the interpolated expression on its own
                 self.advance();
-                if self.position < self.input.len() {
-                    value.push('{');
+                self.advance();
+                let length = scan_interpolation(&self.chars[self.position..])
+                    .map_err(|message| CompilerError::parse_error(self.line, self.column, message))?;
+                let source: String = self.chars[self.position..self.position + length].iter().collect();
+                expressions.push(Lexer::new(source).tokenize()?);
+                quasis.push(std::mem::take(&mut value));
+                for _ in 0..=length {
                     self.advance();
-                    // Skip to closing brace
-                    while self.position < self.input.len() && self.current_char() != '}' {
-                        value.push(self.current_char());
-                        self.advance();
-                    }
-                    if self.position < self.input.len() {
-                        value.push('}');
-                        self.advance();
-                    }
                 }
             } else {
                 value.push(ch);
@@ -566,37 +640,21 @@ impl Lexer {
 
     /// Parse number literal
     fn parse_number(&mut self) -> Result<Option<Token>> {
-        let mut value = String::new();
-        let mut has_dot = false;
-
-        while self.position < self.input.len() {
-            let ch = self.current_char();
-            if ch.is_ascii_digit() {
-                value.push(ch);
-                self.advance();
-            } else if ch == '.' && !has_dot {
-                has_dot = true;
-                value.push(ch);
-                self.advance();
-            } else {
-                break;
-            }
+        let (length, token) = scan_number(&self.chars[self.position..])
+            .map_err(|message| CompilerError::parse_error(self.line, self.column, message))?;
+        for _ in 0..length {
+            self.advance();
         }
-
-        let number: f64 = value.parse().map_err(|_| {
-            CompilerError::parse_error(self.line, self.column, "Invalid number literal")
-        })?;
-
-        Ok(Some(Token::Number(number)))
+        Ok(Some(token))
     }
 
     /// Parse identifier or keyword
     fn parse_identifier_or_keyword(&mut self) -> Result<Option<Token>> {
         let mut value = String::new();
 
-        while self.position < self.input.len() {
+        while self.position < self.chars.len() {
             let ch = self.current_char();
-            if ch.is_ascii_alphanumeric() || ch == '_' || ch == '$' {
+            if ch.is_alphanumeric() || ch == '_' || ch == '$' {
                 value.push(ch);
                 self.advance();
             } else {
@@ -604,7 +662,7 @@ impl Lexer {
             }
         }
 
-        // Check if it's a literal
+        // Check if it's a literal or keyword
         if value == "true" {
             Ok(Some(Token::Boolean(true)))
         } else if value == "false" {
@@ -621,6 +679,7 @@ impl Lexer {
         match value {
             "let" => Some(Keyword::Let),
             "const" => Some(Keyword::Const),
+            "declare" => Some(Keyword::Declare),
             "var" => Some(Keyword::Var),
             "function" => Some(Keyword::Function),
             "class" => Some(Keyword::Class),
@@ -629,108 +688,315 @@ impl Lexer {
             "enum" => Some(Keyword::Enum),
             "namespace" => Some(Keyword::Namespace),
             "module" => Some(Keyword::Module),
-            "import" => Some(Keyword::Import),
             "export" => Some(Keyword::Export),
-            "from" => Some(Keyword::From),
-            "as" => Some(Keyword::As),
-            "default" => Some(Keyword::Default),
+            "import" => Some(Keyword::Import),
+            "public" => Some(Keyword::Public),
+            "private" => Some(Keyword::Private),
+            "protected" => Some(Keyword::Protected),
+            "static" => Some(Keyword::Static),
+            "readonly" => Some(Keyword::Readonly),
+            "keyof" => Some(Keyword::Keyof),
+            "abstract" => Some(Keyword::Abstract),
+            "async" => Some(Keyword::Async),
+            "await" => Some(Keyword::Await),
+            "yield" => Some(Keyword::Yield),
+            "extends" => Some(Keyword::Extends),
+            "implements" => Some(Keyword::Implements),
+            "constructor" => Some(Keyword::Constructor),
+            "get" => Some(Keyword::Get),
+            "set" => Some(Keyword::Set),
+            "this" => Some(Keyword::This),
+            "super" => Some(Keyword::Super),
+            "new" => Some(Keyword::New),
+            "return" => Some(Keyword::Return),
             "if" => Some(Keyword::If),
             "else" => Some(Keyword::Else),
-            "switch" => Some(Keyword::Switch),
-            "case" => Some(Keyword::Case),
-            "for" => Some(Keyword::For),
             "while" => Some(Keyword::While),
+            "for" => Some(Keyword::For),
             "do" => Some(Keyword::Do),
             "break" => Some(Keyword::Break),
             "continue" => Some(Keyword::Continue),
-            "return" => Some(Keyword::Return),
-            "throw" => Some(Keyword::Throw),
+            "switch" => Some(Keyword::Switch),
+            "case" => Some(Keyword::Case),
+            "default" => Some(Keyword::Default),
             "try" => Some(Keyword::Try),
             "catch" => Some(Keyword::Catch),
             "finally" => Some(Keyword::Finally),
-            "extends" => Some(Keyword::Extends),
-            "implements" => Some(Keyword::Implements),
-            "super" => Some(Keyword::Super),
-            "this" => Some(Keyword::This),
-            "new" => Some(Keyword::New),
-            "static" => Some(Keyword::Static),
-            "public" => Some(Keyword::Public),
-            "private" => Some(Keyword::Private),
-            "protected" => Some(Keyword::Protected),
-            "abstract" => Some(Keyword::Abstract),
-            "readonly" => Some(Keyword::Readonly),
-            "get" => Some(Keyword::Get),
-            "set" => Some(Keyword::Set),
-            "async" => Some(Keyword::Async),
-            "await" => Some(Keyword::Await),
-            "Promise" => Some(Keyword::Promise),
-            "any" => Some(Keyword::Any),
-            "unknown" => Some(Keyword::Unknown),
-            "never" => Some(Keyword::Never),
-            "void" => Some(Keyword::Void),
+            "throw" => Some(Keyword::Throw),
+            "true" => Some(Keyword::True),
+            "false" => Some(Keyword::False),
             "null" => Some(Keyword::Null),
             "undefined" => Some(Keyword::Undefined),
-            "boolean" => Some(Keyword::Boolean),
-            "number" => Some(Keyword::Number),
-            "string" => Some(Keyword::String),
+            "void" => Some(Keyword::Void),
+            "never" => Some(Keyword::Never),
+            "any" => Some(Keyword::Any),
+            "unknown" => Some(Keyword::Unknown),
             "object" => Some(Keyword::Object),
-            "Array" => Some(Keyword::Array),
-            "true" => Some(Keyword::True),
-            "false" => Some(Keyword::False),
-            "in" => Some(Keyword::In),
-            "of" => Some(Keyword::Of),
-            "instanceof" => Some(Keyword::Instanceof),
+            "string" => Some(Keyword::String),
+            "number" => Some(Keyword::Number),
+            "boolean" => Some(Keyword::Boolean),
+            "symbol" => Some(Keyword::Symbol),
+            "bigint" => Some(Keyword::BigInt),
             "typeof" => Some(Keyword::Typeof),
-            "keyof" => Some(Keyword::Keyof),
-            "key" => Some(Keyword::Key),
-            "is" => Some(Keyword::Is),
-            "asserts" => Some(Keyword::Asserts),
-            "infer" => Some(Keyword::Infer),
-            "declare" => Some(Keyword::Declare),
+            "instanceof" => Some(Keyword::Instanceof),
+            "in" => Some(Keyword::In),
             "global" => Some(Keyword::Global),
             _ => None,
         }
     }
 
     /// Parse regular expression literal
-    #[allow(dead_code)]
     fn parse_regex(&mut self) -> Result<Option<Token>> {
-        let mut pattern = String::new();
-        let mut flags = String::new();
+        let (length, token) = scan_regex(&self.chars[self.position..])
+            .map_err(|message| CompilerError::parse_error(self.line, self.column, message))?;
+        for _ in 0..length {
+            self.advance();
+        }
+        Ok(Some(token))
+    }
+}
 
-        self.advance(); // consume '/'
+/// Scan a number literal at the start of `chars`
+///
+/// Returns the number of characters consumed and the token. Supports `0x`/`0o`/`0b`
+/// prefixes, `_` separators between digits, `e` exponents and the BigInt `n` suffix.
+pub(crate) fn scan_number(chars: &[char]) -> std::result::Result<(usize, Token), String> {
+    let at = |i: usize| chars.get(i).copied().unwrap_or('\0');
+
+    if at(0) == '0' {
+        if let Some(radix) = radix_of_prefix(at(1)) {
+            let (end, digits) = scan_digits(chars, 2, radix)?;
+            let number = u128::from_str_radix(&digits, radix)
+                .map_err(|_| "Invalid number literal".to_string())?;
+            if at(end) == 'n' {
+                return Ok((end + 1, Token::BigInt(number.to_string())));
+            }
+            let text = format!("0{}{}", at(1).to_ascii_lowercase(), digits);
+            return Ok((end, Token::RadixNumber(number as f64, text)));
+        }
+    }
 
-        // Parse pattern until closing '/'
-        while self.position < self.input.len() {
-            let ch = self.current_char();
-            if ch == '/' {
-                self.advance();
-                break;
-            } else if ch == '\\' {
-                // Handle escape sequences
-                pattern.push(ch);
-                self.advance();
-                if self.position < self.input.len() {
-                    pattern.push(self.current_char());
-                    self.advance();
+    let (mut end, mut value) = scan_digits(chars, 0, 10)?;
+    if at(end) == 'n' {
+        return Ok((end + 1, Token::BigInt(value)));
+    }
+    if at(end) == '.' && at(end + 1) != '.' {
+        value.push('.');
+        end += 1;
+        if at(end).is_ascii_digit() {
+            let (fraction_end, fraction) = scan_digits(chars, end, 10)?;
+            value.push_str(&fraction);
+            end = fraction_end;
+        }
+    }
+    if matches!(at(end), 'e' | 'E') {
+        value.push('e');
+        end += 1;
+        if matches!(at(end), '+' | '-') {
+            value.push(at(end));
+            end += 1;
+        }
+        let (exponent_end, exponent) = scan_digits(chars, end, 10)?;
+        value.push_str(&exponent);
+        end = exponent_end;
+    }
+
+    if at(end) == 'n' {
+        return Err("BigInt literals must be integers".to_string());
+    }
+
+    let number: f64 = value
+        .parse()
+        .map_err(|_| format!("Invalid number literal: {}", value))?;
+    Ok((end, Token::Number(number)))
+}
+
+/// Scan digits of `radix` from `start`, dropping `_` separators placed between digits
+fn scan_digits(chars: &[char], start: usize, radix: u32) -> std::result::Result<(usize, String), String> {
+    let is_digit = |i: usize| chars.get(i).is_some_and(|c| c.is_digit(radix));
+
+    let mut digits = String::new();
+    let mut end = start;
+    while end < chars.len() {
+        if is_digit(end) {
+            digits.push(chars[end]);
+        } else if chars[end] == '_' {
+            if end == start || !is_digit(end - 1) || !is_digit(end + 1) {
+                return Err("Numeric separators are only allowed between digits".to_string());
+            }
+        } else {
+            break;
+        }
+        end += 1;
+    }
+
+    if digits.is_empty() {
+        return Err("Expected digits in number literal".to_string());
+    }
+    Ok((end, digits))
+}
+
+/// Radix for a number literal prefix character (`x`, `o` or `b`)
+fn radix_of_prefix(ch: char) -> Option<u32> {
+    match ch {
+        'x' | 'X' => Some(16),
+        'o' | 'O' => Some(8),
+        'b' | 'B' => Some(2),
+        _ => None,
+    }
+}
+
+/// Scan a `\xHH`, `\uHHHH` or `\u{...}` escape starting at its `x` or `u`
+///
+/// Returns the number of characters consumed and the decoded character. A UTF-16
+/// surrogate pair written as two `\uHHHH` escapes decodes to a single character.
+pub(crate) fn scan_hex_escape(chars: &[char]) -> std::result::Result<(usize, char), String> {
+    let invalid = || "Invalid escape sequence".to_string();
+    let (mut length, mut code) = scan_code_point(chars).ok_or_else(invalid)?;
+
+    if (0xD800..0xDC00).contains(&code) && chars.get(length) == Some(&'\\') {
+        if let Some((low_length, low)) = scan_code_point(&chars[length + 1..]) {
+            if (0xDC00..0xE000).contains(&low) {
+                code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                length += 1 + low_length;
+            }
+        }
+    }
+
+    char::from_u32(code).map(|ch| (length, ch)).ok_or_else(invalid)
+}
+
+/// Scan the code point of a single hex escape starting at its `x` or `u`
+fn scan_code_point(chars: &[char]) -> Option<(usize, u32)> {
+    let hex = |start: usize, end: usize| {
+        let digits: String = chars.get(start..end)?.iter().collect();
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        u32::from_str_radix(&digits, 16).ok()
+    };
+
+    match (chars.first(), chars.get(1)) {
+        (Some('x'), _) => Some((3, hex(1, 3)?)),
+        (Some('u'), Some('{')) => {
+            let close = chars.iter().position(|&c| c == '}')?;
+            Some((close + 1, hex(2, close)?))
+        }
+        (Some('u'), _) => Some((5, hex(1, 5)?)),
+        _ => None,
+    }
+}
+
+/// Find the `}` closing a template interpolation whose `${` precedes `chars`
+///
+/// Returns its index, skipping braces inside nested strings and template literals.
+pub(crate) fn scan_interpolation(chars: &[char]) -> std::result::Result<usize, String> {
+    let unterminated = || "Unterminated template literal interpolation".to_string();
+    let mut depth = 0;
+    let mut end = 0;
+
+    while let Some(&ch) = chars.get(end) {
+        match ch {
+            '{' => depth += 1,
+            '}' if depth == 0 => return Ok(end),
+            '}' => depth -= 1,
+            '"' | '\'' => {
+                end += 1;
+                while chars.get(end) != Some(&ch) {
+                    match chars.get(end) {
+                        None => return Err(unterminated()),
+                        Some('\\') => end += 1,
+                        _ => {}
+                    }
+                    end += 1;
                 }
-            } else {
-                pattern.push(ch);
-                self.advance();
             }
+            '`' => {
+                end += 1;
+                while chars.get(end) != Some(&'`') {
+                    match chars.get(end) {
+                        None => return Err(unterminated()),
+                        Some('\\') => end += 1,
+                        Some('$') if chars.get(end + 1) == Some(&'{') => {
+                            end += 2 + scan_interpolation(&chars[end + 2..])?;
+                        }
+                        _ => {}
+                    }
+                    end += 1;
+                }
+            }
+            _ => {}
         }
+        end += 1;
+    }
 
-        // Parse flags
-        while self.position < self.input.len() {
-            let ch = self.current_char();
-            if ch.is_alphabetic() {
-                flags.push(ch);
-                self.advance();
-            } else {
-                break;
+    Err(unterminated())
+}
+
+/// Whether a `/` after `previous` starts a regex literal rather than a division
+pub(crate) fn regex_allowed_after(previous: Option<&Token>) -> bool {
+    !matches!(
+        previous,
+        Some(
+            Token::Identifier(_)
+                | Token::Number(_)
+                | Token::RadixNumber(..)
+                | Token::BigInt(_)
+                | Token::String(_)
+                | Token::TemplateLiteral(_)
+                | Token::TemplateExpression(..)
+                | Token::Boolean(_)
+                | Token::RegExp(..)
+                | Token::RightParen
+                | Token::RightBracket
+                | Token::Increment
+                | Token::Decrement
+                | Token::RightBrace
+                | Token::Null
+                | Token::Undefined
+                | Token::Keyword(Keyword::This | Keyword::Super | Keyword::Null | Keyword::Undefined)
+        )
+    )
+}
+
+/// Scan a regex literal such as `/ab+c/gi` at the start of `chars`
+///
+/// Returns the number of characters consumed and the token.
+pub(crate) fn scan_regex(chars: &[char]) -> std::result::Result<(usize, Token), String> {
+    let mut pattern = String::new();
+    let mut in_class = false;
+    let mut end = 1; // skip the opening '/'
+
+    loop {
+        match chars.get(end) {
+            None | Some('\n') => return Err("Unterminated regular expression".to_string()),
+            Some('/') if !in_class => break,
+            Some('\\') => {
+                pattern.push('\\');
+                end += 1;
+                match chars.get(end) {
+                    Some(&escaped) if escaped != '\n' => pattern.push(escaped),
+                    _ => return Err("Unterminated regular expression".to_string()),
+                }
+            }
+            Some(&ch) => {
+                match ch {
+                    '[' => in_class = true,
+                    ']' => in_class = false,
+                    _ => {}
+                }
+                pattern.push(ch);
             }
         }
+        end += 1;
+    }
+    end += 1; // skip the closing '/'
 
-        Ok(Some(Token::RegExp(pattern, flags)))
+    let mut flags = String::new();
+    while let Some(&ch) = chars.get(end).filter(|c| c.is_ascii_alphabetic()) {
+        flags.push(ch);
+        end += 1;
     }
+
+    Ok((end, Token::RegExp(pattern, flags)))
 }
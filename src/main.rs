@@ -1,6 +1,7 @@
 use clap::Parser;
 use std::path::PathBuf;
 use TypeScript_Rust_Compiler::compiler::Compiler;
+use TypeScript_Rust_Compiler::config::CompilerConfig;
 use TypeScript_Rust_Compiler::error::Result;
 
 #[derive(Parser)]
@@ -13,8 +14,8 @@ struct Cli {
     input: PathBuf,
 
     /// Output directory for generated Rust code
-    #[arg(short, long)]
-    output: PathBuf,
+    #[arg(short, long, required_unless_present = "emit_ast")]
+    output: Option<PathBuf>,
 
     /// Enable verbose output
     #[arg(short, long)]
@@ -31,6 +32,30 @@ struct Cli {
     /// Generate runtime for TypeScript semantics
     #[arg(short, long)]
     runtime: bool,
+
+    /// Append a `fn main` to the generated file
+    #[arg(long)]
+    emit_main: bool,
+
+    /// Re-indent the generated Rust code
+    #[arg(long)]
+    format: bool,
+
+    /// Watch the input and recompile changed files
+    #[arg(short, long)]
+    watch: bool,
+
+    /// Print the parsed AST as JSON instead of generating Rust code
+    #[arg(long)]
+    emit_ast: bool,
+
+    /// Fail on semantic errors instead of reporting them as warnings
+    #[arg(long)]
+    strict: bool,
+
+    /// JSON config file with compile-time defines
+    #[arg(long)]
+    config: Option<PathBuf>,
 }
 
 fn main() -> Result<()> {
@@ -50,18 +75,47 @@ fn main() -> Result<()> {
     // Create compiler instance
     let mut compiler = Compiler::new()
         .with_optimization(cli.optimize)
-        .with_runtime(cli.runtime);
+        .with_strict(cli.strict);
+    if let Some(config) = &cli.config {
+        let config = CompilerConfig::from_file(config)?;
+        compiler = compiler
+            .with_defines(config.define)
+            .with_generator_config(config.generator);
+    }
+    if cli.runtime {
+        compiler = compiler.with_runtime(true);
+    }
+    if cli.emit_main {
+        compiler = compiler.with_main(true);
+    }
+    if cli.format {
+        compiler = compiler.with_format(true);
+    }
 
     // Debug mode - just print that we're in debug mode
     if cli.debug {
         println!("Debug mode: Enabled");
     }
 
+    // Dump the AST instead of compiling
+    if cli.emit_ast {
+        return compiler.emit_ast(&cli.input, cli.output.as_deref());
+    }
+
     // Compile TypeScript to Rust
-    compiler.compile(&cli.input, &cli.output)?;
+    let output = cli.output.expect("clap requires --output unless --emit-ast");
+    if cli.watch {
+        println!("👀 Watching {} for changes...", cli.input.display());
+        return compiler.watch(&cli.input, &output);
+    }
+    compiler.compile(&cli.input, &output)?;
+
+    for warning in compiler.warnings() {
+        eprintln!("⚠️  {}", warning);
+    }
 
     println!("✅ Compilation completed successfully!");
-    println!("📁 Output directory: {}", cli.output.display());
+    println!("📁 Output directory: {}", output.display());
 
     Ok(())
 }
@@ -0,0 +1,163 @@
+//! AST optimizations applied before code generation
+
+use crate::ast::*;
+use crate::lexer::Token;
+use std::collections::HashMap;
+
+/// Optimizer that folds compile-time constant conditions
+pub struct Optimizer {
+    /// Build-time boolean constants such as `__DEV__`
+    defines: HashMap<String, bool>,
+}
+
+impl Optimizer {
+    /// Create a new optimizer
+    pub fn new() -> Self {
+        Self {
+            defines: HashMap::new(),
+        }
+    }
+
+    /// Define a compile-time boolean constant
+    pub fn with_define(mut self, name: impl Into<String>, value: bool) -> Self {
+        self.defines.insert(name.into(), value);
+        self
+    }
+
+    /// Define several compile-time boolean constants
+    pub fn with_defines(mut self, defines: HashMap<String, bool>) -> Self {
+        self.defines.extend(defines);
+        self
+    }
+
+    /// Optimize a program in place
+    pub fn optimize(&self, program: &mut Program) {
+        self.optimize_statements(&mut program.statements);
+    }
+
+    /// Optimize a list of statements, dropping eliminated ones
+    fn optimize_statements(&self, statements: &mut Vec<Statement>) {
+        *statements = std::mem::take(statements)
+            .into_iter()
+            .filter_map(|statement| self.optimize_statement(statement))
+            .collect();
+    }
+
+    /// Optimize a statement, returning `None` when it is eliminated
+    fn optimize_statement(&self, statement: Statement) -> Option<Statement> {
+        match statement {
+            Statement::IfStatement(if_stmt) => {
+                let IfStatement {
+                    condition,
+                    consequent,
+                    alternate,
+                } = *if_stmt;
+                match self.constant_condition(&condition) {
+                    Some(true) => self.optimize_statement(*consequent),
+                    Some(false) => {
+                        alternate.and_then(|alternate| self.optimize_statement(alternate))
+                    }
+                    None => Some(Statement::IfStatement(Box::new(IfStatement {
+                        condition,
+                        consequent: Box::new(self.optimize_body(*consequent)),
+                        alternate: alternate.map(|alternate| self.optimize_body(alternate)),
+                    }))),
+                }
+            }
+            Statement::WhileStatement(mut while_stmt) => {
+                if self.constant_condition(&while_stmt.condition) == Some(false) {
+                    return None;
+                }
+                while_stmt.body = Box::new(self.optimize_body(*while_stmt.body));
+                Some(Statement::WhileStatement(while_stmt))
+            }
+            // The body always runs once, so a false condition does not remove the loop
+            Statement::DoWhileStatement(mut do_while) => {
+                do_while.body = Box::new(self.optimize_body(*do_while.body));
+                Some(Statement::DoWhileStatement(do_while))
+            }
+            Statement::BlockStatement(mut block) => {
+                self.optimize_statements(&mut block.statements);
+                Some(Statement::BlockStatement(block))
+            }
+            Statement::FunctionDeclaration(mut func) => {
+                func.body = Box::new(self.optimize_body(*func.body));
+                Some(Statement::FunctionDeclaration(func))
+            }
+            Statement::ForStatement(mut for_stmt) => {
+                for_stmt.body = Box::new(self.optimize_body(*for_stmt.body));
+                Some(Statement::ForStatement(for_stmt))
+            }
+            Statement::ForOfStatement(mut for_of) => {
+                for_of.body = Box::new(self.optimize_body(*for_of.body));
+                Some(Statement::ForOfStatement(for_of))
+            }
+            Statement::ForInStatement(mut for_in) => {
+                for_in.body = Box::new(self.optimize_body(*for_in.body));
+                Some(Statement::ForInStatement(for_in))
+            }
+            Statement::LabeledStatement(mut labeled) => {
+                labeled.body = Box::new(self.optimize_body(*labeled.body));
+                Some(Statement::LabeledStatement(labeled))
+            }
+            Statement::ClassDeclaration(mut class) => {
+                for member in &mut class.body.members {
+                    self.optimize_class_member(member);
+                }
+                Some(Statement::ClassDeclaration(class))
+            }
+            Statement::ExportDeclaration(mut export) => {
+                let declaration = self.optimize_statement(*export.declaration)?;
+                export.declaration = Box::new(declaration);
+                Some(Statement::ExportDeclaration(export))
+            }
+            // `declare const __DEV__: boolean;` is replaced by the define itself
+            Statement::DeclareStatement(declare) => match &*declare.declaration {
+                Statement::VariableDeclaration(var) if self.defines.contains_key(&var.name) => None,
+                _ => Some(Statement::DeclareStatement(declare)),
+            },
+            statement => Some(statement),
+        }
+    }
+
+    /// Optimize a statement used as a body, keeping an empty block when it is eliminated
+    fn optimize_body(&self, statement: Statement) -> Statement {
+        self.optimize_statement(statement).unwrap_or_else(|| {
+            Statement::BlockStatement(BlockStatement {
+                statements: Vec::new(),
+            })
+        })
+    }
+
+    /// Optimize the bodies of a class member
+    fn optimize_class_member(&self, member: &mut ClassMember) {
+        let body = match member {
+            ClassMember::Method(method) => &mut method.body,
+            ClassMember::Constructor(constructor) => &mut constructor.body,
+            ClassMember::Getter(getter) => &mut getter.body,
+            ClassMember::Setter(setter) => &mut setter.body,
+            _ => return,
+        };
+        if let Some(statement) = body.take() {
+            *body = Some(self.optimize_body(statement));
+        }
+    }
+
+    /// Evaluate a condition known at compile time
+    fn constant_condition(&self, condition: &Expression) -> Option<bool> {
+        match condition {
+            Expression::Literal(Literal::Boolean(value)) => Some(*value),
+            Expression::Identifier(name) => self.defines.get(name).copied(),
+            Expression::Unary(unary) if unary.operator == Token::Not => {
+                self.constant_condition(&unary.argument).map(|value| !value)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Default for Optimizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -8,6 +8,10 @@ use crate::lexer::{Token, Keyword};
 pub struct Parser {
     tokens: Vec<Token>,
     position: usize,
+    /// Line and column of each token, when known
+    positions: Vec<(usize, usize)>,
+    /// Errors of statements skipped while parsing
+    errors: Vec<CompilerError>,
 }
 
 impl Parser {
@@ -16,15 +20,33 @@ impl Parser {
         Self {
             tokens,
             position: 0,
+            positions: Vec::new(),
+            errors: Vec::new(),
         }
     }
 
+    /// Report errors at the source line and column of each token
+    pub fn with_positions(mut self, positions: Vec<(usize, usize)>) -> Self {
+        self.positions = positions;
+        self
+    }
+
+    /// Take the errors of statements that were skipped so parsing could continue
+    pub fn take_errors(&mut self) -> Vec<CompilerError> {
+        std::mem::take(&mut self.errors)
+    }
+
+    /// Parse error located at the token with the given index
+    fn error_at(&self, index: usize, message: impl Into<String>) -> CompilerError {
+        let (line, column) = self.positions.get(index).copied().unwrap_or((0, 0));
+        CompilerError::parse_error(line, column, message)
+    }
+
     /// Parse the tokens into an AST
     pub fn parse(&mut self) -> Result<Program> {
         let mut statements = Vec::new();
         let mut iterations = 0;
         let max_iterations = self.tokens.len() * 2; // Prevent infinite loops
-        let mut errors = Vec::new();
 
         while self.position < self.tokens.len() && iterations < max_iterations {
             let old_position = self.position;
@@ -37,8 +59,8 @@ impl Parser {
                     break;
                 }
                 Err(error) => {
-                    // Log error but continue parsing
-                    errors.push(error);
+                    // Record the error but continue parsing
+                    self.errors.push(error);
                     // Skip current token and continue
                     self.advance();
                 }
@@ -72,9 +94,9 @@ impl Parser {
         // If we have statements, return them even if there were errors
         if !statements.is_empty() {
             Ok(Program { statements })
-        } else if !errors.is_empty() {
+        } else if !self.errors.is_empty() {
             // If no statements but we have errors, return the first error
-            Err(errors.into_iter().next().unwrap())
+            Err(self.errors.remove(0))
         } else {
             Ok(Program { statements })
         }
@@ -117,33 +139,39 @@ impl Parser {
                 crate::lexer::Keyword::Interface => self.parse_interface_declaration()?,
                 crate::lexer::Keyword::Type => self.parse_type_alias()?,
                 crate::lexer::Keyword::Enum => self.parse_enum_declaration()?,
-                crate::lexer::Keyword::Import => self.parse_import_declaration()?,
+                crate::lexer::Keyword::Import => {
+                    // `import.meta` starts an expression, not a declaration
+                    if self.peek_token() == Some(&Token::Dot) {
+                        self.parse_expression_statement()?
+                    } else {
+                        self.parse_import_declaration()?
+                    }
+                }
                 crate::lexer::Keyword::Export => self.parse_export_declaration()?,
                 crate::lexer::Keyword::Namespace => self.parse_namespace_declaration()?,
                 crate::lexer::Keyword::Module => self.parse_module_declaration()?,
                 crate::lexer::Keyword::Declare => self.parse_declare_statement()?,
                 crate::lexer::Keyword::Return => self.parse_return_statement()?,
+                crate::lexer::Keyword::Break => self.parse_break_statement()?,
+                crate::lexer::Keyword::Continue => self.parse_continue_statement()?,
                 crate::lexer::Keyword::Throw => self.parse_throw_statement()?,
                 crate::lexer::Keyword::If => self.parse_if_statement()?,
+                crate::lexer::Keyword::For => self.parse_for_statement()?,
+                crate::lexer::Keyword::Do => self.parse_do_while_statement()?,
                 crate::lexer::Keyword::Else => self.parse_expression_statement()?,
                 _ => self.parse_expression_statement()?,
             },
             Token::LeftBrace => self.parse_block_statement()?,
+            Token::At => self.parse_decorated_declaration()?,
+            Token::Identifier(_) if self.peek_token() == Some(&Token::Colon) => {
+                self.parse_labeled_statement()?
+            }
             Token::Semicolon => {
                 self.advance();
                 return self.parse_statement();
             }
-            _ => {
-                // Try to parse as expression statement, but if it fails, skip the token
-                match self.parse_expression_statement() {
-                    Ok(expr_stmt) => expr_stmt,
-                    Err(_) => {
-                        // Skip problematic token and continue
-                        self.advance();
-                        return Ok(None);
-                    }
-                }
-            }
+            // On failure the caller records the error and skips the token
+            _ => self.parse_expression_statement()?,
         };
 
         Ok(Some(statement))
@@ -179,7 +207,15 @@ impl Parser {
 
     /// Parse function declaration
     fn parse_function_declaration(&mut self) -> Result<Statement> {
+        let mut func = self.parse_function_signature()?;
+        func.body = Box::new(self.parse_block_statement()?);
+        Ok(Statement::FunctionDeclaration(func))
+    }
+
+    /// Parse a function signature, leaving its body empty
+    fn parse_function_signature(&mut self) -> Result<FunctionDeclaration> {
         self.expect_keyword()?; // consume 'function' keyword
+        let generator = self.consume_generator_star();
         let name = self.expect_identifier()?;
         let type_parameters = self.parse_type_parameters()?;
         let parameters = self.parse_parameters()?;
@@ -189,15 +225,17 @@ impl Parser {
         } else {
             None
         };
-        let body = self.parse_block_statement()?;
 
-        Ok(Statement::FunctionDeclaration(FunctionDeclaration {
+        Ok(FunctionDeclaration {
             name,
             type_parameters,
             parameters,
             return_type,
-            body: Box::new(body),
-        }))
+            body: Box::new(Statement::BlockStatement(BlockStatement {
+                statements: Vec::new(),
+            })),
+            generator,
+        })
     }
 
     /// Parse class declaration
@@ -220,9 +258,57 @@ impl Parser {
             extends,
             implements,
             body,
+            decorators: Vec::new(),
         }))
     }
 
+    /// Parse a declaration preceded by decorators, e.g. `@serializable class User {}`
+    fn parse_decorated_declaration(&mut self) -> Result<Statement> {
+        let start = self.position;
+        let decorators = self.parse_decorators()?;
+        let mut statement = match self.current_token() {
+            Token::Keyword(crate::lexer::Keyword::Export) => self.parse_export_declaration()?,
+            _ => self.parse_class_declaration()?,
+        };
+
+        let class = match &mut statement {
+            Statement::ExportDeclaration(export) => match &mut *export.declaration {
+                Statement::ClassDeclaration(class) => Some(class),
+                _ => None,
+            },
+            Statement::ClassDeclaration(class) => Some(class),
+            _ => None,
+        };
+        match class {
+            Some(class) => class.decorators = decorators,
+            None => {
+                return Err(self.error_at(start, "Decorators are only supported on classes and class members"))
+            }
+        }
+        Ok(statement)
+    }
+
+    /// Parse decorators such as `@Route("/users")`
+    fn parse_decorators(&mut self) -> Result<Vec<Decorator>> {
+        let mut decorators = Vec::new();
+        while self.current_token() == &Token::At {
+            self.advance(); // consume @
+            let name = self.expect_identifier()?;
+
+            // Decorator factories take arguments (e.g., @Route("/users"))
+            let arguments = if self.current_token() == &Token::LeftParen {
+                self.advance(); // consume (
+                let arguments = self.parse_arguments()?;
+                self.expect_token(&Token::RightParen)?;
+                arguments
+            } else {
+                Vec::new()
+            };
+            decorators.push(Decorator { name, arguments });
+        }
+        Ok(decorators)
+    }
+
     /// Parse interface declaration
     fn parse_interface_declaration(&mut self) -> Result<Statement> {
         self.expect_keyword()?; // consume 'interface' keyword
@@ -273,9 +359,7 @@ impl Parser {
         let name = self.expect_identifier()?;
         let members = self.parse_enum_members()?;
 
-        // For now, treat const enum the same as regular enum
-        // In a full implementation, we'd have a ConstEnumDeclaration type
-        Ok(Statement::EnumDeclaration(EnumDeclaration {
+        Ok(Statement::ConstEnumDeclaration(EnumDeclaration {
             name,
             members,
         }))
@@ -285,7 +369,12 @@ impl Parser {
     fn parse_import_declaration(&mut self) -> Result<Statement> {
         self.expect_keyword()?; // import
         let specifiers = self.parse_import_specifiers()?;
-        self.expect_keyword()?; // from
+        // 'from' is a contextual keyword and may be lexed as an identifier
+        if matches!(self.current_token(), Token::Identifier(name) if name == "from") {
+            self.advance();
+        } else {
+            self.expect_keyword()?; // from
+        }
         let source = self.parse_string_literal()?;
         self.expect_semicolon()?;
 
@@ -299,6 +388,9 @@ impl Parser {
     fn parse_export_declaration(&mut self) -> Result<Statement> {
         // Consume 'export' keyword
         self.advance();
+        if self.current_token() == &Token::Keyword(crate::lexer::Keyword::Default) {
+            return self.parse_export_default_declaration();
+        }
         // Parse the specific declaration type directly
         let token = self.current_token().clone();
         let declaration = match token {
@@ -311,24 +403,12 @@ impl Parser {
             Token::Keyword(crate::lexer::Keyword::Let) => self.parse_variable_declaration()?,
             Token::Keyword(crate::lexer::Keyword::Var) => self.parse_variable_declaration()?,
             Token::Keyword(crate::lexer::Keyword::Enum) => self.parse_enum_declaration()?,
-            Token::Keyword(crate::lexer::Keyword::Type) => {
-                // Check if this is "export type { ... }" or "export type Name = ..."
-                if self.position + 1 < self.tokens.len() {
-                    if let Token::LeftBrace = &self.tokens[self.position + 1] {
-                        // This is "export type { ... }", parse as export type statement
-                        self.parse_export_type_statement()?
-                    } else {
-                        // This is "export type Name = ...", parse as type alias
-                        self.parse_type_alias()?
-                    }
-                } else {
-                    self.parse_type_alias()?
-                }
-            },
-            Token::LeftBrace => {
-                // This is "export { ... }", parse as export statement
-                self.parse_export_statement()?
-            },
+            // "export type { ... }" re-exports types, "export type Name = ..." declares one
+            Token::Keyword(crate::lexer::Keyword::Type) if self.peek_token() == Some(&Token::LeftBrace) => {
+                return self.parse_export_type_statement();
+            }
+            Token::Keyword(crate::lexer::Keyword::Type) => self.parse_type_alias()?,
+            Token::LeftBrace => return self.parse_export_statement(),
             _ => {
                 return Err(CompilerError::parse_error(
                     1,
@@ -342,55 +422,74 @@ impl Parser {
         })))
     }
 
-    fn parse_export_statement(&mut self) -> Result<Statement> {
-        self.expect_token(&Token::LeftBrace)?; // consume '{'
-        
-        let mut exports = Vec::new();
-        while self.current_token() != &Token::RightBrace && self.current_token() != &Token::EOF {
-            let name = self.expect_identifier()?;
-            exports.push(name);
-            
-            if self.current_token() == &Token::Comma {
-                self.advance(); // consume ','
+    /// Parse `export default <declaration | expression>` after `export`
+    fn parse_export_default_declaration(&mut self) -> Result<Statement> {
+        self.advance(); // consume 'default'
+
+        // Anonymous classes and functions are parsed as expressions
+        let named = matches!(self.peek_token(), Some(Token::Identifier(_)));
+        let export = match self.current_token() {
+            Token::Keyword(crate::lexer::Keyword::Class) if named => {
+                ExportDefaultDeclaration::Declaration(self.parse_class_declaration()?)
             }
-        }
-        
-        self.expect_token(&Token::RightBrace)?; // consume '}'
-        self.expect_semicolon()?;
-        
-        Ok(Statement::ExportDeclaration(Box::new(ExportDeclaration {
-            declaration: Box::new(Statement::ExpressionStatement(ExpressionStatement {
-                expression: Expression::Literal(Literal::String(format!("Export: {}", exports.join(", ")))),
-            })),
-        })))
+            Token::Keyword(crate::lexer::Keyword::Function) if named => {
+                ExportDefaultDeclaration::Declaration(self.parse_function_declaration()?)
+            }
+            Token::Keyword(crate::lexer::Keyword::Interface) => {
+                ExportDefaultDeclaration::Declaration(self.parse_interface_declaration()?)
+            }
+            Token::Keyword(crate::lexer::Keyword::Enum) => {
+                ExportDefaultDeclaration::Declaration(self.parse_enum_declaration()?)
+            }
+            _ => {
+                let expression = self.parse_expression()?;
+                if self.current_token() == &Token::Semicolon {
+                    self.advance();
+                }
+                ExportDefaultDeclaration::Expression(expression)
+            }
+        };
+
+        Ok(Statement::ExportDefaultDeclaration(Box::new(export)))
+    }
+
+    /// Parse `export { a, b as c };` after `export`
+    fn parse_export_statement(&mut self) -> Result<Statement> {
+        let specifiers = self.parse_export_specifiers()?;
+        Ok(Statement::ExportNamedDeclaration(ExportNamedDeclaration { specifiers }))
     }
 
+    /// Parse `export type { A, B as C };` after `export`
     fn parse_export_type_statement(&mut self) -> Result<Statement> {
         self.expect_keyword()?; // consume 'type' keyword
+        let specifiers = self.parse_export_specifiers()?;
+        Ok(Statement::ExportTypeDeclaration(ExportNamedDeclaration { specifiers }))
+    }
+
+    /// Parse a `{ a, b as c };` export list
+    fn parse_export_specifiers(&mut self) -> Result<Vec<ExportSpecifier>> {
         self.expect_token(&Token::LeftBrace)?; // consume '{'
-        
-        let mut type_names = Vec::new();
+
+        let mut specifiers = Vec::new();
         while self.current_token() != &Token::RightBrace && self.current_token() != &Token::EOF {
-            let name = self.expect_identifier()?;
-            type_names.push(name);
-            
-            if self.current_token() == &Token::Comma {
+            let local = self.expect_identifier()?;
+            // `local as exported` exports the binding under another name
+            let exported = if self.is_as() {
                 self.advance();
+                self.expect_identifier()?
+            } else {
+                local.clone()
+            };
+            specifiers.push(ExportSpecifier { local, exported });
+
+            if self.current_token() == &Token::Comma {
+                self.advance(); // consume ','
             }
         }
-        
+
         self.expect_token(&Token::RightBrace)?; // consume '}'
         self.expect_semicolon()?;
-        
-        // For now, create a simple export statement
-        // In a full implementation, we'd have a proper ExportTypeStatement type
-        Ok(Statement::ExportDeclaration(Box::new(ExportDeclaration {
-            declaration: Box::new(Statement::TypeAlias(TypeAlias {
-                name: "exported_types".to_string(),
-                type_parameters: Vec::new(),
-                type_definition: Type::Any,
-            })),
-        })))
+        Ok(specifiers)
     }
 
     /// Parse namespace declaration
@@ -420,9 +519,25 @@ impl Parser {
     /// Parse declare statement
     fn parse_declare_statement(&mut self) -> Result<Statement> {
         self.expect_keyword()?; // declare
-        let declaration = self.parse_statement()?;
+        // Ambient functions have a signature but no body
+        let declaration = if self.current_token() == &Token::Keyword(Keyword::Function) {
+            let func = self.parse_function_signature()?;
+            if self.current_token() == &Token::Semicolon {
+                self.advance();
+            }
+            Some(Statement::FunctionDeclaration(func))
+        } else {
+            self.parse_statement()?
+        };
+        let declaration = declaration.ok_or_else(|| {
+            CompilerError::parse_error(
+                self.position,
+                1,
+                "Expected declaration after 'declare'".to_string(),
+            )
+        })?;
         Ok(Statement::DeclareStatement(Box::new(DeclareStatement {
-            declaration: Box::new(declaration.unwrap()),
+            declaration: Box::new(declaration),
         })))
     }
 
@@ -444,6 +559,50 @@ impl Parser {
         Ok(Statement::ReturnStatement(ReturnStatement { argument }))
     }
 
+    /// Parse break statement
+    fn parse_break_statement(&mut self) -> Result<Statement> {
+        self.expect_keyword()?; // break
+        let label = self.parse_jump_label();
+        Ok(Statement::BreakStatement(BreakStatement { label }))
+    }
+
+    /// Parse continue statement
+    fn parse_continue_statement(&mut self) -> Result<Statement> {
+        self.expect_keyword()?; // continue
+        let label = self.parse_jump_label();
+        Ok(Statement::ContinueStatement(ContinueStatement { label }))
+    }
+
+    /// Parse the optional label and semicolon after `break` or `continue`
+    fn parse_jump_label(&mut self) -> Option<String> {
+        let label = match self.current_token() {
+            Token::Identifier(name) => Some(name.clone()),
+            _ => None,
+        };
+        if label.is_some() {
+            self.advance();
+        }
+
+        // Optional semicolon
+        if self.current_token() == &Token::Semicolon {
+            self.advance();
+        }
+        label
+    }
+
+    /// Parse labeled statement
+    fn parse_labeled_statement(&mut self) -> Result<Statement> {
+        let label = self.expect_identifier()?;
+        self.expect_token(&Token::Colon)?;
+        let body = self.parse_statement()?.ok_or_else(|| {
+            CompilerError::parse_error(self.position, 0, "Expected statement after label")
+        })?;
+        Ok(Statement::LabeledStatement(LabeledStatement {
+            label,
+            body: Box::new(body),
+        }))
+    }
+
     /// Parse throw statement
     fn parse_throw_statement(&mut self) -> Result<Statement> {
         self.expect_keyword()?; // throw
@@ -491,6 +650,9 @@ impl Parser {
 
     /// Parse assignment expression
     fn parse_assignment_expression(&mut self) -> Result<Expression> {
+        if self.current_token() == &Token::Keyword(Keyword::Yield) {
+            return self.parse_yield_expression();
+        }
         let left = self.parse_conditional_expression()?;
 
         if self.is_assignment_operator() {
@@ -507,6 +669,36 @@ impl Parser {
         }
     }
 
+    /// Parse `yield value` or `yield* iterable`
+    fn parse_yield_expression(&mut self) -> Result<Expression> {
+        self.advance(); // consume 'yield'
+        let delegate = self.current_token() == &Token::Multiply;
+        if delegate {
+            self.advance();
+        }
+        let argument = match self.current_token() {
+            Token::Semicolon
+            | Token::RightParen
+            | Token::RightBracket
+            | Token::RightBrace
+            | Token::Comma
+            | Token::Colon
+            | Token::EOF => None,
+            _ => Some(Box::new(self.parse_assignment_expression()?)),
+        };
+        Ok(Expression::Yield(Box::new(YieldExpression { argument, delegate })))
+    }
+
+    /// Consume the `*` of `function*`, reporting whether it was present
+    fn consume_generator_star(&mut self) -> bool {
+        if self.current_token() == &Token::Multiply {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
     /// Parse conditional expression
     fn parse_conditional_expression(&mut self) -> Result<Expression> {
         let test = self.parse_logical_or_expression()?;
@@ -526,16 +718,17 @@ impl Parser {
         }
     }
 
-    /// Parse logical OR expression
+    /// Parse logical OR and nullish coalescing expressions
     fn parse_logical_or_expression(&mut self) -> Result<Expression> {
         let mut left = self.parse_logical_and_expression()?;
 
-        while self.current_token() == &Token::Or {
+        while matches!(self.current_token(), Token::Or | Token::NullishCoalescing) {
+            let operator = self.current_token().clone();
             self.advance();
             let right = self.parse_logical_and_expression()?;
             left = Expression::Logical(LogicalExpression {
                 left: Box::new(left),
-                operator: Token::Or,
+                operator,
                 right: Box::new(right),
             });
         }
@@ -582,15 +775,31 @@ impl Parser {
     fn parse_relational_expression(&mut self) -> Result<Expression> {
         let mut left = self.parse_additive_expression()?;
 
-        while self.is_relational_operator() {
-            let operator = self.current_token().clone();
-            self.advance();
-            let right = self.parse_additive_expression()?;
-            left = Expression::Binary(BinaryExpression {
-                left: Box::new(left),
-                operator,
-                right: Box::new(right),
-            });
+        loop {
+            if self.is_as() {
+                self.advance();
+                // `as const` only narrows the type
+                if self.current_token() == &Token::Keyword(Keyword::Const) {
+                    self.advance();
+                    continue;
+                }
+                let type_ = self.parse_type()?;
+                left = Expression::AsExpression(AsExpression {
+                    expression: Box::new(left),
+                    type_,
+                });
+            } else if self.is_relational_operator() {
+                let operator = self.current_token().clone();
+                self.advance();
+                let right = self.parse_additive_expression()?;
+                left = Expression::Binary(BinaryExpression {
+                    left: Box::new(left),
+                    operator,
+                    right: Box::new(right),
+                });
+            } else {
+                break;
+            }
         }
 
         Ok(left)
@@ -634,7 +843,16 @@ impl Parser {
 
     /// Parse unary expression
     fn parse_unary_expression(&mut self) -> Result<Expression> {
-        if self.is_unary_operator() {
+        if matches!(self.current_token(), Token::Increment | Token::Decrement) {
+            let operator = self.current_token().clone();
+            self.advance();
+            let argument = self.parse_unary_expression()?;
+            Ok(Expression::Update(UpdateExpression {
+                operator,
+                argument: Box::new(argument),
+                prefix: true,
+            }))
+        } else if self.is_unary_operator() {
             let operator = self.current_token().clone();
             self.advance();
             let argument = self.parse_unary_expression()?;
@@ -681,10 +899,61 @@ impl Parser {
                         computed: false,
                     });
                 }
+                // `a?.b`, `a?.[i]` and `f?.()` wrap the access they guard
+                Token::QuestionDot => {
+                    self.advance();
+                    let access = match self.current_token() {
+                        Token::LeftParen => {
+                            self.advance();
+                            let arguments = self.parse_arguments()?;
+                            self.expect_token(&Token::RightParen)?;
+                            Expression::Call(CallExpression {
+                                callee: Box::new(left),
+                                arguments,
+                            })
+                        }
+                        Token::LeftBracket => {
+                            self.advance();
+                            let index = self.parse_expression()?;
+                            self.expect_token(&Token::RightBracket)?;
+                            Expression::Member(MemberExpression {
+                                object: Box::new(left),
+                                property: Box::new(index),
+                                computed: true,
+                            })
+                        }
+                        _ => Expression::Member(MemberExpression {
+                            object: Box::new(left),
+                            property: Box::new(Expression::Identifier(self.expect_identifier()?)),
+                            computed: false,
+                        }),
+                    };
+                    left = Expression::Optional(OptionalExpression {
+                        expression: Box::new(access),
+                        optional: true,
+                    });
+                }
+                // A `!` after an operand is a non-null assertion, not logical not
+                Token::Not => {
+                    self.advance();
+                    left = Expression::NonNull(NonNullExpression {
+                        expression: Box::new(left),
+                    });
+                }
                 _ => break,
             }
         }
 
+        if matches!(self.current_token(), Token::Increment | Token::Decrement) {
+            let operator = self.current_token().clone();
+            self.advance();
+            left = Expression::Update(UpdateExpression {
+                operator,
+                argument: Box::new(left),
+                prefix: false,
+            });
+        }
+
         Ok(left)
     }
 
@@ -696,6 +965,14 @@ impl Parser {
                 self.advance();
                 Ok(Expression::Literal(Literal::Number(n)))
             }
+            Token::RadixNumber(n, text) => {
+                self.advance();
+                Ok(Expression::Literal(Literal::RadixNumber(n, text)))
+            }
+            Token::BigInt(digits) => {
+                self.advance();
+                Ok(Expression::Literal(Literal::BigInt(digits)))
+            }
             Token::String(s) => {
                 self.advance();
                 Ok(Expression::Literal(Literal::String(s)))
@@ -716,6 +993,37 @@ impl Parser {
                 };
                 Ok(Expression::Template(template))
             }
+            Token::TemplateExpression(quasis, token_streams) => {
+                self.advance();
+                let mut expressions = Vec::new();
+                for tokens in token_streams {
+                    let mut parser = Parser::new(tokens);
+                    expressions.push(parser.parse_expression()?);
+                    if parser.current_token() != &Token::EOF {
+                        return Err(CompilerError::parse_error(
+                            self.position,
+                            0,
+                            format!(
+                                "Unexpected token in template interpolation: {:?}",
+                                parser.current_token()
+                            ),
+                        ));
+                    }
+                }
+                let last = quasis.len() - 1;
+                let quasis = quasis
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, value)| TemplateElement {
+                        value,
+                        tail: i == last,
+                    })
+                    .collect();
+                Ok(Expression::Template(TemplateLiteral {
+                    quasis,
+                    expressions,
+                }))
+            }
             Token::Boolean(b) => {
                 self.advance();
                 Ok(Expression::Literal(Literal::Boolean(b)))
@@ -759,9 +1067,85 @@ impl Parser {
                 self.advance();
                 Ok(Expression::Super(SuperExpression))
             }
+            Token::Keyword(crate::lexer::Keyword::Function) => {
+                self.advance();
+                let generator = self.consume_generator_star();
+                let name = if let Token::Identifier(name) = self.current_token() {
+                    let name = name.clone();
+                    self.advance();
+                    Some(name)
+                } else {
+                    None
+                };
+                let type_parameters = self.parse_type_parameters()?;
+                let parameters = self.parse_parameters()?;
+                let return_type = if self.current_token() == &Token::Colon {
+                    self.advance();
+                    Some(self.parse_type()?)
+                } else {
+                    None
+                };
+                let body = self.parse_block_statement()?;
+                Ok(Expression::Function(Box::new(FunctionExpression {
+                    name,
+                    type_parameters,
+                    parameters,
+                    return_type,
+                    body: Box::new(body),
+                    generator,
+                })))
+            }
+            Token::Keyword(crate::lexer::Keyword::Class) => {
+                self.advance();
+                let name = if let Token::Identifier(name) = self.current_token() {
+                    let name = name.clone();
+                    self.advance();
+                    Some(name)
+                } else {
+                    None
+                };
+                let type_parameters = self.parse_type_parameters()?;
+                let extends = if self.current_token() == &Token::Keyword(crate::lexer::Keyword::Extends) {
+                    self.advance();
+                    Some(self.parse_type()?)
+                } else {
+                    None
+                };
+                let implements = self.parse_implements()?;
+                let body = self.parse_class_body()?;
+                Ok(Expression::Class(Box::new(ClassExpression {
+                    name,
+                    type_parameters,
+                    extends,
+                    implements,
+                    body,
+                })))
+            }
+            Token::Keyword(crate::lexer::Keyword::Import) => {
+                self.advance();
+                self.expect_token(&Token::Dot)?;
+                let property = self.expect_identifier()?;
+                Ok(Expression::MetaProperty(MetaProperty {
+                    meta: "import".to_string(),
+                    property,
+                }))
+            }
             Token::Keyword(crate::lexer::Keyword::New) => {
                 self.advance();
+                if self.current_token() == &Token::Dot {
+                    self.advance(); // consume '.'
+                    let property = self.expect_identifier()?;
+                    return Ok(Expression::MetaProperty(MetaProperty {
+                        meta: "new".to_string(),
+                        property,
+                    }));
+                }
                 let callee = self.parse_primary_expression()?;
+                let type_arguments = if self.current_token() == &Token::LessThan {
+                    self.parse_type_arguments()?
+                } else {
+                    Vec::new()
+                };
                 let arguments = if self.current_token() == &Token::LeftParen {
                     self.advance(); // consume '('
                     let args = self.parse_arguments()?;
@@ -772,6 +1156,7 @@ impl Parser {
                 };
                 Ok(Expression::New(NewExpression {
                     callee: Box::new(callee),
+                    type_arguments,
                     arguments,
                 }))
             }
@@ -839,17 +1224,54 @@ impl Parser {
 
         while self.current_token() != &Token::RightBrace {
             let key = self.parse_property_key()?;
-            let value = if self.current_token() == &Token::Colon {
+
+            // `greet() { ... }` is a method stored as a function value
+            if self.current_token() == &Token::LeftParen {
+                let name = match &key {
+                    Expression::Identifier(name) => Some(name.clone()),
+                    _ => None,
+                };
+                let parameters = self.parse_parameters()?;
+                let return_type = if self.current_token() == &Token::Colon {
+                    self.advance();
+                    Some(self.parse_type()?)
+                } else {
+                    None
+                };
+                let body = self.parse_block_statement()?;
+                properties.push(ObjectProperty {
+                    key,
+                    value: Expression::Function(Box::new(FunctionExpression {
+                        name,
+                        type_parameters: Vec::new(),
+                        parameters,
+                        return_type,
+                        body: Box::new(body),
+                        generator: false,
+                    })),
+                    shorthand: false,
+                    computed: false,
+                    method: true,
+                });
+                if self.current_token() == &Token::Comma {
+                    self.advance();
+                }
+                continue;
+            }
+
+            // `{ x }` is shorthand for `{ x: x }`
+            let shorthand = self.current_token() != &Token::Colon;
+            let value = if shorthand {
+                key.clone()
+            } else {
                 self.advance();
                 self.parse_expression()?
-            } else {
-                key.clone()
             };
 
             properties.push(ObjectProperty {
                 key,
                 value,
-                shorthand: false,
+                shorthand,
                 computed: false,
                 method: false,
             });
@@ -911,14 +1333,27 @@ impl Parser {
             };
         }
         
-        // Handle array types: T[]
+        // Handle array types T[] and indexed access types T[K]
+        self.parse_type_suffixes(left_type)
+    }
+
+    /// Parse `[]` array and `[K]` indexed access suffixes after a type
+    fn parse_type_suffixes(&mut self, mut type_: Type) -> Result<Type> {
         while self.current_token() == &Token::LeftBracket {
             self.advance(); // consume [
-            self.expect_token(&Token::RightBracket)?; // consume ]
-            left_type = Type::Array(Box::new(left_type));
+            if self.current_token() == &Token::RightBracket {
+                self.advance(); // consume ]
+                type_ = Type::Array(Box::new(type_));
+            } else {
+                let index = self.parse_type()?;
+                self.expect_token(&Token::RightBracket)?;
+                type_ = Type::IndexedAccess {
+                    object: Box::new(type_),
+                    index: Box::new(index),
+                };
+            }
         }
-        
-        Ok(left_type)
+        Ok(type_)
     }
     
     fn parse_primary_type(&mut self) -> Result<Type> {
@@ -952,37 +1387,25 @@ impl Parser {
                 self.advance();
                 Ok(Type::Unknown)
             }
-            Token::Keyword(crate::lexer::Keyword::Array) => {
-                self.advance();
-                if self.current_token() == &Token::LessThan {
-                    self.advance(); // consume <
-                    let element_type = self.parse_primary_type()?;
-                    self.expect_token(&Token::GreaterThan)?;
-                    Ok(Type::Array(Box::new(element_type)))
-                } else {
-                    Ok(Type::Array(Box::new(Type::Any)))
-                }
-            }
-            Token::Keyword(crate::lexer::Keyword::Readonly) => {
+            Token::Keyword(crate::lexer::Keyword::Symbol) => {
                 self.advance();
-                // Parse the type that follows readonly
-                let element_type = self.parse_primary_type()?;
-                // For now, just return the element type (readonly is handled at runtime)
-                Ok(element_type)
+                Ok(Type::Symbol)
             }
-            Token::Keyword(crate::lexer::Keyword::Keyof) => {
+            Token::Keyword(crate::lexer::Keyword::BigInt) => {
                 self.advance();
-                let _target_type = self.parse_type()?;
-                // For now, return string as keyof T resolves to string
-                Ok(Type::String)
+                Ok(Type::BigInt)
             }
-            Token::Keyword(crate::lexer::Keyword::Key) => {
+            Token::Keyword(crate::lexer::Keyword::Readonly) => {
                 self.advance();
-                Ok(Type::String) // Key -> string for now
+                // `readonly T[]` applies to the whole array type
+                let target_type = self.parse_primary_type()?;
+                let target_type = self.parse_type_suffixes(target_type)?;
+                Ok(Type::Readonly(Box::new(target_type)))
             }
-            Token::Keyword(crate::lexer::Keyword::Infer) => {
+            Token::Keyword(crate::lexer::Keyword::Keyof) => {
                 self.advance();
-                Ok(Type::Any) // infer -> any for now
+                let target_type = self.parse_primary_type()?;
+                Ok(Type::KeyOf(Box::new(target_type)))
             }
             Token::Keyword(crate::lexer::Keyword::Null) => {
                 self.advance();
@@ -1001,53 +1424,32 @@ impl Parser {
                 Ok(Type::Undefined)
             }
             Token::Identifier(name) => {
+                self.advance(); // consume the identifier token
                 // First, parse the base type (could be array type, generic type, etc.)
                 let base_type = if self.current_token() == &Token::LessThan {
                     // Parse generic type
-                    self.advance(); // consume <
-                    let mut type_args = Vec::new();
-
-                    while self.current_token() != &Token::GreaterThan && self.current_token() != &Token::EOF {
-                        let arg = self.parse_type()?;
-                        type_args.push(arg);
-
-                        if self.current_token() == &Token::Comma {
-                            self.advance(); // consume ,
-                        } else {
-                            break;
-                        }
-                    }
-
-                    self.expect_token(&Token::GreaterThan)?; // consume >
-
-                    self.advance(); // consume the identifier token
                     Type::GenericNamed {
                         name: name.to_string(),
-                        type_arguments: type_args,
+                        type_arguments: self.parse_type_arguments()?,
                     }
                 } else {
-                    self.advance(); // consume the identifier token
                     Type::Named(name.to_string())
                 };
 
-                // Then check for array brackets
-                if self.current_token() == &Token::LeftBracket {
-                    self.advance(); // consume [
-                    self.expect_token(&Token::RightBracket)?; // consume ]
-                    Ok(Type::Array(Box::new(base_type)))
-                } else {
-                    Ok(base_type)
-                }
+                // Then check for array brackets and indexed access
+                self.parse_type_suffixes(base_type)
             }
             Token::String(s) => {
                 self.advance();
-                // String literal type
-                Ok(Type::Named(format!("\"{}\"", s)))
+                Ok(Type::Literal(Literal::String(s)))
             }
             Token::Number(n) => {
                 self.advance();
-                // Number literal type
-                Ok(Type::Named(n.to_string()))
+                Ok(Type::Literal(Literal::Number(n)))
+            }
+            Token::Boolean(b) => {
+                self.advance();
+                Ok(Type::Literal(Literal::Boolean(b)))
             }
             Token::LeftParen => {
                 self.advance();
@@ -1062,9 +1464,17 @@ impl Parser {
 
            while self.current_token() != &Token::RightBrace && self.current_token() != &Token::EOF {
                // Check if this is a mapped type: [P in K] or index signature: [key: type]
-               if self.current_token() == &Token::LeftBracket {
+               // Mapped types may start with a `readonly`, `+readonly` or `-readonly` modifier
+               let mut bracket = self.position;
+               while matches!(
+                   self.tokens.get(bracket),
+                   Some(Token::Plus | Token::Minus | Token::Keyword(Keyword::Readonly))
+               ) {
+                   bracket += 1;
+               }
+               if self.tokens.get(bracket) == Some(&Token::LeftBracket) {
                    // Look ahead to determine if this is a mapped type or index signature
-                   let mut pos = self.position + 1; // skip [
+                   let mut pos = bracket + 1; // skip [
                    let mut is_mapped_type = false;
                    
                    // Look for 'in' keyword to distinguish mapped type from index signature
@@ -1078,6 +1488,11 @@ impl Parser {
                    
                    if is_mapped_type {
                        let mapped_type = self.parse_mapped_type()?;
+                       // `{ [K in T]: V }` on its own is a mapped type
+                       if members.is_empty() && self.current_token() == &Token::RightBrace {
+                           self.advance();
+                           return Ok(Type::Mapped(Box::new(mapped_type)));
+                       }
                        members.push(ObjectTypeMember::Property(PropertySignature {
                            name: mapped_type.type_parameter.name.clone(),
                            optional: false,
@@ -1111,15 +1526,34 @@ impl Parser {
                    } else {
                        false
                    };
-                   self.expect_token(&Token::Colon)?;
-                   let type_ = self.parse_type()?;
-
-                   members.push(ObjectTypeMember::Property(PropertySignature {
-                       name,
-                       optional,
-                       type_: Some(type_),
-                       readonly,
-                   }));
+
+                   if self.current_token() == &Token::LeftParen {
+                       // Method signature: name(params): ReturnType
+                       let parameters = self.parse_parameters()?;
+                       let return_type = if self.current_token() == &Token::Colon {
+                           self.advance();
+                           Some(self.parse_type()?)
+                       } else {
+                           None
+                       };
+                       members.push(ObjectTypeMember::Method(MethodSignature {
+                           name,
+                           optional,
+                           type_parameters: Vec::new(),
+                           parameters,
+                           return_type,
+                       }));
+                   } else {
+                       self.expect_token(&Token::Colon)?;
+                       let type_ = self.parse_type()?;
+
+                       members.push(ObjectTypeMember::Property(PropertySignature {
+                           name,
+                           optional,
+                           type_: Some(type_),
+                           readonly,
+                       }));
+                   }
                }
 
                if self.current_token() == &Token::Semicolon {
@@ -1143,6 +1577,10 @@ impl Parser {
         &self.tokens[self.position]
     }
 
+    fn peek_token(&self) -> Option<&Token> {
+        self.tokens.get(self.position + 1)
+    }
+
     fn advance(&mut self) {
         if self.position < self.tokens.len() {
             self.position += 1;
@@ -1194,6 +1632,18 @@ impl Parser {
         self.expect_token(&Token::Semicolon)
     }
 
+    /// Consume the `;` or `,` after an interface member, optional before `}`
+    fn expect_member_separator(&mut self) -> Result<()> {
+        match self.current_token() {
+            Token::Semicolon | Token::Comma => {
+                self.advance();
+                Ok(())
+            }
+            Token::RightBrace => Ok(()),
+            _ => self.expect_token(&Token::Semicolon),
+        }
+    }
+
     fn parse_string_literal(&mut self) -> Result<String> {
         if let Token::String(s) = self.current_token() {
             let s = s.clone();
@@ -1216,6 +1666,7 @@ impl Parser {
                 | Token::MinusAssign
                 | Token::MultiplyAssign
                 | Token::DivideAssign
+                | Token::ModuloAssign
         )
     }
 
@@ -1229,7 +1680,12 @@ impl Parser {
     fn is_relational_operator(&self) -> bool {
         matches!(
             self.current_token(),
-            Token::LessThan | Token::GreaterThan | Token::LessEqual | Token::GreaterEqual
+            Token::LessThan
+                | Token::GreaterThan
+                | Token::LessEqual
+                | Token::GreaterEqual
+                | Token::Keyword(crate::lexer::Keyword::In)
+                | Token::Keyword(crate::lexer::Keyword::Instanceof)
         )
     }
 
@@ -1254,7 +1710,7 @@ impl Parser {
     fn is_postfix_operator(&self) -> bool {
         matches!(
             self.current_token(),
-            Token::LeftParen | Token::LeftBracket | Token::Dot
+            Token::LeftParen | Token::LeftBracket | Token::Dot | Token::QuestionDot | Token::Not
         )
     }
 
@@ -1308,20 +1764,25 @@ impl Parser {
 
         while self.current_token() != &Token::RightParen {
             // Handle access modifiers on parameters (TypeScript feature)
-            let mut _modifiers = Vec::new();
+            let mut modifiers = Vec::new();
             while let Token::Keyword(keyword) = self.current_token() {
-                match keyword {
-                    crate::lexer::Keyword::Public | 
-                    crate::lexer::Keyword::Private | 
-                    crate::lexer::Keyword::Protected | 
-                    crate::lexer::Keyword::Readonly => {
-                        _modifiers.push(keyword.clone());
-                        self.advance();
-                    }
+                let modifier = match keyword {
+                    crate::lexer::Keyword::Public => crate::ast::Modifier::Public,
+                    crate::lexer::Keyword::Private => crate::ast::Modifier::Private,
+                    crate::lexer::Keyword::Protected => crate::ast::Modifier::Protected,
+                    crate::lexer::Keyword::Readonly => crate::ast::Modifier::Readonly,
                     _ => break,
-                }
+                };
+                modifiers.push(modifier);
+                self.advance();
             }
             
+            let rest = if self.current_token() == &Token::Ellipsis {
+                self.advance();
+                true
+            } else {
+                false
+            };
             let name = self.expect_identifier()?;
             let optional = if self.current_token() == &Token::QuestionMark {
                 self.advance();
@@ -1349,7 +1810,8 @@ impl Parser {
                 optional,
                 type_: type_annotation.map(Box::new),
                 initializer,
-                rest: false,
+                rest,
+                modifiers,
             });
 
             if self.current_token() == &Token::Comma {
@@ -1366,20 +1828,25 @@ impl Parser {
 
         while self.current_token() != &Token::RightParen {
             // Handle access modifiers on parameters (TypeScript feature)
-            let mut _modifiers = Vec::new();
+            let mut modifiers = Vec::new();
             while let Token::Keyword(keyword) = self.current_token() {
-                match keyword {
-                    crate::lexer::Keyword::Public | 
-                    crate::lexer::Keyword::Private | 
-                    crate::lexer::Keyword::Protected | 
-                    crate::lexer::Keyword::Readonly => {
-                        _modifiers.push(keyword.clone());
-                        self.advance();
-                    }
+                let modifier = match keyword {
+                    crate::lexer::Keyword::Public => crate::ast::Modifier::Public,
+                    crate::lexer::Keyword::Private => crate::ast::Modifier::Private,
+                    crate::lexer::Keyword::Protected => crate::ast::Modifier::Protected,
+                    crate::lexer::Keyword::Readonly => crate::ast::Modifier::Readonly,
                     _ => break,
-                }
+                };
+                modifiers.push(modifier);
+                self.advance();
             }
             
+            let rest = if self.current_token() == &Token::Ellipsis {
+                self.advance();
+                true
+            } else {
+                false
+            };
             let name = self.expect_identifier()?;
             let optional = if self.current_token() == &Token::QuestionMark {
                 self.advance();
@@ -1407,7 +1874,8 @@ impl Parser {
                 optional,
                 type_: type_annotation.map(Box::new),
                 initializer,
-                rest: false,
+                rest,
+                modifiers,
             });
 
             if self.current_token() == &Token::Comma {
@@ -1511,15 +1979,35 @@ impl Parser {
     fn parse_import_specifiers(&mut self) -> Result<Vec<ImportSpecifier>> {
         let mut specifiers = Vec::new();
 
-        if self.current_token() == &Token::LeftBrace {
+        // A default import may be followed by named or namespace imports
+        if let Token::Identifier(_) = self.current_token() {
+            let name = self.expect_identifier()?;
+            specifiers.push(ImportSpecifier::Default(DefaultImportSpecifier { name }));
+            if self.current_token() != &Token::Comma {
+                return Ok(specifiers);
+            }
+            self.advance(); // consume ','
+        }
+
+        if self.current_token() == &Token::Multiply {
+            // Namespace import: * as name
+            self.advance();
+            self.expect_as()?;
+            let name = self.expect_identifier()?;
+            specifiers.push(ImportSpecifier::Namespace(NamespaceImportSpecifier { name }));
+        } else if self.current_token() == &Token::LeftBrace {
             self.advance(); // consume '{'
 
             while self.current_token() != &Token::RightBrace {
-                let name = self.expect_identifier()?;
-                specifiers.push(ImportSpecifier::Named(NamedImportSpecifier {
-                    imported: name.clone(),
-                    name,
-                }));
+                let imported = self.expect_identifier()?;
+                // `imported as local` binds the import under another name
+                let name = if self.is_as() {
+                    self.advance();
+                    self.expect_identifier()?
+                } else {
+                    imported.clone()
+                };
+                specifiers.push(ImportSpecifier::Named(NamedImportSpecifier { imported, name }));
 
                 if self.current_token() == &Token::Comma {
                     self.advance();
@@ -1528,19 +2016,66 @@ impl Parser {
 
             self.expect_token(&Token::RightBrace)?; // consume '}'
         } else {
-            // Default import
-            let name = self.expect_identifier()?;
-            specifiers.push(ImportSpecifier::Default(DefaultImportSpecifier { name }));
+            return Err(CompilerError::parse_error(
+                self.position,
+                0,
+                format!("Expected import specifiers, found {:?}", self.current_token()),
+            ));
         }
 
         Ok(specifiers)
     }
 
+    /// Whether the current token is `as`, a contextual keyword lexed as an identifier
+    fn is_as(&self) -> bool {
+        matches!(self.current_token(), Token::Identifier(name) if name == "as")
+    }
+
+    /// Consume `as`
+    fn expect_as(&mut self) -> Result<()> {
+        if !self.is_as() {
+            return Err(CompilerError::parse_error(
+                self.position,
+                0,
+                format!("Expected 'as', found {:?}", self.current_token()),
+            ));
+        }
+        self.advance();
+        Ok(())
+    }
+
+    /// Parse type arguments such as `<string, number>`
+    fn parse_type_arguments(&mut self) -> Result<Vec<Type>> {
+        self.expect_token(&Token::LessThan)?; // consume <
+        let mut type_args = Vec::new();
+
+        while self.current_token() != &Token::GreaterThan && self.current_token() != &Token::EOF {
+            let arg = self.parse_type()?;
+            type_args.push(arg);
+
+            if self.current_token() == &Token::Comma {
+                self.advance(); // consume ,
+            } else {
+                break;
+            }
+        }
+
+        self.expect_token(&Token::GreaterThan)?; // consume >
+        Ok(type_args)
+    }
+
     fn parse_arguments(&mut self) -> Result<Vec<Expression>> {
         let mut arguments = Vec::new();
 
         while self.current_token() != &Token::RightParen {
-            let argument = self.parse_expression()?;
+            let argument = if self.current_token() == &Token::Ellipsis {
+                self.advance();
+                Expression::Spread(SpreadElement {
+                    argument: Box::new(self.parse_expression()?),
+                })
+            } else {
+                self.parse_expression()?
+            };
             arguments.push(argument);
 
             if self.current_token() == &Token::Comma {
@@ -1559,29 +2094,9 @@ impl Parser {
 
     fn parse_class_member(&mut self) -> Result<ClassMember> {
         let mut modifiers = Vec::new();
-        let mut decorators = Vec::new();
 
         // Parse decorators first
-        while self.current_token() == &Token::At {
-            self.advance(); // consume @
-            let decorator_name = self.expect_identifier()?;
-            decorators.push(decorator_name);
-
-            // Skip arguments for now (e.g., @log())
-            if self.current_token() == &Token::LeftParen {
-                self.advance(); // consume (
-                // Skip arguments until closing paren
-                let mut paren_count = 1;
-                while paren_count > 0 && self.position < self.tokens.len() {
-                    match self.current_token() {
-                        Token::LeftParen => paren_count += 1,
-                        Token::RightParen => paren_count -= 1,
-                        _ => {}
-                    }
-                    self.advance();
-                }
-            }
-        }
+        let decorators = self.parse_decorators()?;
 
         // Parse access modifiers
         while let Token::Keyword(keyword) = self.current_token() {
@@ -1701,6 +2216,7 @@ impl Parser {
                         type_: Some(Box::new(type_annotation)),
                         initializer: None,
                         rest: false,
+                        modifiers: Vec::new(),
                     }
                 } else {
                     return Err(CompilerError::parse_error(
@@ -1730,10 +2246,14 @@ impl Parser {
             }
             Token::Identifier(name) => {
                 self.advance();
+                let optional = self.current_token() == &Token::QuestionMark;
+                if optional {
+                    self.advance();
+                }
 
                 // Special handling for constructor
                 if name == "constructor" {
-                    println!("DEBUG: parsing constructor");
+                    tracing::debug!("parsing constructor");
                     // It's a constructor
                     let parameters = self.parse_parameters()?;
                     let body = self.parse_block_statement()?;
@@ -1753,7 +2273,13 @@ impl Parser {
                     } else {
                         None
                     };
-                    let body = self.parse_block_statement()?;
+                    // Methods of declared classes have no body
+                    let body = if self.current_token() == &Token::Semicolon {
+                        self.advance();
+                        None
+                    } else {
+                        Some(self.parse_block_statement()?)
+                    };
 
                     Ok(ClassMember::Method(MethodDeclaration {
                         name,
@@ -1761,7 +2287,7 @@ impl Parser {
                         type_parameters: Vec::new(),
                         parameters,
                         return_type,
-                        body: Some(body),
+                        body,
                         modifiers,
                         decorators,
                     }))
@@ -1781,7 +2307,7 @@ impl Parser {
 
                     Ok(ClassMember::Property(PropertyDeclaration {
                         name,
-                        optional: false,
+                        optional,
                         type_: Some(type_annotation),
                         initializer,
                         modifiers,
@@ -1840,7 +2366,7 @@ impl Parser {
                     // It's a property signature
                     self.advance();
                     let type_annotation = self.parse_type()?;
-                    self.expect_token(&Token::Semicolon)?;
+                    self.expect_member_separator()?;
 
                     return Ok(ObjectTypeMember::Property(PropertySignature {
                         name,
@@ -1872,10 +2398,15 @@ impl Parser {
                     ));
                 };
 
-                // Look ahead to see if this is followed by '('
-                if self.position + 1 < self.tokens.len() && self.tokens[self.position + 1] == Token::LeftParen {
+                // Look ahead to see if this is followed by '(' or '?('
+                let optional_method = self.tokens.get(self.position + 1) == Some(&Token::QuestionMark)
+                    && self.tokens.get(self.position + 2) == Some(&Token::LeftParen);
+                if optional_method || self.tokens.get(self.position + 1) == Some(&Token::LeftParen) {
                     // This is a method signature
                     self.advance(); // consume method name
+                    if optional_method {
+                        self.advance(); // consume ?
+                    }
                     let parameters = self.parse_parameters()?;
                     let return_type = if self.current_token() == &Token::Colon {
                         self.advance();
@@ -1883,11 +2414,11 @@ impl Parser {
                     } else {
                         None
                     };
-                    self.expect_token(&Token::Semicolon)?;
+                    self.expect_member_separator()?;
 
                     Ok(ObjectTypeMember::Method(MethodSignature {
                         name,
-                        optional: false,
+                        optional: optional_method,
                         type_parameters: Vec::new(),
                         parameters,
                         return_type,
@@ -1904,7 +2435,7 @@ impl Parser {
 
                     self.expect_token(&Token::Colon)?;
                     let type_annotation = self.parse_type()?;
-                    self.expect_token(&Token::Semicolon)?;
+                    self.expect_member_separator()?;
 
                     Ok(ObjectTypeMember::Property(PropertySignature {
                         name,
@@ -1923,7 +2454,7 @@ impl Parser {
                 } else {
                     None
                 };
-                self.expect_token(&Token::Semicolon)?;
+                self.expect_member_separator()?;
 
                 Ok(ObjectTypeMember::Method(MethodSignature {
                     name: "call".to_string(), // Use a default name for call signatures
@@ -1943,7 +2474,7 @@ impl Parser {
                 } else {
                     None
                 };
-                self.expect_token(&Token::Semicolon)?;
+                self.expect_member_separator()?;
 
                 Ok(ObjectTypeMember::Method(MethodSignature {
                     name: "constructor".to_string(), // Use a default name for construct signatures
@@ -1962,7 +2493,7 @@ impl Parser {
                     // It's a property signature
                     self.advance();
                     let type_annotation = self.parse_type()?;
-                    self.expect_token(&Token::Semicolon)?;
+                    self.expect_member_separator()?;
 
                     Ok(ObjectTypeMember::Property(PropertySignature {
                         name,
@@ -1986,10 +2517,6 @@ impl Parser {
                         self.advance();
                         name
                     }
-                    Token::Keyword(crate::lexer::Keyword::Key) => {
-                        self.advance();
-                        "key".to_string()
-                    }
                     _ => return Err(CompilerError::parse_error(
                         1, 1,
                         "Expected identifier or 'key' in index signature".to_string(),
@@ -2000,7 +2527,7 @@ impl Parser {
                 self.expect_token(&Token::RightBracket)?;
                 self.expect_token(&Token::Colon)?;
                 let value_type = self.parse_type()?;
-                self.expect_token(&Token::Semicolon)?;
+                self.expect_member_separator()?;
 
                 Ok(ObjectTypeMember::Index(IndexSignature {
                     parameter: Box::new(Parameter {
@@ -2009,6 +2536,7 @@ impl Parser {
                         optional: false,
                         initializer: None,
                         rest: false,
+                        modifiers: Vec::new(),
                     }),
                     type_: value_type,
                     readonly: false,
@@ -2058,9 +2586,195 @@ impl Parser {
         })))
     }
 
+    /// Parse `do body while (condition);`
+    fn parse_do_while_statement(&mut self) -> Result<Statement> {
+        self.expect_keyword()?; // do
+        let body = self.parse_statement()?.ok_or_else(|| {
+            CompilerError::parse_error(self.position, 0, "Expected do...while loop body")
+        })?;
+        match self.current_token() {
+            Token::Keyword(crate::lexer::Keyword::While) => self.advance(),
+            _ => return Err(CompilerError::parse_error(self.position, 0, "Expected 'while' after do body")),
+        }
+        self.expect_token(&Token::LeftParen)?;
+        let condition = self.parse_expression()?;
+        self.expect_token(&Token::RightParen)?;
+
+        // Optional semicolon
+        if self.current_token() == &Token::Semicolon {
+            self.advance();
+        }
+
+        Ok(Statement::DoWhileStatement(DoWhileStatement {
+            body: Box::new(body),
+            condition,
+        }))
+    }
+
+    /// Parse for statement
+    fn parse_for_statement(&mut self) -> Result<Statement> {
+        self.expect_keyword()?; // for
+        self.expect_token(&Token::LeftParen)?;
+
+        if self.is_classic_for_loop() {
+            return self.parse_classic_for_statement();
+        }
+
+        let keyword = match self.current_token() {
+            Token::Keyword(
+                keyword @ (crate::lexer::Keyword::Const
+                | crate::lexer::Keyword::Let
+                | crate::lexer::Keyword::Var),
+            ) => keyword.clone(),
+            _ => return Err(CompilerError::unsupported_feature("for loops other than for...of and for...in")),
+        };
+        self.advance();
+        let binding = self.parse_binding_pattern()?;
+
+        // 'of' is a contextual keyword, lexed as an identifier
+        match self.current_token() {
+            Token::Identifier(name) if name == "of" => self.advance(),
+            Token::Keyword(crate::lexer::Keyword::In) => {
+                self.advance();
+                return self.parse_for_in_rest(keyword, binding);
+            }
+            _ => return Err(CompilerError::unsupported_feature("for loops other than for...of and for...in")),
+        }
+
+        let iterable = self.parse_expression()?;
+        self.expect_token(&Token::RightParen)?;
+        let body = self.parse_statement()?.ok_or_else(|| {
+            CompilerError::parse_error(self.position, 0, "Expected for...of loop body")
+        })?;
+
+        Ok(Statement::ForOfStatement(ForOfStatement {
+            keyword,
+            binding,
+            iterable,
+            body: Box::new(body),
+        }))
+    }
+
+    /// Parse the rest of `for (const key in object) body` after the `in`
+    fn parse_for_in_rest(&mut self, keyword: crate::lexer::Keyword, binding: BindingPattern) -> Result<Statement> {
+        let BindingPattern::Identifier(name) = binding else {
+            return Err(CompilerError::parse_error(self.position, 0, "for...in loops bind a single variable"));
+        };
+        let object = self.parse_expression()?;
+        self.expect_token(&Token::RightParen)?;
+        let body = self.parse_statement()?.ok_or_else(|| {
+            CompilerError::parse_error(self.position, 0, "Expected for...in loop body")
+        })?;
+
+        Ok(Statement::ForInStatement(ForInStatement {
+            keyword,
+            name,
+            object,
+            body: Box::new(body),
+        }))
+    }
+
+    /// Whether the loop header after `(` has a top-level `;`, as in `for (init; test; update)`
+    fn is_classic_for_loop(&self) -> bool {
+        let mut depth = 0;
+        for token in &self.tokens[self.position..] {
+            match token {
+                Token::LeftParen | Token::LeftBracket | Token::LeftBrace => depth += 1,
+                Token::RightParen if depth == 0 => return false,
+                Token::RightParen | Token::RightBracket | Token::RightBrace => depth -= 1,
+                Token::Semicolon if depth == 0 => return true,
+                Token::EOF => return false,
+                _ => {}
+            }
+        }
+        false
+    }
+
+    /// Parse the rest of `for (init; test; update) body` after the `(`
+    fn parse_classic_for_statement(&mut self) -> Result<Statement> {
+        let init = match self.current_token() {
+            Token::Semicolon => {
+                self.advance();
+                None
+            }
+            Token::Keyword(
+                crate::lexer::Keyword::Const
+                | crate::lexer::Keyword::Let
+                | crate::lexer::Keyword::Var,
+            ) => Some(Box::new(self.parse_variable_declaration()?)),
+            _ => {
+                let expression = self.parse_expression()?;
+                self.expect_token(&Token::Semicolon)?;
+                Some(Box::new(Statement::ExpressionStatement(ExpressionStatement {
+                    expression,
+                })))
+            }
+        };
+
+        let condition = if self.current_token() == &Token::Semicolon {
+            None
+        } else {
+            Some(self.parse_expression()?)
+        };
+        self.expect_token(&Token::Semicolon)?;
+
+        let update = if self.current_token() == &Token::RightParen {
+            None
+        } else {
+            Some(self.parse_expression()?)
+        };
+        self.expect_token(&Token::RightParen)?;
+
+        let body = self.parse_statement()?.ok_or_else(|| {
+            CompilerError::parse_error(self.position, 0, "Expected for loop body")
+        })?;
+
+        Ok(Statement::ForStatement(ForStatement {
+            init,
+            condition,
+            update,
+            body: Box::new(body),
+        }))
+    }
+
+    /// Parse binding pattern: identifier or array destructuring
+    fn parse_binding_pattern(&mut self) -> Result<BindingPattern> {
+        if self.current_token() != &Token::LeftBracket {
+            return Ok(BindingPattern::Identifier(self.expect_identifier()?));
+        }
+
+        self.advance(); // consume '['
+        let mut elements = Vec::new();
+        while self.current_token() != &Token::RightBracket {
+            if self.current_token() == &Token::Comma {
+                // Hole such as [, second]
+                self.advance();
+                elements.push(None);
+                continue;
+            }
+            elements.push(Some(self.parse_binding_pattern()?));
+            if self.current_token() == &Token::Comma {
+                self.advance();
+            }
+        }
+        self.expect_token(&Token::RightBracket)?;
+
+        Ok(BindingPattern::Array(elements))
+    }
+
     fn parse_property_key(&mut self) -> Result<Expression> {
-        // TODO: Implement property key parsing
-        self.parse_expression()
+        // A plain key must not swallow a following `(` as a call
+        match self.current_token().clone() {
+            Token::Identifier(name) => {
+                self.advance();
+                Ok(Expression::Identifier(name))
+            }
+            Token::String(value) => {
+                self.advance();
+                Ok(Expression::Literal(Literal::String(value)))
+            }
+            _ => self.parse_expression(),
+        }
     }
 
     /// Parse index signature: [key: type]: returnType
@@ -2073,10 +2787,6 @@ impl Parser {
                 self.advance();
                 name
             }
-            Token::Keyword(Keyword::Key) => {
-                self.advance();
-                "key".to_string()
-            }
             _ => {
                 return Err(CompilerError::parse_error(
                     self.position,
@@ -2099,6 +2809,7 @@ impl Parser {
                 optional: false,
                 initializer: None,
                 rest: false,
+                modifiers: Vec::new(),
             }),
             type_: value_type,
             readonly: false,
@@ -2107,7 +2818,8 @@ impl Parser {
 
     /// Parse mapped type: [P in K] or [P in keyof T]
     fn parse_mapped_type(&mut self) -> Result<MappedType> {
-        // Parse [P in K]: T
+        // Parse [P in K]: T with optional readonly and ? modifiers
+        let readonly = self.parse_mapped_modifier(&Token::Keyword(Keyword::Readonly))?;
         self.expect_token(&Token::LeftBracket)?;
         
         let type_parameter_name = match self.current_token() {
@@ -2116,10 +2828,6 @@ impl Parser {
                 self.advance();
                 name
             }
-            Token::Keyword(Keyword::Key) => {
-                self.advance();
-                "Key".to_string()
-            }
             _ => {
                 return Err(CompilerError::parse_error(
                     self.position,
@@ -2148,6 +2856,7 @@ impl Parser {
         let constraint_type = self.parse_type()?;
         
         self.expect_token(&Token::RightBracket)?;
+        let optional = self.parse_mapped_modifier(&Token::QuestionMark)?;
         self.expect_token(&Token::Colon)?;
         
         let value_type = self.parse_type()?;
@@ -2162,9 +2871,25 @@ impl Parser {
             constraint: Some(Box::new(constraint_type)),
             name_type: None,
             type_: Box::new(value_type),
-            readonly: None,
-            optional: None,
+            readonly,
+            optional,
         })
     }
 
+    /// Parse a mapped type modifier: `Some(true)` when added, `Some(false)` when removed with `-`
+    fn parse_mapped_modifier(&mut self, modifier: &Token) -> Result<Option<bool>> {
+        let added = match self.current_token() {
+            Token::Plus => true,
+            Token::Minus => false,
+            token if token == modifier => {
+                self.advance();
+                return Ok(Some(true));
+            }
+            _ => return Ok(None),
+        };
+        self.advance();
+        self.expect_token(modifier)?;
+        Ok(Some(added))
+    }
+
 }
@@ -1,15 +1,41 @@
 //! Semantic analysis for TypeScript code
 
 use crate::ast::*;
-use crate::error::Result;
-use std::collections::HashMap;
+use crate::error::{CompilerError, Result};
+use crate::lexer::{Keyword, Token};
+use std::collections::{HashMap, HashSet};
+
+/// Names provided by the JavaScript environment that need no declaration
+const GLOBALS: &[&str] = &[
+    "console", "Math", "JSON", "Object", "Array", "String", "Number", "Boolean", "Symbol",
+    "BigInt", "Date", "RegExp", "Error", "Promise", "Map", "Set", "WeakMap", "WeakSet",
+    "parseInt", "parseFloat", "isNaN", "isFinite", "undefined", "NaN", "Infinity",
+    "globalThis", "window", "document", "process", "require", "setTimeout", "setInterval",
+    "clearTimeout", "clearInterval",
+];
 
 /// Semantic analyzer for TypeScript code
 pub struct SemanticAnalyzer {
-    /// Symbol table for variables and functions
-    symbols: HashMap<String, SymbolInfo>,
+    /// Symbol tables for variables and functions, innermost scope last
+    scopes: Vec<HashMap<String, SymbolInfo>>,
     /// Current scope
     current_scope: Vec<String>,
+    /// Property names of declared classes, interfaces and object type aliases
+    type_properties: HashMap<String, HashSet<String>>,
+    /// Declarations of bindings assigned after their declaration, by `declaration_key`
+    reassigned: HashSet<usize>,
+    /// `in` checks whose object is a map, by `binary_key`
+    map_key_checks: HashSet<usize>,
+    /// Operands of `as` casts and type assertions that hold strings, by `expression_key`
+    string_cast_operands: HashSet<usize>,
+    /// Optional parameters, by `parameter_key`
+    optional_parameters: HashSet<usize>,
+    /// Expressions holding an `Option`, by `expression_key`
+    optional_values: HashSet<usize>,
+    /// Errors recorded so far; analysis continues past them
+    errors: Vec<CompilerError>,
+    /// Errors that leave no valid translation, such as assigning to a constant
+    fatal_errors: Vec<CompilerError>,
 }
 
 /// Information about a symbol
@@ -18,7 +44,11 @@ pub struct SymbolInfo {
     pub name: String,
     pub symbol_type: SymbolType,
     pub scope: Vec<String>,
+    /// Whether the binding may be reassigned
+    pub mutable: bool,
     pub defined_at: usize,
+    /// Identity of the declaring variable statement or parameter, telling shadowed bindings apart
+    pub(crate) declaration: Option<usize>,
 }
 
 /// Type of symbol
@@ -69,17 +99,52 @@ impl SemanticAnalyzer {
     /// Create a new semantic analyzer
     pub fn new() -> Self {
         Self {
-            symbols: HashMap::new(),
+            scopes: vec![HashMap::new()],
             current_scope: Vec::new(),
+            type_properties: HashMap::new(),
+            reassigned: HashSet::new(),
+            map_key_checks: HashSet::new(),
+            string_cast_operands: HashSet::new(),
+            optional_parameters: HashSet::new(),
+            optional_values: HashSet::new(),
+            errors: Vec::new(),
+            fatal_errors: Vec::new(),
         }
     }
 
-    /// Analyze a program
+    /// Analyze a program, combining every error found into one
     pub fn analyze(&mut self, program: &Program) -> Result<()> {
-        for statement in &program.statements {
-            self.analyze_statement(statement)?;
+        let mut errors = self.analyze_all(program);
+        errors.append(&mut self.fatal_errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(CompilerError::multiple(errors))
+        }
+    }
+
+    /// Analyze a program, returning every advisory error found
+    ///
+    /// A failed statement is skipped and analysis continues with the next one, so later
+    /// declarations and reassignments are still recorded. Fatal errors are kept for
+    /// `take_fatal_errors`.
+    pub fn analyze_all(&mut self, program: &Program) -> Vec<CompilerError> {
+        self.hoist_functions(&program.statements);
+        self.analyze_statements(&program.statements);
+        std::mem::take(&mut self.errors)
+    }
+
+    /// Analyze statements in order, recording the error of each failed statement
+    fn analyze_statements(&mut self, statements: &[Statement]) {
+        for statement in statements {
+            let depth = self.scopes.len();
+            if let Err(error) = self.analyze_statement(statement) {
+                // Drop the scopes left open by the failed statement
+                self.scopes.truncate(depth);
+                self.current_scope.truncate(depth - 1);
+                self.errors.push(error);
+            }
         }
-        Ok(())
     }
 
     /// Analyze a statement
@@ -100,16 +165,82 @@ impl SemanticAnalyzer {
             Statement::TypeAlias(type_alias) => {
                 self.analyze_type_alias(type_alias)?;
             }
-            Statement::EnumDeclaration(enum_decl) => {
+            Statement::EnumDeclaration(enum_decl) | Statement::ConstEnumDeclaration(enum_decl) => {
                 self.analyze_enum_declaration(enum_decl)?;
             }
             Statement::BlockStatement(block) => {
                 self.enter_scope();
-                for stmt in &block.statements {
-                    self.analyze_statement(stmt)?;
+                self.hoist_functions(&block.statements);
+                self.analyze_statements(&block.statements);
+                self.exit_scope();
+            }
+            Statement::ExpressionStatement(expr_stmt) => {
+                self.analyze_expression(&expr_stmt.expression)?;
+            }
+            Statement::ReturnStatement(ret) => {
+                if let Some(ref argument) = ret.argument {
+                    self.analyze_expression(argument)?;
+                }
+            }
+            Statement::IfStatement(if_stmt) => {
+                self.analyze_if_statement(if_stmt)?;
+            }
+            Statement::WhileStatement(while_stmt) => {
+                self.analyze_expression(&while_stmt.condition)?;
+                self.analyze_statement(&while_stmt.body)?;
+            }
+            Statement::DoWhileStatement(do_while) => {
+                self.analyze_statement(&do_while.body)?;
+                self.analyze_expression(&do_while.condition)?;
+            }
+            Statement::ForStatement(for_stmt) => {
+                // The loop variable is scoped to the loop
+                self.enter_scope();
+                if let Some(ref init) = for_stmt.init {
+                    self.analyze_statement(init)?;
                 }
+                if let Some(ref condition) = for_stmt.condition {
+                    self.analyze_expression(condition)?;
+                }
+                if let Some(ref update) = for_stmt.update {
+                    self.analyze_expression(update)?;
+                }
+                self.analyze_statement(&for_stmt.body)?;
+                self.exit_scope();
+            }
+            Statement::ForOfStatement(for_of) => {
+                self.analyze_expression(&for_of.iterable)?;
+                self.enter_scope();
+                self.declare_binding(&for_of.binding, for_of.keyword != Keyword::Const);
+                self.analyze_statement(&for_of.body)?;
                 self.exit_scope();
             }
+            Statement::ForInStatement(for_in) => {
+                self.analyze_expression(&for_in.object)?;
+                self.enter_scope();
+                self.declare(SymbolInfo {
+                    name: for_in.name.clone(),
+                    symbol_type: SymbolType::Variable(Type::String),
+                    scope: self.current_scope.clone(),
+                    mutable: for_in.keyword != Keyword::Const,
+                    defined_at: 0, // TODO: Get actual position
+                    declaration: None,
+                });
+                self.analyze_statement(&for_in.body)?;
+                self.exit_scope();
+            }
+            Statement::LabeledStatement(labeled) => {
+                self.analyze_statement(&labeled.body)?;
+            }
+            Statement::ImportDeclaration(import) => {
+                self.analyze_import_declaration(import);
+            }
+            Statement::ExportDeclaration(export) => {
+                self.analyze_statement(&export.declaration)?;
+            }
+            Statement::DeclareStatement(declare) => {
+                self.analyze_statement(&declare.declaration)?;
+            }
             _ => {
                 // Handle other statement types
             }
@@ -117,8 +248,337 @@ impl SemanticAnalyzer {
         Ok(())
     }
 
+    /// Declare the functions of a statement list up front so they can be called before their definition
+    fn hoist_functions(&mut self, statements: &[Statement]) {
+        for statement in statements {
+            let func = match statement {
+                Statement::FunctionDeclaration(func) => func,
+                Statement::ExportDeclaration(export) => match &*export.declaration {
+                    Statement::FunctionDeclaration(func) => func,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            self.declare(SymbolInfo {
+                name: func.name.clone(),
+                symbol_type: SymbolType::Function(function_signature(func)),
+                scope: self.current_scope.clone(),
+                mutable: true,
+                defined_at: 0, // TODO: Get actual position
+                declaration: None,
+            });
+        }
+    }
+
+    /// Declare the names bound by a loop binding pattern
+    fn declare_binding(&mut self, binding: &BindingPattern, mutable: bool) {
+        match binding {
+            BindingPattern::Identifier(name) => self.declare(SymbolInfo {
+                name: name.clone(),
+                symbol_type: SymbolType::Variable(Type::Any),
+                scope: self.current_scope.clone(),
+                mutable,
+                defined_at: 0, // TODO: Get actual position
+                declaration: None,
+            }),
+            BindingPattern::Array(elements) => {
+                for element in elements.iter().flatten() {
+                    self.declare_binding(element, mutable);
+                }
+            }
+        }
+    }
+
+    /// Declare the local names bound by an import
+    fn analyze_import_declaration(&mut self, import: &ImportDeclaration) {
+        for specifier in &import.specifiers {
+            let name = match specifier {
+                ImportSpecifier::Named(named) => &named.name,
+                ImportSpecifier::Default(default) => &default.name,
+                ImportSpecifier::Namespace(namespace) => &namespace.name,
+            };
+            self.declare(SymbolInfo {
+                name: name.clone(),
+                symbol_type: SymbolType::Variable(Type::Any),
+                scope: self.current_scope.clone(),
+                mutable: false,
+                defined_at: 0, // TODO: Get actual position
+                declaration: None,
+            });
+        }
+    }
+
+    /// Analyze if statement, narrowing `"prop" in value` checks in each branch
+    fn analyze_if_statement(&mut self, if_stmt: &IfStatement) -> Result<()> {
+        self.analyze_expression(&if_stmt.condition)?;
+
+        let narrowing = self.in_operator_narrowing(&if_stmt.condition);
+        let (consequent_type, alternate_type) = match &narrowing {
+            Some((_, with, without)) => (with.clone(), without.clone()),
+            None => (None, None),
+        };
+        let name = narrowing.as_ref().map(|(name, _, _)| name.as_str());
+
+        self.analyze_narrowed(&if_stmt.consequent, name, consequent_type)?;
+        if let Some(ref alternate) = if_stmt.alternate {
+            self.analyze_narrowed(alternate, name, alternate_type)?;
+        }
+        Ok(())
+    }
+
+    /// Analyze a statement with a variable temporarily narrowed to another type
+    fn analyze_narrowed(
+        &mut self,
+        statement: &Statement,
+        name: Option<&str>,
+        narrowed: Option<Type>,
+    ) -> Result<()> {
+        let (Some(name), Some(narrowed)) = (name, narrowed) else {
+            return self.analyze_statement(statement);
+        };
+
+        let previous = self.symbol_mut(name).map(|symbol| {
+            std::mem::replace(&mut symbol.symbol_type, SymbolType::Variable(narrowed))
+        });
+        let result = self.analyze_statement(statement);
+        if let Some(previous) = previous {
+            if let Some(symbol) = self.symbol_mut(name) {
+                symbol.symbol_type = previous;
+            }
+        }
+        result
+    }
+
+    /// Split a union variable by a `"prop" in value` check
+    ///
+    /// Returns the variable name and its type when the check passes and when it fails.
+    fn in_operator_narrowing(
+        &self,
+        condition: &Expression,
+    ) -> Option<(String, Option<Type>, Option<Type>)> {
+        let Expression::Binary(binary) = condition else {
+            return None;
+        };
+        if binary.operator != Token::Keyword(Keyword::In) {
+            return None;
+        }
+        let (Expression::Literal(Literal::String(property)), Expression::Identifier(name)) =
+            (&*binary.left, &*binary.right)
+        else {
+            return None;
+        };
+
+        let members = self.union_members_of(name)?;
+        let (with, without): (Vec<Type>, Vec<Type>) = members
+            .into_iter()
+            .partition(|member| self.may_have_property(member, property));
+
+        Some((name.clone(), union_of(with), union_of(without)))
+    }
+
+    /// Members of a variable's union type, resolving type aliases
+    fn union_members_of(&self, name: &str) -> Option<Vec<Type>> {
+        let SymbolType::Variable(t) = &self.get_symbol(name)?.symbol_type else {
+            return None;
+        };
+        let t = self.resolve_alias(t);
+        if !matches!(t, Type::Union { .. }) {
+            return None;
+        }
+        let mut members = Vec::new();
+        collect_union_members(&t, &mut members);
+        Some(members)
+    }
+
+    /// Whether an expression is a variable of a `Map`, `Record` or index signature type
+    fn is_map(&self, expression: &Expression) -> bool {
+        let Expression::Identifier(name) = expression else {
+            return false;
+        };
+        let Some(SymbolType::Variable(t)) = self.get_symbol(name).map(|symbol| &symbol.symbol_type) else {
+            return false;
+        };
+        match self.resolve_alias(t) {
+            Type::Generic(generic) => {
+                matches!(&*generic.type_, Type::Named(name) if name == "Map" || name == "Record")
+            }
+            Type::GenericNamed { name, .. } => name == "Map" || name == "Record",
+            Type::IndexSignature(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether an expression holds an `Option`: an optional parameter, or a `?.` chain on one
+    ///
+    /// Its subexpressions must already be analyzed.
+    fn is_optional_value(&self, expression: &Expression) -> bool {
+        let recorded = |expression: &Expression| self.optional_values.contains(&expression_key(expression));
+        match expression {
+            Expression::Identifier(name) => self
+                .get_symbol(name)
+                .and_then(|symbol| symbol.declaration)
+                .is_some_and(|declaration| self.optional_parameters.contains(&declaration)),
+            Expression::Optional(optional) => match &*optional.expression {
+                Expression::Member(member) => recorded(&member.object),
+                Expression::Call(call) => recorded(&call.callee),
+                _ => false,
+            },
+            // Accesses after a `?.` continue its chain
+            Expression::Member(member) => !matches!(*member.object, Expression::Identifier(_)) && recorded(&member.object),
+            Expression::Call(call) => !matches!(*call.callee, Expression::Identifier(_)) && recorded(&call.callee),
+            _ => false,
+        }
+    }
+
+    /// Resolve a named type alias to its definition
+    fn resolve_alias(&self, t: &Type) -> Type {
+        if let Type::Named(name) = t {
+            if let Some(SymbolInfo {
+                symbol_type: SymbolType::Type(definition),
+                ..
+            }) = self.get_symbol(name)
+            {
+                return definition.clone();
+            }
+        }
+        t.clone()
+    }
+
+    /// Whether a type may have a property; unknown types are assumed to
+    fn may_have_property(&self, t: &Type, property: &str) -> bool {
+        match t {
+            Type::Named(name) => self
+                .type_properties
+                .get(name)
+                .is_none_or(|properties| properties.contains(property)),
+            Type::ObjectType(object) => object_type_properties(object).contains(property),
+            _ => true,
+        }
+    }
+
+    /// Check that a property exists on every member of a union-typed variable
+    fn check_member_access(&self, member: &MemberExpression) -> Result<()> {
+        let (Expression::Identifier(name), Expression::Identifier(property)) =
+            (&*member.object, &*member.property)
+        else {
+            return Ok(());
+        };
+        if member.computed {
+            return Ok(());
+        }
+
+        if let Some(members) = self.union_members_of(name) {
+            if let Some(missing) = members.iter().find(|m| !self.may_have_property(m, property)) {
+                return Err(CompilerError::semantic_error(format!(
+                    "Property '{}' does not exist on '{}' of type {:?}; narrow it with `\"{}\" in {}` first",
+                    property, name, missing, property, name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that an assigned identifier is not a constant binding, recording the reassignment
+    fn check_assignment_target(&mut self, target: &Expression) -> Result<()> {
+        if let Expression::Identifier(name) = target {
+            let Some(symbol) = self.get_symbol(name) else {
+                return Ok(());
+            };
+            if !symbol.mutable {
+                self.fatal_errors.push(CompilerError::semantic_error(format!(
+                    "Cannot assign to '{}' because it is a constant",
+                    name
+                )));
+                return Ok(());
+            }
+            if let Some(declaration) = symbol.declaration {
+                self.reassigned.insert(declaration);
+            }
+        }
+        Ok(())
+    }
+
+    /// Analyze an expression
+    fn analyze_expression(&mut self, expression: &Expression) -> Result<()> {
+        self.analyze_subexpressions(expression)?;
+        if self.is_optional_value(expression) {
+            self.optional_values.insert(expression_key(expression));
+        }
+        Ok(())
+    }
+
+    /// Check the parts of an expression
+    fn analyze_subexpressions(&mut self, expression: &Expression) -> Result<()> {
+        match expression {
+            Expression::Identifier(name)
+                if self.get_symbol(name).is_none() && !GLOBALS.contains(&name.as_str()) =>
+            {
+                return Err(CompilerError::semantic_error(format!(
+                    "Cannot find name '{}'",
+                    name
+                )));
+            }
+            Expression::Member(member) => {
+                self.check_member_access(member)?;
+                self.analyze_expression(&member.object)?;
+                if member.computed {
+                    self.analyze_expression(&member.property)?;
+                }
+            }
+            Expression::Binary(binary) => {
+                self.analyze_expression(&binary.left)?;
+                self.analyze_expression(&binary.right)?;
+                if binary.operator == Token::Keyword(Keyword::In) && self.is_map(&binary.right) {
+                    self.map_key_checks.insert(binary_key(binary));
+                }
+            }
+            Expression::Logical(logical) => {
+                self.analyze_expression(&logical.left)?;
+                self.analyze_expression(&logical.right)?;
+            }
+            Expression::Conditional(conditional) => {
+                self.analyze_expression(&conditional.test)?;
+                self.analyze_expression(&conditional.consequent)?;
+                self.analyze_expression(&conditional.alternate)?;
+            }
+            Expression::Parenthesized(parenthesized) => self.analyze_expression(&parenthesized.expression)?,
+            Expression::Optional(optional) => self.analyze_expression(&optional.expression)?,
+            Expression::Unary(unary) => self.analyze_expression(&unary.argument)?,
+            Expression::AsExpression(AsExpression { expression, .. })
+            | Expression::TypeAssertion(TypeAssertion { expression, .. }) => {
+                self.analyze_expression(expression)?;
+                if matches!(self.resolve_alias(&self.infer_type_from_expression(expression)?), Type::String) {
+                    self.string_cast_operands.insert(expression_key(expression));
+                }
+            }
+            Expression::Assignment(assignment) => {
+                self.analyze_expression(&assignment.left)?;
+                self.check_assignment_target(&assignment.left)?;
+                self.analyze_expression(&assignment.right)?;
+            }
+            Expression::Update(update) => {
+                self.analyze_expression(&update.argument)?;
+                self.check_assignment_target(&update.argument)?;
+            }
+            Expression::Call(call) => {
+                self.analyze_expression(&call.callee)?;
+                for argument in &call.arguments {
+                    self.analyze_expression(argument)?;
+                }
+            }
+            _ => {
+                // Other expressions have no checks yet
+            }
+        }
+        Ok(())
+    }
+
     /// Analyze variable declaration
     fn analyze_variable_declaration(&mut self, var: &VariableDeclaration) -> Result<()> {
+        if let Some(ref init) = var.initializer {
+            self.analyze_expression(init)?;
+        }
+
         let symbol_type = if let Some(ref t) = var.type_annotation {
             SymbolType::Variable(t.clone())
         } else {
@@ -135,34 +595,51 @@ impl SemanticAnalyzer {
             name: var.name.clone(),
             symbol_type,
             scope: self.current_scope.clone(),
+            mutable: var.keyword != Keyword::Const,
             defined_at: 0, // TODO: Get actual position
+            declaration: Some(declaration_key(var)),
         };
 
-        self.symbols.insert(var.name.clone(), symbol_info);
+        self.declare(symbol_info);
         Ok(())
     }
 
     /// Analyze function declaration
     fn analyze_function_declaration(&mut self, func: &FunctionDeclaration) -> Result<()> {
-        let signature = FunctionSignature {
-            name: func.name.clone(),
-            parameters: func.parameters.clone(),
-            return_type: func.return_type.clone().unwrap_or(Type::Void),
-            type_parameters: func.type_parameters.clone(),
-        };
-
         let symbol_info = SymbolInfo {
             name: func.name.clone(),
-            symbol_type: SymbolType::Function(signature),
+            symbol_type: SymbolType::Function(function_signature(func)),
             scope: self.current_scope.clone(),
+            mutable: true,
             defined_at: 0, // TODO: Get actual position
+            declaration: None,
         };
 
-        self.symbols.insert(func.name.clone(), symbol_info);
+        self.declare(symbol_info);
 
-        // Analyze function body
+        self.analyze_function_body(&func.parameters, Some(&func.body))
+    }
+
+    /// Analyze a function or method body with its parameters in scope
+    fn analyze_function_body(&mut self, parameters: &[Parameter], body: Option<&Statement>) -> Result<()> {
         self.enter_scope();
-        self.analyze_statement(&func.body)?;
+        for param in parameters {
+            let symbol_info = SymbolInfo {
+                name: param.name.clone(),
+                symbol_type: SymbolType::Variable(param.type_.as_deref().cloned().unwrap_or(Type::Any)),
+                scope: self.current_scope.clone(),
+                mutable: true,
+                defined_at: 0, // TODO: Get actual position
+                declaration: Some(parameter_key(param)),
+            };
+            if param.optional {
+                self.optional_parameters.insert(parameter_key(param));
+            }
+            self.declare(symbol_info);
+        }
+        if let Some(body) = body {
+            self.analyze_statement(body)?;
+        }
         self.exit_scope();
 
         Ok(())
@@ -181,10 +658,23 @@ impl SemanticAnalyzer {
             name: class.name.clone(),
             symbol_type: SymbolType::Class(signature),
             scope: self.current_scope.clone(),
+            mutable: false,
             defined_at: 0, // TODO: Get actual position
+            declaration: None,
         };
 
-        self.symbols.insert(class.name.clone(), symbol_info);
+        self.declare(symbol_info);
+
+        let properties = class
+            .body
+            .members
+            .iter()
+            .filter_map(|member| match member {
+                ClassMember::Property(prop) => Some(prop.name.clone()),
+                _ => None,
+            })
+            .collect();
+        self.type_properties.insert(class.name.clone(), properties);
 
         // Analyze class body
         self.enter_scope();
@@ -208,10 +698,23 @@ impl SemanticAnalyzer {
             name: interface.name.clone(),
             symbol_type: SymbolType::Interface(signature),
             scope: self.current_scope.clone(),
+            mutable: false,
             defined_at: 0, // TODO: Get actual position
+            declaration: None,
         };
 
-        self.symbols.insert(interface.name.clone(), symbol_info);
+        self.declare(symbol_info);
+
+        let properties = interface
+            .body
+            .members
+            .iter()
+            .filter_map(|member| match member {
+                ObjectTypeMember::Property(prop) => Some(prop.name.clone()),
+                _ => None,
+            })
+            .collect();
+        self.type_properties.insert(interface.name.clone(), properties);
         Ok(())
     }
 
@@ -221,10 +724,17 @@ impl SemanticAnalyzer {
             name: type_alias.name.clone(),
             symbol_type: SymbolType::Type(type_alias.type_definition.clone()),
             scope: self.current_scope.clone(),
+            mutable: false,
             defined_at: 0, // TODO: Get actual position
+            declaration: None,
         };
 
-        self.symbols.insert(type_alias.name.clone(), symbol_info);
+        self.declare(symbol_info);
+
+        if let Type::ObjectType(ref object) = type_alias.type_definition {
+            self.type_properties
+                .insert(type_alias.name.clone(), object_type_properties(object));
+        }
         Ok(())
     }
 
@@ -240,10 +750,12 @@ impl SemanticAnalyzer {
             name: enum_decl.name.clone(),
             symbol_type: SymbolType::Enum(signature),
             scope: self.current_scope.clone(),
+            mutable: false,
             defined_at: 0, // TODO: Get actual position
+            declaration: None,
         };
 
-        self.symbols.insert(enum_decl.name.clone(), symbol_info);
+        self.declare(symbol_info);
         Ok(())
     }
 
@@ -253,8 +765,20 @@ impl SemanticAnalyzer {
             ClassMember::Property(_prop) => {
                 // Analyze property
             }
-            ClassMember::Method(_method) => {
-                // Analyze method
+            ClassMember::Method(method) => {
+                self.analyze_function_body(&method.parameters, method.body.as_ref())?;
+            }
+            ClassMember::Constructor(constructor) => {
+                self.analyze_function_body(&constructor.parameters, constructor.body.as_ref())?;
+            }
+            ClassMember::Getter(getter) => {
+                self.analyze_function_body(&[], getter.body.as_ref())?;
+            }
+            ClassMember::Setter(setter) => {
+                self.analyze_function_body(
+                    std::slice::from_ref(&setter.parameter),
+                    setter.body.as_ref(),
+                )?;
             }
             _ => {
                 // Handle other member types
@@ -268,7 +792,7 @@ impl SemanticAnalyzer {
         match expression {
             Expression::Literal(literal) => self.infer_type_from_literal(literal),
             Expression::Identifier(ident) => {
-                if let Some(symbol) = self.symbols.get(ident) {
+                if let Some(symbol) = self.get_symbol(ident) {
                     match &symbol.symbol_type {
                         SymbolType::Variable(t) => Ok(t.clone()),
                         _ => Ok(Type::Any),
@@ -285,7 +809,8 @@ impl SemanticAnalyzer {
     fn infer_type_from_literal(&self, literal: &Literal) -> Result<Type> {
         match literal {
             Literal::String(_) => Ok(Type::String),
-            Literal::Number(_) => Ok(Type::Number),
+            Literal::Number(_) | Literal::RadixNumber(..) => Ok(Type::Number),
+            Literal::BigInt(_) => Ok(Type::BigInt),
             Literal::Boolean(_) => Ok(Type::Boolean),
             Literal::Null => Ok(Type::Null),
             Literal::Undefined => Ok(Type::Undefined),
@@ -296,21 +821,60 @@ impl SemanticAnalyzer {
     /// Enter a new scope
     fn enter_scope(&mut self) {
         self.current_scope.push("block".to_string());
+        self.scopes.push(HashMap::new());
     }
 
     /// Exit current scope
     fn exit_scope(&mut self) {
         self.current_scope.pop();
+        self.scopes.pop();
+    }
+
+    /// Declare a symbol in the innermost scope
+    fn declare(&mut self, symbol_info: SymbolInfo) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(symbol_info.name.clone(), symbol_info);
+        }
+    }
+
+    /// Find the innermost visible declaration of a symbol for updating
+    fn symbol_mut(&mut self, name: &str) -> Option<&mut SymbolInfo> {
+        self.scopes.iter_mut().rev().find_map(|scope| scope.get_mut(name))
     }
 
-    /// Get symbol information
+    /// Get symbol information, preferring the innermost declaration
     pub fn get_symbol(&self, name: &str) -> Option<&SymbolInfo> {
-        self.symbols.get(name)
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    /// Take the errors that leave no valid translation, such as assigning to a constant
+    pub fn take_fatal_errors(&mut self) -> Vec<CompilerError> {
+        std::mem::take(&mut self.fatal_errors)
+    }
+
+    /// Whether the binding declared by `var` is assigned after its declaration
+    pub fn is_reassigned(&self, var: &VariableDeclaration) -> bool {
+        self.reassigned.contains(&declaration_key(var))
+    }
+
+    /// Whether the `in` check `binary` tests a key of a map
+    pub fn is_map_key_check(&self, binary: &BinaryExpression) -> bool {
+        self.map_key_checks.contains(&binary_key(binary))
+    }
+
+    /// Whether `expression`, cast with `as` or a type assertion, holds a string
+    pub fn is_string_cast_operand(&self, expression: &Expression) -> bool {
+        self.string_cast_operands.contains(&expression_key(expression))
     }
 
-    /// Get all symbols
+    /// Whether `expression` holds an `Option`: an optional parameter, or a `?.` chain on one
+    pub fn is_optional(&self, expression: &Expression) -> bool {
+        self.optional_values.contains(&expression_key(expression))
+    }
+
+    /// Get all symbols declared in the global scope
     pub fn get_all_symbols(&self) -> &HashMap<String, SymbolInfo> {
-        &self.symbols
+        &self.scopes[0]
     }
 }
 
@@ -319,3 +883,68 @@ impl Default for SemanticAnalyzer {
         Self::new()
     }
 }
+
+/// Identity of a variable declaration within the analyzed program
+///
+/// The generator walks the same AST, so the address identifies the binding even when
+/// another scope declares the same name.
+fn declaration_key(var: &VariableDeclaration) -> usize {
+    var as *const VariableDeclaration as usize
+}
+
+/// Identity of a binary expression within the analyzed program, like `declaration_key`
+fn binary_key(binary: &BinaryExpression) -> usize {
+    binary as *const BinaryExpression as usize
+}
+
+/// Identity of an expression within the analyzed program, like `declaration_key`
+fn expression_key(expression: &Expression) -> usize {
+    expression as *const Expression as usize
+}
+
+/// Identity of a parameter within the analyzed program, like `declaration_key`
+fn parameter_key(param: &Parameter) -> usize {
+    param as *const Parameter as usize
+}
+
+/// Signature of a declared function
+fn function_signature(func: &FunctionDeclaration) -> FunctionSignature {
+    FunctionSignature {
+        name: func.name.clone(),
+        parameters: func.parameters.clone(),
+        // Unannotated functions may return anything
+        return_type: func.return_type.clone().unwrap_or(Type::Any),
+        type_parameters: func.type_parameters.clone(),
+    }
+}
+
+/// Collect the members of a (possibly nested) union type
+fn collect_union_members(t: &Type, members: &mut Vec<Type>) {
+    match t {
+        Type::Union { left, right } => {
+            collect_union_members(left, members);
+            collect_union_members(right, members);
+        }
+        _ => members.push(t.clone()),
+    }
+}
+
+/// Rebuild a union from its members
+fn union_of(members: Vec<Type>) -> Option<Type> {
+    members.into_iter().reduce(|left, right| Type::Union {
+        left: Box::new(left),
+        right: Box::new(right),
+    })
+}
+
+/// Property names declared by an object type
+fn object_type_properties(object: &ObjectType) -> HashSet<String> {
+    object
+        .members
+        .iter()
+        .filter_map(|member| match member {
+            ObjectTypeMember::Property(prop) => Some(prop.name.clone()),
+            _ => None,
+        })
+        .collect()
+}
@@ -2,7 +2,76 @@
 
 use crate::ast::*;
 use crate::error::Result;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+
+/// Key type for indexing a map with the given Rust key type, borrowing strings as `str`
+pub(crate) fn index_key_type(key_type: &str, reference: &str) -> String {
+    match key_type {
+        "String" => format!("{}str", reference),
+        key_type => format!("{}{}", reference, key_type),
+    }
+}
+
+/// Import for `HashMap`
+pub const HASH_MAP_IMPORT: &str = "use std::collections::HashMap;";
+/// Import for `HashSet`
+pub const HASH_SET_IMPORT: &str = "use std::collections::HashSet;";
+/// Import for `dyn Any`
+pub const ANY_IMPORT: &str = "use std::any::Any;";
+/// Import for the serde derives
+pub const SERDE_IMPORT: &str = "use serde::{Deserialize, Serialize};";
+
+/// Words reserved in Rust that are ordinary identifiers in TypeScript
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do",
+    "dyn", "else", "enum", "extern", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Escape a TypeScript identifier that is reserved in Rust, e.g. `match` becomes `r#match`
+pub(crate) fn rust_identifier(name: &str) -> String {
+    match name {
+        // These keywords cannot be raw identifiers
+        "self" | "Self" | "super" | "crate" => format!("{}_", name),
+        _ if RUST_KEYWORDS.contains(&name) => format!("r#{}", name),
+        _ => name.to_string(),
+    }
+}
+
+/// Convert a camelCase name to snake_case, leaving PascalCase and SCREAMING_CASE names alone
+pub(crate) fn snake_case(name: &str) -> String {
+    if !name.starts_with(|c: char| c.is_ascii_lowercase()) {
+        return name.to_string();
+    }
+    let chars: Vec<char> = name.chars().collect();
+    let mut result = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() {
+            let previous = chars[i - 1];
+            // Split before a word (`getValue`) and after an acronym (`parseHTMLString`)
+            let starts_word = previous.is_ascii_lowercase() || previous.is_ascii_digit();
+            let ends_acronym = previous.is_ascii_uppercase()
+                && chars.get(i + 1).is_some_and(|next| next.is_ascii_lowercase());
+            if starts_word || ends_acronym {
+                result.push('_');
+            }
+            result.push(c.to_ascii_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Rust code for a named object type
+pub struct ObjectTypeDefinition {
+    /// Struct holding the object's properties
+    pub struct_code: String,
+    /// Trait for the object's methods and impls for its index signature, if it has any
+    pub impl_code: Option<String>,
+}
 
 /// Type mapper for converting TypeScript types to Rust types
 pub struct TypeMapper {
@@ -12,6 +81,16 @@ pub struct TypeMapper {
     generics: Vec<String>,
     /// Runtime support enabled
     runtime: bool,
+    /// Rust type for `number`
+    number_type: String,
+    /// Rename camelCase identifiers to snake_case
+    snake_case: bool,
+    /// Derive `Serialize` and `Deserialize` on generated types
+    serde_derives: bool,
+    /// `use` lines required by the types handed out so far
+    imports: BTreeSet<&'static str>,
+    /// Visibility of generated structs
+    struct_visibility: String,
 }
 
 /// Extract struct name from generated code
@@ -42,20 +121,137 @@ impl TypeMapper {
         type_mappings.insert("object".to_string(), "Box<dyn Any>".to_string());
         type_mappings.insert("symbol".to_string(), "Symbol".to_string());
         type_mappings.insert("bigint".to_string(), "i64".to_string());
+        type_mappings.insert("Map".to_string(), "HashMap".to_string());
+        type_mappings.insert("ReadonlyMap".to_string(), "HashMap".to_string());
+        type_mappings.insert("Set".to_string(), "HashSet".to_string());
+        type_mappings.insert("ReadonlySet".to_string(), "HashSet".to_string());
 
         Self {
             type_mappings,
             generics: Vec::new(),
             runtime,
+            number_type: "f64".to_string(),
+            snake_case: false,
+            serde_derives: true,
+            struct_visibility: "pub".to_string(),
+            imports: BTreeSet::new(),
+        }
+    }
+
+    /// Map `number` to `i64` instead of `f64`
+    pub fn with_integer_numbers(mut self, integer: bool) -> Self {
+        self.number_type = if integer { "i64" } else { "f64" }.to_string();
+        self.type_mappings
+            .insert("number".to_string(), self.number_type.clone());
+        self
+    }
+
+    /// Map `Date` to the given Rust type, e.g. `chrono::DateTime<chrono::Utc>`
+    pub fn with_date_type(mut self, date_type: impl Into<String>) -> Self {
+        self.type_mappings.insert("Date".to_string(), date_type.into());
+        self
+    }
+
+    /// Rename camelCase identifiers to snake_case
+    pub fn with_snake_case(mut self, snake_case: bool) -> Self {
+        self.snake_case = snake_case;
+        self
+    }
+
+    /// Derive `Serialize` and `Deserialize` on generated types
+    pub fn with_serde_derives(mut self, serde_derives: bool) -> Self {
+        self.serde_derives = serde_derives;
+        self
+    }
+
+    /// Set the visibility of generated structs, e.g. `pub(crate)`
+    pub fn with_struct_visibility(mut self, visibility: impl Into<String>) -> Self {
+        self.struct_visibility = visibility.into();
+        self
+    }
+
+    /// Traits to derive on a generated type, followed by the serde traits when enabled
+    pub fn derives(&mut self, traits: &[&str]) -> Vec<String> {
+        let serde: &[&str] = if self.serde_derives {
+            self.imports.insert(SERDE_IMPORT);
+            &["Serialize", "Deserialize"]
+        } else {
+            &[]
+        };
+        traits.iter().chain(serde).map(|t| t.to_string()).collect()
+    }
+
+    /// Record the imports a Rust type written by the generator needs, and return it
+    pub fn use_type(&mut self, rust_type: &str) -> String {
+        self.record_imports(rust_type);
+        rust_type.to_string()
+    }
+
+    /// `use` lines required by the types handed out so far
+    pub fn imports(&self) -> impl Iterator<Item = &str> {
+        self.imports.iter().copied()
+    }
+
+    /// Record the imports for each std type named in a Rust type
+    fn record_imports(&mut self, rust_type: &str) {
+        let mut previous = "";
+        // Paths stay whole so a fully qualified `std::collections::HashMap` needs no import
+        for word in rust_type.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':')) {
+            match word {
+                "HashMap" => {
+                    self.imports.insert(HASH_MAP_IMPORT);
+                }
+                "HashSet" => {
+                    self.imports.insert(HASH_SET_IMPORT);
+                }
+                "Any" if previous == "dyn" => {
+                    self.imports.insert(ANY_IMPORT);
+                }
+                _ => {}
+            }
+            if !word.is_empty() {
+                previous = word;
+            }
+        }
+    }
+
+    /// Whether generated types derive the serde traits
+    pub fn serde_derives(&self) -> bool {
+        self.serde_derives
+    }
+
+    /// Visibility of generated structs
+    pub fn struct_visibility(&self) -> &str {
+        &self.struct_visibility
+    }
+
+    /// Rust type used for `number`
+    pub fn number_type(&self) -> &str {
+        &self.number_type
+    }
+
+    /// Name of an identifier in generated code
+    pub fn rust_name(&self, name: &str) -> String {
+        if self.snake_case {
+            rust_identifier(&snake_case(name))
+        } else {
+            rust_identifier(name)
         }
     }
 
     /// Map a TypeScript type to Rust type
     pub fn map_type(&mut self, ts_type: &Type) -> Result<String> {
+        let rust_type = self.map_type_inner(ts_type)?;
+        self.record_imports(&rust_type);
+        Ok(rust_type)
+    }
+
+    /// Map a TypeScript type to Rust type without recording its imports
+    fn map_type_inner(&mut self, ts_type: &Type) -> Result<String> {
         match ts_type {
             // Primitive types
             Type::String => Ok("String".to_string()),
-            Type::Number => Ok("f64".to_string()),
+            Type::Number => Ok(self.number_type.clone()),
             Type::Boolean => Ok("bool".to_string()),
             Type::Any => {
                 if self.runtime {
@@ -96,16 +292,7 @@ impl TypeMapper {
                 type_arguments,
             } => {
                 let rust_name = self.map_named_type(name)?;
-                if type_arguments.is_empty() {
-                    Ok(rust_name)
-                } else {
-                    let param_types: Result<Vec<String>> = type_arguments
-                        .iter()
-                        .map(|arg| self.map_type(arg))
-                        .collect();
-                    let param_types = param_types?;
-                    Ok(format!("{}<{}>", rust_name, param_types.join(", ")))
-                }
+                self.map_type_arguments(rust_name, type_arguments)
             }
             
             // Union and intersection types
@@ -162,9 +349,30 @@ impl TypeMapper {
             // Parenthesized types
             Type::Parenthesized(inner) => self.map_type(inner),
 
+            // A lone literal type is a value of its primitive type
+            Type::Literal(literal) => match literal {
+                Literal::String(_) => Ok("String".to_string()),
+                Literal::Number(_) | Literal::RadixNumber(..) => Ok(self.number_type.clone()),
+                Literal::BigInt(_) => Ok("i64".to_string()),
+                Literal::Boolean(_) => Ok("bool".to_string()),
+                _ => Ok("()".to_string()),
+            },
+
             // Type queries
             Type::TypeQuery(query) => self.map_type_query(query),
 
+            // `keyof T` is the set of T's property names
+            Type::KeyOf(_) => Ok("String".to_string()),
+
+            // `readonly T[]` is a fixed slice, so it has no push/pop API
+            Type::Readonly(inner) => match inner.as_ref() {
+                Type::Array(element_type) => Ok(format!("Box<[{}]>", self.map_type(element_type)?)),
+                inner => self.map_type(inner),
+            },
+
+            // `T[K]` cannot be resolved without T's declaration
+            Type::IndexedAccess { .. } => self.map_type(&Type::Any),
+
             // Import types
             Type::Import(import) => self.map_import_type(import),
         }
@@ -189,8 +397,12 @@ impl TypeMapper {
     /// Map generic type
     fn map_generic_type(&mut self, generic: &GenericType) -> Result<String> {
         let base_type = self.map_type(&generic.type_)?;
-        let type_args: Result<Vec<String>> = generic
-            .type_arguments
+        self.map_type_arguments(base_type, &generic.type_arguments)
+    }
+
+    /// Apply type arguments to an already mapped base type
+    fn map_type_arguments(&mut self, base_type: String, type_arguments: &[Type]) -> Result<String> {
+        let type_args: Result<Vec<String>> = type_arguments
             .iter()
             .map(|t| self.map_type(t))
             .collect();
@@ -215,18 +427,18 @@ impl TypeMapper {
                         Ok("Box<dyn std::future::Future<Output = ()>>".to_string())
                     }
                 }
-                "Map" | "Record" => {
+                "HashMap" | "Record" => {
                     if type_args.len() == 2 {
-                        Ok(format!("std::collections::HashMap<{}, {}>", type_args[0], type_args[1]))
+                        Ok(format!("HashMap<{}, {}>", type_args[0], type_args[1]))
                     } else {
-                        Ok("std::collections::HashMap<Box<dyn Any>, Box<dyn Any>>".to_string())
+                        Ok("HashMap<Box<dyn Any>, Box<dyn Any>>".to_string())
                     }
                 }
-                "Set" => {
+                "HashSet" => {
                     if type_args.len() == 1 {
-                        Ok(format!("std::collections::HashSet<{}>", type_args[0]))
+                        Ok(format!("HashSet<{}>", type_args[0]))
                     } else {
-                        Ok("std::collections::HashSet<Box<dyn Any>>".to_string())
+                        Ok("HashSet<Box<dyn Any>>".to_string())
                     }
                 }
                 "Partial" | "Required" | "Readonly" | "Pick" | "Omit" => {
@@ -268,7 +480,7 @@ impl TypeMapper {
                 } else {
                     "Box<dyn Any>".to_string()
                 };
-                Ok(format!("{}: {}", param.name, param_type))
+                Ok(format!("{}: {}", self.rust_name(&param.name), param_type))
             })
             .collect();
         let params = params?;
@@ -280,7 +492,24 @@ impl TypeMapper {
 
     /// Map object type
     fn map_object_type(&mut self, obj_type: &ObjectType) -> Result<String> {
+        // Generate a proper struct name for object types
+        let struct_name = format!("ObjectType_{}", obj_type.members.len());
+        let definition = self.map_object_type_definition(&struct_name, obj_type)?;
+        Ok(match definition.impl_code {
+            Some(impl_code) => format!("{}\n\n{}", definition.struct_code, impl_code),
+            None => definition.struct_code,
+        })
+    }
+
+    /// Map a named object type to a struct of its properties and an impl of its methods
+    pub fn map_object_type_definition(
+        &mut self,
+        name: &str,
+        obj_type: &ObjectType,
+    ) -> Result<ObjectTypeDefinition> {
         let mut struct_fields = Vec::new();
+        let mut methods = Vec::new();
+        let mut index_impls = Vec::new();
 
         for member in &obj_type.members {
             match member {
@@ -292,28 +521,24 @@ impl TypeMapper {
                     };
 
                     let field_name = if prop.optional {
-                        format!("{}: Option<{}>", prop.name, field_type)
+                        format!("pub {}: Option<{}>", self.rust_name(&prop.name), field_type)
                     } else {
-                        format!("{}: {}", prop.name, field_type)
+                        format!("pub {}: {}", self.rust_name(&prop.name), field_type)
                     };
 
                     struct_fields.push(field_name);
                 }
                 ObjectTypeMember::Method(method) => {
-                    // Methods become associated functions
-                    let params: Result<Vec<String>> = method
-                        .parameters
-                        .iter()
-                        .map(|param| {
-                            let param_type = if let Some(ref t) = param.type_ {
-                                self.map_type(t)?
-                            } else {
-                                "Box<dyn Any>".to_string()
-                            };
-                            Ok(format!("{}: {}", param.name, param_type))
-                        })
-                        .collect();
-                    let params = params?;
+                    // Methods become trait methods for the value's implementor to provide
+                    let mut params = vec!["&self".to_string()];
+                    for param in &method.parameters {
+                        let param_type = if let Some(ref t) = param.type_ {
+                            self.map_type(t)?
+                        } else {
+                            "Box<dyn Any>".to_string()
+                        };
+                        params.push(format!("{}: {}", self.rust_name(&param.name), param_type));
+                    }
 
                     let return_type = if let Some(ref t) = method.return_type {
                         self.map_type(t)?
@@ -321,26 +546,65 @@ impl TypeMapper {
                         "()".to_string()
                     };
 
-                    struct_fields.push(format!(
-                        "fn {}({}) -> {}",
-                        method.name,
+                    methods.push(format!(
+                        "    fn {}({}) -> {};",
+                        self.rust_name(&method.name),
                         params.join(", "),
                         return_type
                     ));
                 }
+                // Entries live in a map field reachable with `value[key]`
+                ObjectTypeMember::Index(index) if index_impls.is_empty() => {
+                    let map_type = self.map_index_signature(index)?;
+                    let key_type = self.map_type(
+                        &index
+                            .parameter
+                            .type_
+                            .as_ref()
+                            .map_or(Type::String, |v| *v.clone()),
+                    )?;
+                    let value_type = self.map_type(&index.type_)?;
+                    let key = index_key_type(&key_type, "&");
+                    struct_fields.push(format!("pub entries: {}", map_type));
+
+                    index_impls.push(format!(
+                        "impl std::ops::Index<{1}> for {0} {{\n    type Output = {2};\n\n    fn index(&self, key: {1}) -> &{2} {{\n        &self.entries[key]\n    }}\n}}",
+                        name, key, value_type
+                    ));
+                    if !index.readonly {
+                        index_impls.push(format!(
+                            "impl std::ops::IndexMut<{1}> for {0} {{\n    fn index_mut(&mut self, key: {1}) -> &mut {2} {{\n        self.entries.entry(key.to_owned()).or_default()\n    }}\n}}",
+                            name, key, value_type
+                        ));
+                    }
+                }
                 _ => {
                     // Handle other member types as needed
                 }
             }
         }
 
-        // Generate a proper struct name for object types
-        let struct_name = format!("ObjectType_{}", struct_fields.len());
-        Ok(format!(
-            "#[derive(Debug, Clone, Serialize, Deserialize)]\npub struct {} {{\n    {}\n}}",
-            struct_name,
+        let struct_code = format!(
+            "#[derive({})]\n{} struct {} {{\n    {}\n}}",
+            self.derives(&["Debug", "Clone"]).join(", "),
+            self.struct_visibility,
+            name,
             struct_fields.join(",\n    ")
-        ))
+        );
+        let mut impls = Vec::new();
+        if !methods.is_empty() {
+            impls.push(format!("pub trait {}Methods {{\n{}\n}}", name, methods.join("\n")));
+        }
+        impls.extend(index_impls);
+        let impl_code = if impls.is_empty() {
+            None
+        } else {
+            Some(impls.join("\n\n"))
+        };
+        Ok(ObjectTypeDefinition {
+            struct_code,
+            impl_code,
+        })
     }
 
     /// Map index signature
@@ -353,6 +617,7 @@ impl TypeMapper {
                 .map_or(Type::String, |v| *v.clone()),
         )?;
         let value_type = self.map_type(&index_sig.type_)?;
+        self.imports.insert(HASH_MAP_IMPORT);
         Ok(format!("HashMap<{}, {}>", key_type, value_type))
     }
 
@@ -361,7 +626,6 @@ impl TypeMapper {
         // Convert mapped type to generic struct
         let key_type = self.map_type(
             &mapped
-                .type_parameter
                 .constraint
                 .as_ref()
                 .map_or(Type::String, |v| *v.clone()),
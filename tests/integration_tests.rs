@@ -1,8 +1,43 @@
 //! Integration tests for the TypeScript-Rust-Compiler
 
+use std::collections::HashMap;
 use std::fs;
+use std::thread;
+use std::time::Duration;
 use tempfile::TempDir;
+use TypeScript_Rust_Compiler::ast::{ClassMember, Expression, Literal, Statement, Type};
 use TypeScript_Rust_Compiler::compiler::Compiler;
+use TypeScript_Rust_Compiler::config::{CompilerConfig, GeneratorConfig};
+use TypeScript_Rust_Compiler::error::CompilerError;
+use TypeScript_Rust_Compiler::formatter::format_rust;
+use TypeScript_Rust_Compiler::lexer::{Keyword, Lexer, Token};
+use TypeScript_Rust_Compiler::semantic::SemanticAnalyzer;
+
+/// Build generated Rust followed by `main` with rustc, run it and return its stdout
+fn run_generated(rust_code: &str, main: &str) -> String {
+    let temp_dir = TempDir::new().unwrap();
+    let source = temp_dir.path().join("generated.rs");
+    let binary = temp_dir.path().join("generated");
+    fs::write(&source, format!("{}\n{}\n", rust_code, main)).unwrap();
+
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let build = std::process::Command::new(rustc)
+        .args(["--edition", "2021", "-A", "warnings", "-o"])
+        .arg(&binary)
+        .arg(&source)
+        .output()
+        .unwrap();
+    assert!(
+        build.status.success(),
+        "{}\n{}",
+        rust_code,
+        String::from_utf8_lossy(&build.stderr)
+    );
+
+    let run = std::process::Command::new(&binary).output().unwrap();
+    assert!(run.status.success(), "{}", String::from_utf8_lossy(&run.stderr));
+    String::from_utf8(run.stdout).unwrap()
+}
 
 /// Test basic TypeScript compilation
 #[test]
@@ -609,3 +644,2762 @@ const result = fibonacci(10);
     // Check for optimization hints in generated code
     assert!(rust_code.contains("fn fibonacci"));
 }
+
+/// Test AST emission as JSON
+#[test]
+fn test_emit_ast() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("input.ts");
+    let output_file = temp_dir.path().join("ast.json");
+
+    let ts_code = r#"
+function add(a: number, b: number): number {
+    return a + b;
+}
+"#;
+
+    fs::write(&input_file, ts_code).unwrap();
+
+    let mut compiler = Compiler::new();
+    let result = compiler.emit_ast(&input_file, Some(&output_file));
+
+    assert!(result.is_ok());
+
+    let json = fs::read_to_string(&output_file).unwrap();
+    assert!(json.contains("\"FunctionDeclaration\""));
+    assert!(json.contains("\"add\""));
+}
+
+/// Test string concatenation assignment
+#[test]
+fn test_string_plus_assign() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("input.ts");
+    let output_file = temp_dir.path().join("output.rs");
+
+    let ts_code = r#"
+function greet(name: string): string {
+    name += "!";
+    return name;
+}
+"#;
+
+    fs::write(&input_file, ts_code).unwrap();
+
+    let mut compiler = Compiler::new();
+    let result = compiler.compile(&input_file, &output_file);
+
+    assert!(result.is_ok());
+
+    let rust_code = fs::read_to_string(&output_file).unwrap();
+    assert!(rust_code.contains("name.push_str(\"!\")"));
+}
+
+/// Test the compiler re-exported from the crate root
+#[test]
+fn test_root_compiler_compile_str() {
+    let ts_code = r#"
+function add(a: number, b: number): number {
+    return a + b;
+}
+"#;
+
+    let mut compiler = TypeScript_Rust_Compiler::Compiler::new().with_optimization(true);
+    let result = compiler.compile_str(ts_code);
+
+    assert!(result.is_ok());
+    assert!(result.unwrap().contains("fn add"));
+}
+
+/// Test compound arithmetic assignment operators
+#[test]
+fn test_compound_assignment_operators() {
+    let ts_code = r#"
+function scale(x: number): number {
+    x *= 2;
+    x -= 1;
+    x /= 3;
+    x %= 4;
+    return x;
+}
+"#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+
+    assert!(rust_code.contains("x *= 2.0"));
+    assert!(rust_code.contains("x -= 1.0"));
+    assert!(rust_code.contains("x /= 3.0"));
+    assert!(rust_code.contains("x %= 4.0"));
+
+    let rust_code = compiler
+        .compile_str("function bump(count: number): number {\n    count += 2;\n    return count;\n}")
+        .unwrap();
+    assert!(rust_code.contains("count += 2.0;"));
+}
+
+/// Test cross-file import resolution
+#[test]
+fn test_cross_file_imports() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_dir = temp_dir.path().join("input");
+    let output_dir = temp_dir.path().join("output");
+
+    fs::create_dir_all(&input_dir).unwrap();
+
+    let main_ts = r#"
+import { Calculator } from './calculator';
+
+const calc = new Calculator();
+"#;
+
+    let calculator_ts = r#"
+export class Calculator {
+    add(a: number, b: number): number {
+        return a + b;
+    }
+}
+"#;
+
+    fs::write(input_dir.join("main.ts"), main_ts).unwrap();
+    fs::write(input_dir.join("calculator.ts"), calculator_ts).unwrap();
+
+    let mut compiler = Compiler::new();
+    let result = compiler.compile_project(&input_dir, &output_dir);
+
+    assert!(result.is_ok());
+
+    let main_rs = fs::read_to_string(output_dir.join("src").join("main.rs")).unwrap();
+    assert!(main_rs.contains("pub mod calculator;"));
+    assert!(main_rs.contains("use crate::calculator::{Calculator};"));
+}
+
+/// Test meta properties
+#[test]
+fn test_meta_properties() {
+    let mut compiler = Compiler::new();
+
+    let ts_code = r#"
+const url = import.meta.url;
+console.log(import.meta.url);
+"#;
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("file!()"));
+
+    let ts_code = r#"
+function Shape() {
+    return new.target;
+}
+"#;
+    assert!(compiler.compile_str(ts_code).is_err());
+}
+
+/// Test circular import detection
+#[test]
+fn test_circular_imports() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_dir = temp_dir.path().join("input");
+    let output_dir = temp_dir.path().join("output");
+
+    fs::create_dir_all(&input_dir).unwrap();
+
+    let a_ts = r#"
+import { B } from './b';
+
+export class A {}
+"#;
+
+    let b_ts = r#"
+import { A } from './a';
+
+export class B {}
+"#;
+
+    fs::write(input_dir.join("a.ts"), a_ts).unwrap();
+    fs::write(input_dir.join("b.ts"), b_ts).unwrap();
+
+    let mut compiler = Compiler::new();
+    let result = compiler.compile_project(&input_dir, &output_dir);
+
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("Circular import"));
+    assert!(error.contains("a.ts"));
+    assert!(error.contains("b.ts"));
+}
+
+/// Test prototype method assignment
+#[test]
+fn test_prototype_method_assignment() {
+    let ts_code = r#"
+class Foo {}
+
+Foo.prototype.bar = function() { return 1; };
+"#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+
+    let impl_start = rust_code.find("impl Foo").unwrap();
+    assert!(rust_code[impl_start..].contains("pub fn bar(&self)"));
+    assert!(!rust_code.contains("prototype"));
+}
+
+/// Test watch mode recompiles only changed files
+#[test]
+fn test_watch_recompiles_changed_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_dir = temp_dir.path().join("input");
+    let output_dir = temp_dir.path().join("output");
+
+    fs::create_dir_all(&input_dir).unwrap();
+
+    let main_ts = r#"
+import { Calculator } from './calculator';
+
+const calc = new Calculator();
+"#;
+
+    let calculator_ts = r#"
+export class Calculator {
+    add(a: number, b: number): number {
+        return a + b;
+    }
+}
+"#;
+
+    fs::write(input_dir.join("main.ts"), main_ts).unwrap();
+    fs::write(input_dir.join("calculator.ts"), calculator_ts).unwrap();
+
+    let mut compiler = Compiler::new();
+    let mut mtimes = HashMap::new();
+
+    // The first pass compiles everything
+    let changed = compiler
+        .recompile_changed(&input_dir, &output_dir, &mut mtimes)
+        .unwrap();
+    assert_eq!(changed.len(), 2);
+    assert!(output_dir.join("src").join("calculator.rs").exists());
+
+    // Nothing changed since
+    let changed = compiler
+        .recompile_changed(&input_dir, &output_dir, &mut mtimes)
+        .unwrap();
+    assert!(changed.is_empty());
+
+    // Touching a file triggers its recompilation
+    thread::sleep(Duration::from_millis(20));
+    let updated_ts = calculator_ts.replace(
+        "}\n}",
+        "}\n\n    subtract(a: number, b: number): number {\n        return a - b;\n    }\n}",
+    );
+    fs::write(input_dir.join("calculator.ts"), updated_ts).unwrap();
+
+    let changed = compiler
+        .recompile_changed(&input_dir, &output_dir, &mut mtimes)
+        .unwrap();
+    assert_eq!(changed, vec![input_dir.join("calculator.ts")]);
+
+    let rust_code = fs::read_to_string(output_dir.join("src").join("calculator.rs")).unwrap();
+    assert!(rust_code.contains("fn subtract"));
+}
+
+/// Test the watch loop recompiles a file when it is written
+#[test]
+fn test_watch_reacts_to_file_events() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_dir = temp_dir.path().join("input");
+    let output_dir = temp_dir.path().join("output");
+    fs::create_dir_all(&input_dir).unwrap();
+    fs::write(input_dir.join("main.ts"), "const x = 1;").unwrap();
+    fs::write(input_dir.join("util.ts"), "export function one(): number { return 1; }").unwrap();
+
+    let util = input_dir.join("util.ts");
+    let writer = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(500));
+        fs::write(&util, "export function two(): number { return 2; }").unwrap();
+    });
+
+    let mut compiler = Compiler::new();
+    let recompiled = compiler
+        .watch_with_timeout(&input_dir, &output_dir, Some(Duration::from_secs(2)))
+        .unwrap();
+    writer.join().unwrap();
+
+    assert_eq!(recompiled, vec![input_dir.join("util.ts")]);
+    let rust_code = fs::read_to_string(output_dir.join("src").join("util.rs")).unwrap();
+    assert!(rust_code.contains("pub fn two()"), "{}", rust_code);
+}
+
+/// Test PartialEq on union-derived data enums
+#[test]
+fn test_union_enum_partial_eq() {
+    let mut compiler = Compiler::new();
+
+    // Comparable payloads derive PartialEq
+    let ts_code = r#"
+type Value = string | number;
+
+function same(a: Value, b: Value): boolean {
+    return a == b;
+}
+"#;
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\npub enum Value"));
+    assert!(rust_code.contains("String(String)"));
+    assert!(rust_code.contains("Number(f64)"));
+    assert!(rust_code.contains("(a == b)"));
+
+    let output = run_generated(
+        &rust_code.replace("use serde::{Deserialize, Serialize};", "").replace(", Serialize, Deserialize", ""),
+        "fn main() { println!(\"{} {}\", same(Value::Number(1.0), Value::Number(1.0)), same(Value::Number(1.0), Value::String(\"1\".to_string()))); }",
+    );
+    assert_eq!(output.trim(), "true false");
+
+    // Class payloads can't be compared, so PartialEq is left out with a warning
+    let ts_code = r#"
+class Circle {
+    radius: number;
+}
+
+type Shape = Circle | number;
+"#;
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("#[derive(Debug, Clone, Serialize, Deserialize)]\npub enum Shape"));
+    assert!(!rust_code.contains("impl PartialEq for Shape"));
+    assert!(compiler.warnings().iter().any(|w| w.to_string().contains("Shape")));
+}
+
+/// Test parallel compilation of many project files
+#[test]
+fn test_parallel_project_compilation() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_dir = temp_dir.path().join("input");
+    let output_dir = temp_dir.path().join("output");
+
+    fs::create_dir_all(input_dir.join("nested")).unwrap();
+
+    for i in 0..10 {
+        let ts_code = format!(
+            "export function value{}(): number {{\n    return {};\n}}\n",
+            i, i
+        );
+        let dir = if i % 2 == 0 { input_dir.clone() } else { input_dir.join("nested") };
+        fs::write(dir.join(format!("module{}.ts", i)), ts_code).unwrap();
+    }
+
+    let mut compiler = Compiler::new();
+    let result = compiler.compile_project(&input_dir, &output_dir);
+
+    assert!(result.is_ok());
+
+    let src_dir = output_dir.join("src");
+    for i in 0..10 {
+        let dir = if i % 2 == 0 { src_dir.clone() } else { src_dir.join("nested") };
+        let rust_code = fs::read_to_string(dir.join(format!("module{}.rs", i))).unwrap();
+        assert!(rust_code.contains(&format!("fn value{}", i)));
+    }
+    assert!(src_dir.join("nested").join("mod.rs").exists());
+    assert!(src_dir.join("lib.rs").exists());
+}
+
+/// Test destructuring in for...of loops
+#[test]
+fn test_for_of_destructuring() {
+    let ts_code = r#"
+function show(arr: number[]): void {
+    for (const [i, x] of arr.entries()) {
+        console.log(i, x);
+    }
+}
+"#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+
+    assert!(rust_code.contains("for (i, x) in arr.iter().enumerate() {"));
+}
+
+/// Test `in` operator type guard narrowing
+#[test]
+fn test_in_operator_narrowing() {
+    let compiler = Compiler::new();
+
+    let ts_code = r#"
+interface Circle {
+    radius: number;
+}
+
+interface Square {
+    side: number;
+}
+
+type Shape = Circle | Square;
+
+function area(shape: Shape): number {
+    if ("radius" in shape) {
+        return shape.radius * shape.radius;
+    } else {
+        return shape.side * shape.side;
+    }
+}
+"#;
+    let program = compiler.parse_str(ts_code).unwrap();
+    let mut analyzer = SemanticAnalyzer::new();
+    assert!(analyzer.analyze(&program).is_ok());
+
+    // Without the guard the property is not on every member
+    let ts_code = r#"
+interface Circle {
+    radius: number;
+}
+
+interface Square {
+    side: number;
+}
+
+function radius(shape: Circle | Square): number {
+    return shape.radius;
+}
+"#;
+    let program = compiler.parse_str(ts_code).unwrap();
+    let mut analyzer = SemanticAnalyzer::new();
+    assert!(analyzer.analyze(&program).is_err());
+}
+
+/// Test `in` checks a map key and is rejected on other values
+#[test]
+fn test_in_operator_on_maps() {
+    let mut compiler = Compiler::new();
+
+    let ts_code = r#"
+function has(m: Map<string, number>, r: Record<string, number>): boolean {
+    return "a" in m && "b" in r;
+}
+"#;
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("m.contains_key(\"a\")"), "{}", rust_code);
+    assert!(rust_code.contains("r.contains_key(\"b\")"), "{}", rust_code);
+
+    let ts_code = r#"
+interface Point {
+    x: number;
+}
+
+function hasX(p: Point): boolean {
+    return "x" in p;
+}
+"#;
+    let result = compiler.compile_str(ts_code);
+    assert!(matches!(result, Err(CompilerError::UnsupportedFeature { .. })), "{:?}", result);
+}
+
+/// Test block scopes keep sibling and shadowed declarations apart
+#[test]
+fn test_block_scoped_symbols() {
+    let compiler = Compiler::new();
+
+    let ts_code = r#"
+interface Circle {
+    radius: number;
+}
+
+interface Square {
+    side: number;
+}
+
+{
+    let x: Circle | Square;
+}
+{
+    let x: Circle;
+    let r = x.radius;
+}
+"#;
+    let program = compiler.parse_str(ts_code).unwrap();
+    let mut analyzer = SemanticAnalyzer::new();
+    assert!(analyzer.analyze(&program).is_ok());
+    assert!(analyzer.get_symbol("x").is_none());
+
+    // An inner declaration does not replace the outer one
+    let ts_code = r#"
+interface Circle {
+    radius: number;
+}
+
+interface Square {
+    side: number;
+}
+
+let x: Circle | Square;
+{
+    let x: Circle;
+}
+let r = x.radius;
+"#;
+    let program = compiler.parse_str(ts_code).unwrap();
+    let mut analyzer = SemanticAnalyzer::new();
+    assert!(analyzer.analyze(&program).is_err());
+}
+
+/// Test references to undeclared names are reported
+#[test]
+fn test_undeclared_identifier() {
+    let compiler = Compiler::new();
+
+    let program = compiler.parse_str("let x = foo + 1;").unwrap();
+    let mut analyzer = SemanticAnalyzer::new();
+    let error = analyzer.analyze(&program).unwrap_err();
+    assert!(matches!(error, CompilerError::SemanticError { .. }));
+    assert!(error.to_string().contains("foo"));
+
+    let ts_code = r#"
+let foo = 1;
+let x = foo + 1;
+console.log(Math.max(x, foo));
+"#;
+    let program = compiler.parse_str(ts_code).unwrap();
+    let mut analyzer = SemanticAnalyzer::new();
+    assert!(analyzer.analyze(&program).is_ok());
+}
+
+/// Test const bindings cannot be reassigned and stay immutable in Rust
+#[test]
+fn test_const_reassignment() {
+    let mut compiler = Compiler::new();
+
+    let program = compiler.parse_str("const x = 1; x = 2;").unwrap();
+    let mut analyzer = SemanticAnalyzer::new();
+    let error = analyzer.analyze(&program).unwrap_err();
+    assert!(matches!(error, CompilerError::SemanticError { .. }));
+
+    let program = compiler.parse_str("let y = 1; y = 2;").unwrap();
+    let mut analyzer = SemanticAnalyzer::new();
+    assert!(analyzer.analyze(&program).is_ok());
+
+    let rust_code = compiler.compile_str("const x = 1; let y = 2; y = 3;").unwrap();
+    assert!(rust_code.contains("let x: f64 = 1.0;"));
+    assert!(!rust_code.contains("let mut x"));
+    assert!(rust_code.contains("let mut y: f64 = 2.0;"));
+
+    // Assigning to a constant has no translation, so it fails even outside strict mode
+    let result = compiler.compile_str("const x = 1; x = 2;");
+    assert!(matches!(result, Err(CompilerError::SemanticError { .. })), "{:?}", result);
+    let result = compiler.compile_str("function f(): void { const n = 1; n++; }");
+    assert!(matches!(result, Err(CompilerError::SemanticError { .. })), "{:?}", result);
+}
+
+/// Test only reassigned `let` bindings are generated as `let mut`
+#[test]
+fn test_let_mut_for_reassigned_bindings() {
+    let ts_code = r#"
+function tally(n: number): number {
+    let total = 0;
+    let step = 2;
+    total = total + n * step;
+    return total;
+}
+"#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+
+    assert!(rust_code.contains("let mut total: f64 = 0.0;"));
+    assert!(rust_code.contains("let step: f64 = 2.0;"));
+    assert!(!rust_code.contains("let mut step"));
+}
+
+/// Test variable types are inferred from the callee's declared return type
+#[test]
+fn test_call_return_type_inference() {
+    let ts_code = r#"
+function f(): number {
+    return 1;
+}
+
+function label(): string {
+    return "one";
+}
+
+function run(): void {
+    const x = f();
+    const y = label();
+    const z = missing();
+}
+"#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+
+    assert!(rust_code.contains("let x: f64 = f();"));
+    assert!(rust_code.contains("let y: String = label();"));
+    assert!(rust_code.contains("let z: Box<dyn Any> = missing();"));
+}
+
+/// Test union aliases become enums with From impls and intersections become structs
+#[test]
+fn test_union_enum_and_intersection_struct() {
+    let ts_code = r#"
+type Value = string | number | boolean;
+
+interface Named {
+    name: string;
+}
+
+type Person = Named & { age: number };
+"#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+
+    assert!(rust_code.contains("pub enum Value {\n    String(String),\n    Number(f64),\n    Boolean(bool)\n}"));
+    assert!(rust_code.contains("impl From<String> for Value {\n    fn from(value: String) -> Self {\n        Self::String(value)\n    }\n}"));
+    assert!(rust_code.contains("impl From<f64> for Value"));
+    assert!(rust_code.contains("impl From<bool> for Value"));
+    assert!(rust_code.contains("pub struct Person {\n    pub name: String,\n    pub age: f64\n}"));
+}
+
+/// Test readonly arrays and properties have no mutation API
+#[test]
+fn test_readonly_arrays_and_properties() {
+    let ts_code = r#"
+interface Config {
+    readonly limits: readonly number[];
+    name: string;
+}
+
+class Grid {
+    readonly cells: readonly number[];
+}
+"#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+
+    assert!(rust_code.contains("fn get_limits(&self) -> Box<[f64]>;"));
+    assert!(!rust_code.contains("set_limits"));
+    assert!(rust_code.contains("fn set_name(&mut self, value: String);"));
+    assert!(rust_code.contains("    cells: Box<[f64]>"));
+    assert!(!rust_code.contains("pub cells"));
+    assert!(rust_code.contains("    pub fn cells(&self) -> &Box<[f64]> {\n        &self.cells\n    }"));
+    assert!(!rust_code.contains("Vec<f64>"));
+}
+
+/// Test unions of literal types become enums that keep the literal values
+#[test]
+fn test_literal_union_enum() {
+    let ts_code = r#"
+type Dir = "n" | "s";
+type Mode = "read-only" | "read-write";
+"#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+
+    assert!(rust_code.contains(
+        "pub enum Dir {\n    #[serde(rename = \"n\")]\n    N,\n    #[serde(rename = \"s\")]\n    S\n}"
+    ));
+    assert!(rust_code.contains("    #[serde(rename = \"read-only\")]\n    ReadOnly,"));
+    assert!(rust_code.contains("    #[serde(rename = \"read-write\")]\n    ReadWrite"));
+}
+
+/// Test Exclude, Extract and NonNullable resolve over unions
+#[test]
+fn test_exclude_extract_non_nullable() {
+    let ts_code = r#"
+type Dir = "n" | "s" | "e";
+type NoSouth = Exclude<Dir, "s">;
+type Vertical = Extract<Dir, "n" | "s" | "w">;
+type Label = NonNullable<string | null | undefined>;
+"#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+
+    assert!(rust_code.contains(
+        "pub enum NoSouth {\n    #[serde(rename = \"n\")]\n    N,\n    #[serde(rename = \"e\")]\n    E\n}"
+    ));
+    assert!(rust_code.contains(
+        "pub enum Vertical {\n    #[serde(rename = \"n\")]\n    N,\n    #[serde(rename = \"s\")]\n    S\n}"
+    ));
+    assert!(rust_code.contains("pub type Label = String;"));
+
+    // The operand must resolve to a known union
+    assert!(compiler.compile_str("type T = Exclude<Missing, \"a\">;").is_err());
+}
+
+/// Test object type methods go into a trait rather than the struct
+#[test]
+fn test_object_type_methods_impl() {
+    let ts_code = r#"
+type Point = {
+    x: number;
+    label?: string;
+    scaled(factor: number): number;
+};
+"#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+
+    assert!(rust_code.contains(
+        "pub struct Point {\n    pub x: f64,\n    pub label: Option<String>\n}"
+    ));
+    assert!(rust_code.contains("pub trait PointMethods {\n    fn scaled(&self, factor: f64) -> f64;\n}"), "{}", rust_code);
+    assert!(!rust_code.contains("unimplemented!()"), "{}", rust_code);
+}
+
+/// Test optional interface members get default trait bodies
+#[test]
+fn test_optional_interface_members() {
+    let ts_code = r#"
+interface Greeter {
+    name: string;
+    nickname?: string;
+    greet?(): string;
+    wave?(times: number): void;
+    describe(): string;
+}
+"#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+
+    assert!(rust_code.contains("    fn get_name(&self) -> String;"));
+    assert!(rust_code.contains("    fn get_nickname(&self) -> Option<String> {\n        None\n    }"));
+    assert!(rust_code.contains("    fn set_nickname(&mut self, value: Option<String>);"), "{}", rust_code);
+    assert!(rust_code.contains("    fn greet(&self) -> Option<String> {\n        None\n    }"));
+    assert!(!rust_code.contains("fn greet(&self) -> String;"));
+    assert!(rust_code.contains("    fn wave(&self, times: f64) {}"));
+    assert!(rust_code.contains("    fn describe(&self) -> String;"));
+}
+
+/// Test a class implements an interface's optional property through its field
+#[test]
+fn test_optional_interface_property_impl() {
+    let ts_code = r#"
+interface Shape {
+    name?: string;
+    area(): number;
+}
+
+class Sq implements Shape {
+    name?: string;
+    side: number;
+
+    constructor(side: number) {
+        this.side = side;
+    }
+
+    area(): number {
+        return this.side * this.side;
+    }
+}
+"#;
+
+    let mut compiler = Compiler::new().with_generator_config(GeneratorConfig {
+        derive_serde: false,
+        ..Default::default()
+    });
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    let output = run_generated(
+        &rust_code,
+        "fn main() { let mut s = Sq::new(2.0); Shape::set_name(&mut s, Some(\"sq\".to_string())); println!(\"{:?} {}\", Shape::get_name(&s), Shape::area(&s)); }",
+    );
+    assert_eq!(output.trim(), "Some(\"sq\") 4");
+}
+
+/// Test index signatures map to the std indexing traits
+#[test]
+fn test_index_signature_index_impls() {
+    let ts_code = r#"
+interface Scores {
+    [name: string]: number;
+}
+
+type Labels = {
+    title: string;
+    [key: string]: string;
+};
+"#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+
+    assert!(rust_code.contains(
+        "pub trait Scores: for<'a> std::ops::Index<&'a str, Output = f64> + for<'a> std::ops::IndexMut<&'a str> {"
+    ));
+    assert!(!rust_code.contains("index_get"));
+    assert!(rust_code.contains("pub struct Labels {\n    pub title: String,\n    pub entries: HashMap<String, String>\n}"));
+    assert!(rust_code.contains(
+        "impl std::ops::Index<&str> for Labels {\n    type Output = String;\n\n    fn index(&self, key: &str) -> &String {\n        &self.entries[key]\n    }\n}"
+    ));
+    assert!(rust_code.contains("impl std::ops::IndexMut<&str> for Labels {"));
+    assert!(rust_code.contains("self.entries.entry(key.to_owned()).or_default()"), "{}", rust_code);
+
+    // Assigning through a missing key inserts it, as in TypeScript
+    let mut compiler = Compiler::new().with_generator_config(GeneratorConfig {
+        derive_serde: false,
+        ..Default::default()
+    });
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    let output = run_generated(
+        &rust_code,
+        "fn main() { let mut l = Labels { title: String::new(), entries: HashMap::new() }; l[\"a\"] = \"x\".to_string(); println!(\"{}\", l[\"a\"]); }",
+    );
+    assert_eq!(output.trim(), "x");
+}
+
+/// Test generator config can map number to i64
+#[test]
+fn test_generator_config_number_as_i64() {
+    let mut compiler = Compiler::new().with_generator_config(GeneratorConfig {
+        number_as_i64: true,
+        ..Default::default()
+    });
+    let result = compiler.compile_str("let x: number = 1;").unwrap();
+    assert!(result.contains("let x: i64 = 1;"), "{}", result);
+
+    let result = compiler.compile_str("let ratio: number = 1.5;");
+    assert!(matches!(result, Err(CompilerError::UnsupportedFeature { .. })), "{:?}", result);
+}
+
+/// Test serde derives and struct visibility are applied where types are generated
+#[test]
+fn test_generator_config_derives_and_visibility() {
+    let ts_code = r#"
+        class Label { text: string; }
+        type Mode = "on" | "off";
+        function describe(): string {
+            return "pub struct , Serialize, Deserialize";
+        }
+    "#;
+    let mut compiler = Compiler::new().with_generator_config(GeneratorConfig {
+        derive_serde: false,
+        struct_visibility: "pub(crate)".to_string(),
+        ..Default::default()
+    });
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("#[derive(Debug, Clone)]\npub(crate) struct Label"), "{}", rust_code);
+    assert!(rust_code.contains("#[derive(Debug, Clone, Copy, PartialEq, Eq)]\npub enum Mode"), "{}", rust_code);
+    assert!(!rust_code.contains("#[serde("), "{}", rust_code);
+    assert!(!rust_code.contains("use serde"), "{}", rust_code);
+    // String literals in the output are left alone
+    assert!(rust_code.contains("\"pub struct , Serialize, Deserialize\""), "{}", rust_code);
+}
+
+/// Test main is only emitted when requested
+#[test]
+fn test_main_emission_is_opt_in() {
+    let source = "class Point { x: number; }";
+    let result = Compiler::new().compile_str(source).unwrap();
+    assert!(!result.contains("fn main"), "{}", result);
+
+    let result = Compiler::new().with_main(true).compile_str(source).unwrap();
+    assert!(result.contains("fn main"), "{}", result);
+
+    // Output written to a directory is a library crate unless main is requested
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("point.ts");
+    fs::write(&input_file, source).unwrap();
+    let output_dir = temp_dir.path().join("out");
+    fs::create_dir_all(&output_dir).unwrap();
+    Compiler::new().compile(&input_file, &output_dir).unwrap();
+    let lib_rs = fs::read_to_string(output_dir.join("src").join("lib.rs")).unwrap();
+    assert!(lib_rs.contains("pub struct Point"), "{}", lib_rs);
+    assert!(!output_dir.join("src").join("main.rs").exists());
+
+    let output_dir = temp_dir.path().join("bin");
+    fs::create_dir_all(&output_dir).unwrap();
+    Compiler::new().with_main(true).compile(&input_file, &output_dir).unwrap();
+    let main_rs = fs::read_to_string(output_dir.join("src").join("main.rs")).unwrap();
+    assert!(main_rs.contains("fn main"), "{}", main_rs);
+}
+
+/// Test imports are emitted once, sorted, and only when used
+#[test]
+fn test_imports_are_deduplicated() {
+    let ts_code = r#"
+        import { readFile } from "fs";
+        import { readFile } from "fs";
+        interface Config { name: string; }
+        namespace Shapes { export class Circle { radius: number; } }
+        class Registry { entries: Map<string, number>; }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    let uses: Vec<&str> = rust_code.lines().filter(|line| line.starts_with("use ")).collect();
+    let mut unique = uses.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(uses, unique, "{}", rust_code);
+    assert!(uses.contains(&"use serde::{Deserialize, Serialize};"), "{}", rust_code);
+}
+
+/// Test names and strings that mention std types do not pull in their imports
+#[test]
+fn test_imports_follow_used_types() {
+    let ts_code = r#"
+        const label: string = "HashMap of dyn Any, HashSet too";
+        function describeHashMap(HashSetName: string): string { return label + HashSetName; }
+        const seen: Set<string> = new Set(["a"]);
+    "#;
+
+    let mut compiler = Compiler::new().with_generator_config(GeneratorConfig {
+        derive_serde: false,
+        ..Default::default()
+    });
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(!rust_code.contains("use std::collections::HashMap;"), "{}", rust_code);
+    assert!(!rust_code.contains("use std::any::Any;"), "{}", rust_code);
+    assert!(!rust_code.contains("use serde::"), "{}", rust_code);
+    assert!(rust_code.contains("use std::collections::HashSet;"), "{}", rust_code);
+}
+
+/// Test a purely-typed program needs neither `Any` nor `serde_json`
+#[test]
+fn test_typed_program_skips_any_and_serde_json() {
+    let ts_code = "interface User { name: string; age: number; }\nfunction greet(user: User): string { return user.name; }";
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(!rust_code.contains("use std::any::Any;"), "{}", rust_code);
+    assert!(!rust_code.contains("serde_json"), "{}", rust_code);
+
+    let temp_dir = TempDir::new().unwrap();
+    let input_dir = temp_dir.path().join("src");
+    let output_dir = temp_dir.path().join("out");
+    fs::create_dir_all(&input_dir).unwrap();
+    fs::write(input_dir.join("lib.ts"), ts_code).unwrap();
+    compiler.compile_project(&input_dir, &output_dir).unwrap();
+    let cargo_toml = fs::read_to_string(output_dir.join("Cargo.toml")).unwrap();
+    assert!(!cargo_toml.contains("serde_json"), "{}", cargo_toml);
+
+    fs::write(input_dir.join("lib.ts"), "let data: any = 1;").unwrap();
+    compiler.compile_project(&input_dir, &output_dir).unwrap();
+    let cargo_toml = fs::read_to_string(output_dir.join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains("serde_json"), "{}", cargo_toml);
+}
+
+/// Test if/else chains inside function bodies
+#[test]
+fn test_if_else_statements() {
+    let ts_code = r#"
+        function sign(x: number): number {
+            if (x > 0) {
+                return 1;
+            } else if (x < 0) {
+                return -1;
+            } else {
+                return 0;
+            }
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("if (x > 0.0) {\n        return 1.0;\n    }"), "{}", rust_code);
+    assert!(rust_code.contains("} else if (x < 0.0) {\n        return -1.0;\n    }"), "{}", rust_code);
+    assert!(rust_code.contains("} else {\n        return 0.0;\n    }"), "{}", rust_code);
+    assert!(!rust_code.contains("TODO"), "{}", rust_code);
+}
+
+/// Test throw statements become panics
+#[test]
+fn test_throw_statement() {
+    let ts_code = r#"
+        function fail(reason: string): void {
+            if (reason === "") {
+                throw new Error("boom");
+            }
+            throw reason;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("panic!(\"{}\", \"boom\");"), "{}", rust_code);
+    assert!(rust_code.contains("panic!(\"{}\", reason);"), "{}", rust_code);
+}
+
+/// Test built-in constructors map to std types
+#[test]
+fn test_new_builtin_constructors() {
+    let ts_code = r#"
+        function setup(): void {
+            const scores = new Map<string, number>();
+            const seen = new Set();
+            const slots = new Array(3);
+            const err = new Error("e");
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("= HashMap::new();"), "{}", rust_code);
+    assert!(rust_code.contains("let seen: HashSet<Box<dyn Any>> = HashSet::new();"), "{}", rust_code);
+    assert!(rust_code.contains("let slots: Vec<Box<dyn Any>> = Vec::with_capacity(3);"), "{}", rust_code);
+    assert!(rust_code.contains("let err: String = \"e\".to_string();"), "{}", rust_code);
+    assert!(!rust_code.contains("Box::new(Map::new())"), "{}", rust_code);
+
+    // Constructor arguments fill the collection
+    let ts_code = r#"
+        function count(): number {
+            const scores = new Map<string, number>([["a", 1], ["b", 2]]);
+            const tags = new Set<string>(["x", "y", "x"]);
+            const copy = new Map<string, number>(scores);
+            return copy.size + tags.size;
+        }
+    "#;
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(
+        rust_code.contains("HashMap::from_iter([(\"a\".to_string(), 1.0), (\"b\".to_string(), 2.0)])"),
+        "{}",
+        rust_code
+    );
+    assert!(rust_code.contains("HashSet::from_iter(vec!["), "{}", rust_code);
+    assert!(rust_code.contains("HashMap::from_iter(scores)"), "{}", rust_code);
+
+    let result = compiler.compile_str("const m = new Map([1, 2]);");
+    assert!(matches!(result, Err(CompilerError::UnsupportedFeature { .. })), "{:?}", result);
+}
+
+/// Test Map and Set types map to HashMap and HashSet
+#[test]
+fn test_map_and_set_types() {
+    let ts_code = r#"
+        interface Index {
+            scores: Map<string, number>;
+            tags: Set<string>;
+            frozen: ReadonlyMap<string, boolean>;
+            names: ReadonlySet<string>;
+        }
+        function setup(): void {
+            const scores = new Map<string, number>();
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("HashMap<String, f64>"), "{}", rust_code);
+    assert!(rust_code.contains("HashSet<String>"), "{}", rust_code);
+    assert!(rust_code.contains("HashMap<String, bool>"), "{}", rust_code);
+    assert!(rust_code.contains("let scores: HashMap<String, f64> = HashMap::new();"), "{}", rust_code);
+    assert!(rust_code.contains("use std::collections::HashMap;"), "{}", rust_code);
+    assert!(rust_code.contains("use std::collections::HashSet;"), "{}", rust_code);
+}
+
+/// Test Date maps to a configurable Rust type
+#[test]
+fn test_date_type_mapping() {
+    let ts_code = "function stamp(): void { let d: Date; }";
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("let d: std::time::SystemTime;"), "{}", rust_code);
+
+    let mut compiler = Compiler::new().with_generator_config(GeneratorConfig {
+        date_type: "chrono::DateTime<chrono::Utc>".to_string(),
+        ..Default::default()
+    });
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("let d: chrono::DateTime<chrono::Utc>;"), "{}", rust_code);
+}
+
+/// Test rest parameters become a trailing Vec
+#[test]
+fn test_rest_parameters() {
+    let ts_code = r#"
+        function sum(...nums: number[]): number {
+            return 0;
+        }
+        function run(xs: number[]): void {
+            sum(1, 2, 3);
+            sum(...xs);
+            sum(1, ...xs);
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub fn sum(nums: Vec<f64>) -> f64"), "{}", rust_code);
+    assert!(rust_code.contains("sum(vec![1.0, 2.0, 3.0]);"), "{}", rust_code);
+    assert!(rust_code.contains("sum(xs.clone());"), "{}", rust_code);
+    assert!(rust_code.contains("sum([vec![1.0], xs.clone()].concat());"), "{}", rust_code);
+}
+
+/// Test default parameters become optional with the default applied in the body
+#[test]
+fn test_default_parameters() {
+    let ts_code = r#"
+        function greet(name: string = "world"): string {
+            return name;
+        }
+        function run(): void {
+            greet();
+            greet("bob");
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub fn greet(name: Option<String>) -> String"), "{}", rust_code);
+    assert!(rust_code.contains("let name = name.unwrap_or(\"world\".to_string());"), "{}", rust_code);
+    assert!(rust_code.contains("greet(None);"), "{}", rust_code);
+    assert!(rust_code.contains("greet(Some(\"bob\".to_string()));"), "{}", rust_code);
+
+    // Method calls can't be resolved to their class, so method defaults stay required
+    let ts_code = r#"
+        class Greeter {
+            greet(name: string = "world"): string {
+                return name;
+            }
+        }
+    "#;
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub fn greet(&self, name: String) -> String"), "{}", rust_code);
+    assert!(!rust_code.contains("unwrap_or"), "{}", rust_code);
+    assert!(compiler.warnings().iter().any(|w| w.to_string().contains("'name'")));
+}
+
+/// Test logical expressions keep their grouping
+#[test]
+fn test_logical_expressions() {
+    let ts_code = r#"
+        function check(a: boolean, b: boolean, c: boolean): void {
+            const ok = a && b || c;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("let ok: bool = ((a && b) || c);"), "{}", rust_code);
+}
+
+/// Test nullish coalescing falls back only for absent values
+#[test]
+fn test_nullish_coalescing() {
+    let ts_code = r#"
+        const fallback = null ?? "default";
+
+        function orZero(x?: number): number {
+            return x ?? 0;
+        }
+
+        function present(y: number): number {
+            return y ?? 0;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("let fallback: String = \"default\".to_string();"), "{}", rust_code);
+    assert!(rust_code.contains("return x.unwrap_or(0.0);"), "{}", rust_code);
+    assert!(rust_code.contains("return y;"), "{}", rust_code);
+}
+
+/// Test ternaries become if/else expressions
+#[test]
+fn test_conditional_expressions() {
+    let ts_code = r#"
+        function sign(x: number): void {
+            const m = x > 0 ? x : -x;
+            const s = x > 0 ? "pos" : x < 0 ? "neg" : "zero";
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("= if (x > 0.0) { x } else { -x };"), "{}", rust_code);
+    assert!(
+        rust_code.contains("let s: String = if (x > 0.0) { \"pos\".to_string() } else if (x < 0.0) { \"neg\".to_string() } else { \"zero\".to_string() };"),
+        "{}",
+        rust_code
+    );
+}
+
+/// Test parenthesized expressions keep their precedence
+#[test]
+fn test_parenthesized_expressions() {
+    let ts_code = r#"
+        function scale(a: number, b: number, c: number): void {
+            const y = (a + b) * c;
+            const ok = a > 0 && (b > 0 || c > 0);
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("((a + b) * c)"), "{}", rust_code);
+    assert!(rust_code.contains("((a > 0.0) && ((b > 0.0) || (c > 0.0)))"), "{}", rust_code);
+    assert!(!rust_code.contains("TODO"), "{}", rust_code);
+}
+
+/// Test as-expressions become casts or downcasts
+#[test]
+fn test_as_expressions() {
+    let ts_code = r#"
+        function convert(x: number, value: any): void {
+            const n = x as number;
+            const p = value as Point;
+            const fixed = [1, 2] as const;
+        }
+    "#;
+
+    let mut compiler = Compiler::new().with_runtime(true);
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("let n: f64 = (x as f64);"), "{}", rust_code);
+    assert!(rust_code.contains("let p: Point = *value.downcast::<Point>()"), "{}", rust_code);
+    assert!(rust_code.contains("= vec![1.0, 2.0];"), "{}", rust_code);
+}
+
+/// Test strings cast to numbers are parsed and strings cast to strings are kept
+#[test]
+fn test_as_expressions_on_strings() {
+    let ts_code = r#"
+        function parsed(): number {
+            const s = "42";
+            return s as number;
+        }
+
+        function same(t: string): string {
+            return t as string;
+        }
+    "#;
+
+    let mut compiler = Compiler::new().with_generator_config(GeneratorConfig {
+        derive_serde: false,
+        ..Default::default()
+    });
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("return t;"), "{}", rust_code);
+    let output = run_generated(
+        &rust_code,
+        "fn main() { println!(\"{} {}\", parsed() + 1.0, same(\"a\".to_string())); }",
+    );
+    assert_eq!(output.trim(), "43 a");
+}
+
+/// Test non-null assertions unwrap the operand
+#[test]
+fn test_non_null_assertions() {
+    let ts_code = r#"
+        function read(maybe?: string, flag?: boolean): void {
+            const v = maybe!;
+            const off = !flag!;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("= maybe.unwrap();"), "{}", rust_code);
+    assert!(rust_code.contains("= !flag.unwrap();"), "{}", rust_code);
+}
+
+/// Test generator functions collect their yielded values into an iterator
+#[test]
+fn test_generator_functions() {
+    let ts_code = "function* g() { yield 1; yield 2; }";
+
+    let mut compiler = Compiler::new();
+    let program = compiler.parse_str(ts_code).unwrap();
+    match &program.statements[0] {
+        Statement::FunctionDeclaration(func) => assert!(func.generator),
+        other => panic!("expected a function declaration, got {:?}", other),
+    }
+
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub fn g() -> impl Iterator<Item = f64>"), "{}", rust_code);
+    assert!(rust_code.contains("yielded.push(1.0);\n    yielded.push(2.0);"), "{}", rust_code);
+    assert!(rust_code.contains("yielded.into_iter()\n}"), "{}", rust_code);
+}
+
+/// Test shorthand object properties
+#[test]
+fn test_shorthand_object_properties() {
+    let ts_code = r#"
+        type Point = { x: number; y: number; };
+        function build(x: number, y: number): void {
+            const o = { x, y };
+            const p: Point = { x, y: 2 };
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let program = compiler.parse_str(ts_code).unwrap();
+    let Statement::FunctionDeclaration(func) = &program.statements[1] else {
+        panic!("expected a function declaration");
+    };
+    let Statement::BlockStatement(body) = &*func.body else {
+        panic!("expected a block body");
+    };
+    let Statement::VariableDeclaration(var) = &body.statements[0] else {
+        panic!("expected a variable declaration");
+    };
+    let Some(Expression::Object(object)) = &var.initializer else {
+        panic!("expected an object literal");
+    };
+    assert!(object.properties.iter().all(|property| property.shorthand));
+
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("(\"x\".to_string(), Box::new(x) as Box<dyn Any>),"), "{}", rust_code);
+    assert!(rust_code.contains("(\"y\".to_string(), Box::new(y) as Box<dyn Any>)"), "{}", rust_code);
+    assert!(rust_code.contains("let p: Point = Point { x, y: 2.0 };"), "{}", rust_code);
+}
+
+/// Test method shorthand in object literals
+#[test]
+fn test_object_method_shorthand() {
+    let ts_code = "const o = { greet() { return 1; } };";
+
+    let mut compiler = Compiler::new();
+    let program = compiler.parse_str(ts_code).unwrap();
+    let Statement::VariableDeclaration(var) = &program.statements[0] else {
+        panic!("expected a variable declaration");
+    };
+    let Some(Expression::Object(object)) = &var.initializer else {
+        panic!("expected an object literal");
+    };
+    assert!(object.properties[0].method);
+    assert!(matches!(object.properties[0].value, Expression::Function(_)));
+
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("(\"greet\".to_string(), Box::new(|| -> f64 {"), "{}", rust_code);
+}
+
+/// Test string enums convert to and from their string values
+#[test]
+fn test_string_enum_conversions() {
+    let ts_code = r#"
+        enum Status {
+            Pending = "pending",
+            Done = "done"
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("impl Status {"), "{}", rust_code);
+    assert!(rust_code.contains("pub fn as_str(&self) -> &str"), "{}", rust_code);
+    assert!(rust_code.contains("Self::Pending => \"pending\","), "{}", rust_code);
+    assert!(rust_code.contains("pub fn from_str(s: &str) -> Option<Self>"), "{}", rust_code);
+    assert!(rust_code.contains("\"done\" => Some(Self::Done),"), "{}", rust_code);
+}
+
+/// Test const enums generate inlinable constants
+#[test]
+fn test_const_enum_constants() {
+    let ts_code = r#"
+        const enum E {
+            A = 1,
+            B
+        }
+
+        function first(): number {
+            return E.A;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub const E_A: f64 = 1.0;"), "{}", rust_code);
+    assert!(rust_code.contains("pub const E_B: f64 = 2.0;"), "{}", rust_code);
+    assert!(rust_code.contains("return E_A;"), "{}", rust_code);
+    assert!(!rust_code.contains("enum E"), "{}", rust_code);
+
+    let output = run_generated(&rust_code, "fn main() { println!(\"{} {}\", first(), E_B); }");
+    assert_eq!(output.trim(), "1 2");
+}
+
+/// Test ambient declarations generate stubs
+#[test]
+fn test_declare_statements() {
+    let ts_code = r#"
+        declare function ext(x: number): string;
+        declare const VERSION: string;
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub fn ext(x: f64) -> String"), "{}", rust_code);
+    assert!(rust_code.contains("unimplemented!()"), "{}", rust_code);
+    assert!(!rust_code.contains("VERSION"), "{}", rust_code);
+    assert!(compiler.warnings().iter().any(|w| w.to_string().contains("VERSION")));
+}
+
+/// Test a lone `declare` reports a parse error instead of panicking
+#[test]
+fn test_declare_without_declaration() {
+    let mut compiler = Compiler::new();
+    let error = compiler.compile_str("declare").unwrap_err();
+    assert!(matches!(error, CompilerError::ParseError { .. }), "{:?}", error);
+}
+
+/// Test the library prints nothing to stdout while compiling
+#[test]
+fn test_no_debug_output_on_stdout() {
+    let ts_code = r#"
+class Point {
+    constructor(x: number) {}
+}
+function add(a: number, b: number): number { return a + b; }
+"#;
+
+    // The test harness captures printing, so rerun this test uncaptured in a child process
+    if std::env::var_os("TSRC_STDOUT_CHILD").is_some() {
+        println!("<<<");
+        Compiler::new().compile_str(ts_code).unwrap();
+        println!(">>>");
+        return;
+    }
+    let child = std::process::Command::new(std::env::current_exe().unwrap())
+        .args(["test_no_debug_output_on_stdout", "--exact", "--nocapture"])
+        .env("TSRC_STDOUT_CHILD", "1")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&child.stdout);
+    assert!(child.status.success(), "{}", stdout);
+    let start = stdout.find("<<<\n").expect("child did not run") + 4;
+    let end = stdout.find(">>>").expect("child did not finish");
+    assert_eq!(&stdout[start..end], "", "{}", stdout);
+}
+
+/// Test strict equality and modulo operators
+#[test]
+fn test_strict_equality_and_modulo() {
+    let ts_code = r#"
+        function same(a: number, b: number): boolean {
+            return a === b;
+        }
+
+        function different(a: number, b: number): boolean {
+            return a !== b;
+        }
+
+        function remainder(a: number, b: number): number {
+            return a % b;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("(a == b)"), "{}", rust_code);
+    assert!(rust_code.contains("(a != b)"), "{}", rust_code);
+    assert!(rust_code.contains("(a % b)"), "{}", rust_code);
+}
+
+/// Test nested and array-of-generic type arguments
+#[test]
+fn test_nested_generic_types() {
+    let ts_code = r#"
+        interface Index {
+            scores: Map<string, number[]>;
+            nested: Map<string, Array<number>>;
+            pages: Array<Map<string, Array<string>>>;
+            boxes: Box<number>[];
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("HashMap<String, Vec<f64>>"), "{}", rust_code);
+    assert!(!rust_code.contains("Array<"), "{}", rust_code);
+    assert!(rust_code.contains("Vec<HashMap<String, Vec<String>>>"), "{}", rust_code);
+    assert!(rust_code.contains("Vec<Box<f64>>"), "{}", rust_code);
+}
+
+/// Test type arguments of a generic reference are mapped
+#[test]
+fn test_generic_type_reference_arguments() {
+    let ts_code = r#"
+        class Container<T> {
+            value: T;
+        }
+
+        function first(items: Container<number>): Container<number> {
+            return items;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(
+        rust_code.contains("pub fn first(items: Container<f64>) -> Container<f64>"),
+        "{}",
+        rust_code
+    );
+}
+
+/// Test extended interfaces become supertraits
+#[test]
+fn test_interface_extends_supertraits() {
+    let ts_code = r#"
+        interface Named { name: string }
+        interface Aged { age: number }
+        interface Person extends Named { id: number }
+        interface Employee extends Named, Aged { role: string }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub trait Person: Named {"), "{}", rust_code);
+    assert!(rust_code.contains("pub trait Employee: Named + Aged {"), "{}", rust_code);
+}
+
+/// Test generic classes implement generic interfaces as traits
+#[test]
+fn test_generic_interface_implementation() {
+    let ts_code = r#"
+interface Container<T> {
+    value: T;
+    getValue(): T;
+    setValue(value: T): void;
+}
+
+class Box<T> implements Container<T> {
+    value: T;
+
+    constructor(value: T) {
+        this.value = value;
+    }
+
+    getValue(): T {
+        return this.value;
+    }
+
+    setValue(value: T): void {
+        this.value = value;
+    }
+}
+"#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("impl<T> Container<T> for Box<T> {"), "{}", rust_code);
+    assert!(rust_code.contains("fn get_value(&self) -> T {\n        self.value.clone()"), "{}", rust_code);
+    assert!(rust_code.contains("Self::setValue(self, value)"), "{}", rust_code);
+}
+
+/// Test generic constraints become trait bounds
+#[test]
+fn test_generic_constraint_bounds() {
+    let ts_code = r#"
+        interface Ordered {
+            rank(): number;
+        }
+
+        function max<T extends Ordered>(a: T, b: T): T {
+            return a;
+        }
+
+        class Sorted<T extends Ordered> {
+            items: T[];
+        }
+
+        interface Ranked<T extends Ordered> {
+            best(): T;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub fn max<T: Ordered>(a: T, b: T) -> T"), "{}", rust_code);
+    assert!(rust_code.contains("pub struct Sorted<T: Ordered>"), "{}", rust_code);
+    assert!(rust_code.contains("pub trait Ranked<T: Ordered>"), "{}", rust_code);
+}
+
+/// Test type parameter defaults appear only in declarations
+#[test]
+fn test_generic_parameter_defaults() {
+    let ts_code = r#"
+        class Wrapper<T = number> {
+            value: T;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub struct Wrapper<T = f64>"), "{}", rust_code);
+    assert!(rust_code.contains("impl<T> Wrapper<T>"), "{}", rust_code);
+}
+
+/// Test identifiers reserved in Rust are escaped
+#[test]
+fn test_rust_keyword_identifiers() {
+    let ts_code = r#"
+        let match = 1;
+
+        function move(ref: number): number {
+            return ref + match;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("let r#match: f64 = 1.0;"), "{}", rust_code);
+    assert!(rust_code.contains("pub fn r#move(r#ref: f64) -> f64"), "{}", rust_code);
+    assert!(rust_code.contains("(r#ref + r#match)"), "{}", rust_code);
+}
+
+/// Test camelCase names can be renamed to snake_case consistently
+#[test]
+fn test_rename_to_snake_case() {
+    let ts_code = r#"
+        class Counter {
+            currentValue: number;
+
+            getValue(): number {
+                return this.currentValue;
+            }
+        }
+
+        function readCounter(counter: Counter): number {
+            return counter.getValue();
+        }
+    "#;
+
+    let mut compiler = Compiler::new().with_generator_config(GeneratorConfig {
+        rename_to_snake_case: true,
+        ..Default::default()
+    });
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub current_value: f64"), "{}", rust_code);
+    assert!(rust_code.contains("pub fn get_value(&self) -> f64"), "{}", rust_code);
+    assert!(rust_code.contains("self.current_value"), "{}", rust_code);
+    assert!(rust_code.contains("pub fn read_counter(counter: Counter)"), "{}", rust_code);
+    assert!(rust_code.contains("counter.get_value()"), "{}", rust_code);
+    assert!(!rust_code.contains("getValue"), "{}", rust_code);
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub fn getValue(&self) -> f64"), "{}", rust_code);
+}
+
+/// Test the lexer recognizes contextual and type-level keywords
+#[test]
+fn test_unified_keyword_table() {
+    let mut lexer = Lexer::new("get set constructor keyof global".to_string());
+    let tokens = lexer.tokenize().unwrap();
+    assert_eq!(
+        tokens,
+        vec![
+            Token::Keyword(Keyword::Get),
+            Token::Keyword(Keyword::Set),
+            Token::Keyword(Keyword::Constructor),
+            Token::Keyword(Keyword::Keyof),
+            Token::Keyword(Keyword::Global),
+            Token::EOF,
+        ]
+    );
+}
+
+/// Test the deprecated lexer entry points still tokenize like `Lexer::new`
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_lexer_shims() {
+    let source = "const as = 1;";
+    let expected = Lexer::new(source.to_string()).tokenize().unwrap();
+    assert_eq!(Lexer::new_utf8(source.to_string()).tokenize().unwrap(), expected);
+    assert_eq!(
+        TypeScript_Rust_Compiler::lexer_utf8::Utf8Lexer::new(source.to_string()).tokenize().unwrap(),
+        expected
+    );
+    assert!(!expected.contains(&Token::Keyword(Keyword::As)));
+}
+
+/// Test getter declarations lex `get` as a keyword
+#[test]
+fn test_get_keyword_token() {
+    let mut lexer = Lexer::new("get x() {}".to_string());
+    let tokens = lexer.tokenize().unwrap();
+    assert_eq!(tokens[0], Token::Keyword(Keyword::Get));
+    assert_eq!(tokens[1], Token::Identifier("x".to_string()));
+}
+
+/// Test `symbol` and `bigint` type annotations
+#[test]
+fn test_symbol_and_bigint_types() {
+    let mut compiler = Compiler::new();
+    let program = compiler.parse_str("let s: symbol;").unwrap();
+    match &program.statements[0] {
+        Statement::VariableDeclaration(var) => {
+            assert!(matches!(var.type_annotation, Some(Type::Symbol)))
+        }
+        other => panic!("expected a variable declaration, got {:?}", other),
+    }
+
+    let rust_code = compiler
+        .compile_str("let s: symbol;\nfunction widen(x: bigint): bigint { return x; }")
+        .unwrap();
+    assert!(rust_code.contains("let s: Symbol;"), "{}", rust_code);
+    assert!(rust_code.contains("pub fn widen(x: i64) -> i64"), "{}", rust_code);
+}
+
+/// Test input that translates to nothing is reported
+#[test]
+fn test_empty_program_diagnostic() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("comments.ts");
+    let output_file = temp_dir.path().join("comments.rs");
+    fs::write(&input_file, "/* comment */").unwrap();
+
+    let mut compiler = Compiler::new();
+    compiler.compile(&input_file, &output_file).unwrap();
+    assert_eq!(compiler.warnings().len(), 1);
+
+    let mut compiler = Compiler::new().with_strict(true);
+    let result = compiler.compile(&input_file, &output_file);
+    assert!(matches!(result, Err(CompilerError::SemanticError { .. })));
+
+    let mut compiler = Compiler::new().with_strict(true);
+    assert!(compiler.compile_str("  \n").is_ok());
+}
+
+/// Test a statement that fails to parse is reported and later statements are kept
+#[test]
+fn test_skipped_statement_diagnostic() {
+    let ts_code = r#"
+        const a = 1;
+        const b = );
+        enum Status { Active }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let program = compiler.parse_str(ts_code).unwrap();
+    assert_eq!(program.statements.len(), 2);
+
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub enum Status"), "{}", rust_code);
+    assert!(
+        compiler.warnings().iter().any(|w| matches!(w, CompilerError::ParseError { .. })),
+        "{:?}",
+        compiler.warnings()
+    );
+}
+
+/// Test labeled loops with labeled break and continue
+#[test]
+fn test_labeled_loops() {
+    let ts_code = r#"
+        function search(grid: number[][]): void {
+            outer: for (const row of grid) {
+                for (let i = 0; i < 3; i++) {
+                    if (i == 2) continue outer;
+                    if (row[i] == 0) break outer;
+                }
+                break;
+            }
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("'outer: for row in grid.iter() {"), "{}", rust_code);
+    assert!(rust_code.contains("break 'outer;"), "{}", rust_code);
+    assert!(rust_code.contains("continue 'outer;"), "{}", rust_code);
+    assert!(rust_code.contains("break;"), "{}", rust_code);
+}
+
+/// Test do-while loops check their condition after the body
+#[test]
+fn test_do_while_loop() {
+    let ts_code = r#"
+        function count(): number {
+            let x = 0;
+            do { x = x + 1; } while (x < 3);
+            return x;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(
+        rust_code.contains("loop {\n        x = (x + 1.0);\n        if !(x < 3.0) {\n            break;\n        }\n    }"),
+        "{}",
+        rust_code
+    );
+}
+
+/// Test for...in loops iterate over map keys
+#[test]
+fn test_for_in_loop() {
+    let ts_code = r#"
+        function show(map: Record<string, number>): void {
+            for (const key in map) { console.log(key); }
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let program = compiler.parse_str(ts_code).unwrap();
+    let Statement::FunctionDeclaration(func) = &program.statements[0] else {
+        panic!("expected a function declaration, got {:?}", program.statements[0]);
+    };
+    let Statement::BlockStatement(body) = &*func.body else {
+        panic!("expected a block body, got {:?}", func.body);
+    };
+    assert!(matches!(body.statements[0], Statement::ForInStatement(_)));
+
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("for key in map.keys() {\n        println!(\"{}\", key);"), "{}", rust_code);
+}
+
+/// Test exported variables are generated
+#[test]
+fn test_exported_variable() {
+    let mut compiler = Compiler::new();
+    let ts_code = r#"
+        export const VERSION = "1.0";
+        export const RETRIES = 3;
+        export const ENABLED = true;
+    "#;
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub const VERSION: &str = \"1.0\";"), "{}", rust_code);
+    assert!(rust_code.contains("pub const RETRIES: f64 = 3.0;"), "{}", rust_code);
+    assert!(rust_code.contains("pub const ENABLED: bool = true;"), "{}", rust_code);
+
+    let output = run_generated(&rust_code, "fn main() { println!(\"{} {} {}\", VERSION, RETRIES, ENABLED); }");
+    assert_eq!(output.trim(), "1.0 3 true");
+
+    let rust_code = compiler.compile_str("export const LIMIT = Math.max(2, 5);").unwrap();
+    assert!(rust_code.contains("pub static LIMIT: std::sync::LazyLock<"), "{}", rust_code);
+}
+
+/// Test `export { ... }` lists become `pub use` re-exports
+#[test]
+fn test_named_export_list() {
+    let mut compiler = Compiler::new();
+    let program = compiler.parse_str("export { foo, bar as baz };").unwrap();
+    match &program.statements[0] {
+        Statement::ExportNamedDeclaration(export) => {
+            assert_eq!(export.specifiers.len(), 2);
+            assert_eq!(export.specifiers[1].local, "bar");
+            assert_eq!(export.specifiers[1].exported, "baz");
+        }
+        other => panic!("expected a named export, got {:?}", other),
+    }
+
+    let ts_code = r#"
+        function foo(): number { return 1; }
+        function bar(): number { return 2; }
+        export { foo, bar as baz };
+    "#;
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub use self::bar as baz;"), "{}", rust_code);
+    assert!(!rust_code.contains("pub use self::foo"), "{}", rust_code);
+    assert!(!rust_code.contains("Export:"), "{}", rust_code);
+
+    let output = run_generated(&rust_code, "fn main() { println!(\"{}\", foo() + baz()); }");
+    assert_eq!(output.trim(), "3");
+}
+
+/// Test `export type { ... }` re-exports the named types
+#[test]
+fn test_type_only_export_list() {
+    let ts_code = r#"
+        interface Foo { x: number; }
+        type Bar = string;
+        export type { Foo, Bar as Name };
+    "#;
+
+    let mut compiler = Compiler::new();
+    let program = compiler.parse_str(ts_code).unwrap();
+    match &program.statements[2] {
+        Statement::ExportTypeDeclaration(export) => {
+            let names: Vec<&str> = export.specifiers.iter().map(|s| s.exported.as_str()).collect();
+            assert_eq!(names, vec!["Foo", "Name"]);
+        }
+        other => panic!("expected a type-only export, got {:?}", other),
+    }
+
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub use self::Bar as Name;"), "{}", rust_code);
+    assert!(!rust_code.contains("pub use self::Foo"), "{}", rust_code);
+    assert!(!rust_code.contains("exported_types"), "{}", rust_code);
+}
+
+/// Test formatted output indents every block by its nesting depth
+#[test]
+fn test_format_output_indentation() {
+    let ts_code = r#"
+        class Counter {
+            count: number;
+            bump(step: number): void {
+                for (let i = 0; i < step; i++) {
+                    if (i > 2) { console.log("big {"); } else { this.count = this.count + 1; }
+                }
+            }
+        }
+    "#;
+
+    let mut compiler = Compiler::new().with_format(true);
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    let mut open_indents = Vec::new();
+    for line in rust_code.lines().filter(|line| !line.trim().is_empty()) {
+        let indent = line.len() - line.trim_start().len();
+        assert_eq!(indent % 4, 0, "{}", rust_code);
+        if line.trim_start().starts_with('}') {
+            assert_eq!(open_indents.pop(), Some(indent), "{}", rust_code);
+        } else if let Some(&outer) = open_indents.last() {
+            assert_eq!(indent, outer + 4, "{}", rust_code);
+        }
+        if line.ends_with('{') {
+            open_indents.push(indent);
+        }
+    }
+    assert!(open_indents.is_empty(), "{}", rust_code);
+    assert!(!rust_code.contains("\n\n\n"), "{}", rust_code);
+}
+
+/// Test optional chaining maps over optional values and accesses other values directly
+#[test]
+fn test_optional_chaining() {
+    let ts_code = r#"
+class Point {
+    x: number;
+
+    constructor(x: number) {
+        this.x = x;
+    }
+
+    double(): number {
+        return this.x * 2;
+    }
+}
+
+function getX(p?: Point): number {
+    return p?.x ?? 0;
+}
+
+function doubled(p?: Point): number {
+    return p?.double() ?? -1;
+}
+
+function direct(p: Point): number {
+    return p?.x;
+}
+"#;
+
+    let mut compiler = Compiler::new().with_generator_config(GeneratorConfig {
+        derive_serde: false,
+        ..Default::default()
+    });
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("p.as_ref().map(|present| present.x.clone())"), "{}", rust_code);
+    assert!(rust_code.contains("return p.x;"), "{}", rust_code);
+    let output = run_generated(
+        &rust_code,
+        "fn main() { println!(\"{} {} {} {} {}\", getX(Some(Point::new(3.0))), getX(None), doubled(Some(Point::new(3.0))), doubled(None), direct(Point::new(5.0))); }",
+    );
+    assert_eq!(output.trim(), "3 0 6 -1 5");
+}
+
+/// Test `continue` in a for loop still runs the update
+#[test]
+fn test_for_loop_continue_runs_update() {
+    let ts_code = r#"
+        function sumOdd(n: number): number {
+            let total = 0;
+            for (let i = 0; i < n; i++) {
+                if (i % 2 == 0) { continue; }
+                total += i;
+            }
+            return total;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    let output = run_generated(&rust_code, "fn main() { println!(\"{}\", sumOdd(6.0)); }");
+    assert_eq!(output.trim(), "9");
+}
+
+/// Test labeled `continue` runs the outer loop's update and rejects labeled blocks
+#[test]
+fn test_labeled_continue() {
+    let ts_code = r#"
+        function countInner(): number {
+            let count = 0;
+            outer: for (let j = 0; j < 3; j++) {
+                for (let k = 0; k < 3; k++) {
+                    count = count + 1;
+                    continue outer;
+                }
+            }
+            return count;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    let output = run_generated(&rust_code, "fn main() { println!(\"{}\", countInner()); }");
+    assert_eq!(output.trim(), "3");
+
+    let ts_code = "function f(): void { block: { continue block; } }";
+    let result = compiler.compile_str(ts_code);
+    assert!(matches!(result, Err(CompilerError::GenerationError { .. })), "{:?}", result);
+}
+
+/// Test `continue` in a do-while loop still checks the condition
+#[test]
+fn test_do_while_continue_checks_condition() {
+    let ts_code = r#"
+        function countUp(): number {
+            let x = 0;
+            do {
+                x = x + 1;
+                if (x < 5) { continue; }
+            } while (x < 3);
+            return x;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    let output = run_generated(&rust_code, "fn main() { println!(\"{}\", countUp()); }");
+    assert_eq!(output.trim(), "3");
+}
+
+/// Test `mut` is decided per binding and survives earlier semantic errors
+#[test]
+fn test_reassignment_tracking_by_binding() {
+    let ts_code = r#"
+        function bump(): number {
+            let count = 0;
+            fetch("x");
+            count = count + 1;
+            return count;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("let mut count: f64 = 0.0;"), "{}", rust_code);
+
+    let ts_code = r#"
+        let total = 0;
+        function inner(): number {
+            let total = 1;
+            let step = 1;
+            return total + step;
+        }
+        function reassigns(): number {
+            let step = 1;
+            step = 2;
+            return step;
+        }
+        total = 5;
+    "#;
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("let mut total: f64 = 0.0;"), "{}", rust_code);
+    assert!(rust_code.contains("let total: f64 = 1.0;"), "{}", rust_code);
+    assert!(rust_code.contains("let step: f64 = 1.0;"), "{}", rust_code);
+    assert!(rust_code.contains("let mut step: f64 = 1.0;"), "{}", rust_code);
+}
+
+/// Test semantic errors are reported as warnings, and fail in strict mode
+#[test]
+fn test_semantic_errors_reach_warnings() {
+    let ts_code = "let total = 1;\ntotal = missing + 1;";
+
+    let mut compiler = Compiler::new();
+    compiler.compile_str(ts_code).unwrap();
+    assert_eq!(compiler.warnings().len(), 1);
+    assert!(matches!(compiler.warnings()[0], CompilerError::SemanticError { .. }));
+    assert!(compiler.warnings()[0].to_string().contains("missing"));
+
+    let mut compiler = Compiler::new().with_strict(true);
+    let result = compiler.compile_str(ts_code);
+    assert!(matches!(result, Err(CompilerError::SemanticError { .. })), "{:?}", result);
+}
+
+/// Test the formatter skips brackets inside raw strings and block comments
+#[test]
+fn test_format_skips_raw_strings_and_block_comments() {
+    let code = "fn f() {\nlet s = r#\"a\"{\"#;\n/* { */\n/* (\n] */\nif s.is_empty() {\nreturn;\n}\n}\n";
+    assert_eq!(
+        format_rust(code),
+        "fn f() {\n    let s = r#\"a\"{\"#;\n    /* { */\n    /* (\n] */\n    if s.is_empty() {\n        return;\n    }\n}\n"
+    );
+
+    let ts_code = r#"
+        function quoted(text: string): boolean {
+            const re = /a"[{(]/;
+            return re.test(text);
+        }
+    "#;
+    let mut compiler = Compiler::new().with_format(true);
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("\n    return "), "{}", rust_code);
+    assert!(rust_code.trim_end().ends_with("\n}"), "{}", rust_code);
+}
+
+/// Test decorator errors report the decorator's line and column
+#[test]
+fn test_decorator_error_position() {
+    let mut lexer = Lexer::new("let a = 1;\n\n    @log\n    export function f() {}".to_string());
+    let tokens = lexer.tokenize().unwrap();
+    assert_eq!(lexer.positions().len(), tokens.len());
+    assert_eq!(lexer.positions()[0], (1, 1));
+
+    let compiler = Compiler::new();
+    let error = compiler.parse_str("\n\n    @log\n    export function f() {}").unwrap_err();
+    match error {
+        CompilerError::ParseError { line, column, .. } => assert_eq!((line, column), (3, 5)),
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
+
+/// Test decorator arguments are preserved
+#[test]
+fn test_decorator_arguments() {
+    let ts_code = r#"
+class UserController {
+    @Route("/users")
+    list(): string {
+        return "users";
+    }
+}
+"#;
+
+    let mut compiler = Compiler::new();
+    let program = compiler.parse_str(ts_code).unwrap();
+
+    let Statement::ClassDeclaration(class) = &program.statements[0] else {
+        panic!("expected a class declaration");
+    };
+    let ClassMember::Method(method) = &class.body.members[0] else {
+        panic!("expected a method");
+    };
+    let decorator = &method.decorators[0];
+    assert_eq!(decorator.name, "Route");
+    assert!(matches!(
+        &decorator.arguments[..],
+        [Expression::Literal(Literal::String(path))] if path == "/users"
+    ));
+
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("// Decorators: Route(\"/users\")"));
+}
+
+/// Test folding compile-time defines from a config file
+#[test]
+fn test_compile_time_defines() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_file = temp_dir.path().join("tsrc.json");
+    fs::write(&config_file, r#"{ "define": { "__DEV__": false } }"#).unwrap();
+    let config = CompilerConfig::from_file(&config_file).unwrap();
+
+    let ts_code = r#"
+        declare const __DEV__: boolean;
+        function run(): void {
+            if (__DEV__) {
+                debug();
+            }
+            start();
+        }
+    "#;
+
+    let mut compiler = Compiler::new().with_defines(config.define);
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(!rust_code.contains("debug"));
+    assert!(rust_code.contains("start()"));
+
+    let mut compiler = Compiler::new().with_defines(HashMap::from([("__DEV__".to_string(), true)]));
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("debug()"));
+}
+
+/// Test getter and setter bodies accessing their backing field
+#[test]
+fn test_getter_setter_bodies() {
+    let ts_code = r#"
+        class Person {
+            private _name: string;
+            get name(): string {
+                return this._name;
+            }
+            set name(newName: string) {
+                this._name = newName;
+            }
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub fn name(&self) -> String {\n        self._name.clone()\n    }"));
+    assert!(rust_code.contains("pub fn set_name(&mut self, newName: String) {\n        self._name = newName;\n    }"));
+}
+
+/// Test where clauses for complex generic constraints
+#[test]
+fn test_generic_where_clause() {
+    let ts_code = r#"
+        interface Named {
+            name: string;
+        }
+        interface Comparable {
+            compare(other: number): number;
+        }
+        function pick<T extends Named & Comparable, U extends Named>(first: T, second: U): T {
+            return first;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub fn pick<T, U: Named>(first: T, second: U) -> T\nwhere\n    T: Named + Comparable,\n{"));
+}
+
+/// Test that known class decorators affect output and unknown ones warn
+#[test]
+fn test_class_decorator_registry() {
+    let ts_code = r#"
+        @deprecated
+        @serializable
+        class Legacy {
+            id: number;
+        }
+
+        @Entity("users")
+        class User {
+            name: string;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("#[deprecated]\n#[derive(Debug, Clone, Serialize, Deserialize)]\npub struct Legacy"));
+
+    let warnings: Vec<String> = compiler.warnings().iter().map(|w| w.to_string()).collect();
+    assert_eq!(warnings, vec!["Unsupported TypeScript feature: decorator @Entity(\"users\")"]);
+}
+
+/// Test constructor parameter properties and nested field assignments
+#[test]
+fn test_constructor_parameter_properties() {
+    let ts_code = r#"
+        class Account {
+            balance: number;
+            label: string;
+            constructor(private owner: string, readonly id: number, vip: boolean) {
+                {
+                    this.balance = 0;
+                }
+                if (vip) {
+                    this.label = "gold";
+                } else {
+                    this.label = "basic";
+                }
+            }
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub owner: String"));
+    assert!(rust_code.contains("pub id: f64"));
+    assert!(!rust_code.contains("pub vip"));
+    assert!(rust_code.contains("pub fn new(owner: String, id: f64, vip: bool) -> Self"));
+    assert!(rust_code.contains("balance: 0"));
+    assert!(rust_code.contains("label: if vip { \"gold\".to_string() } else { \"basic\".to_string() }"));
+    assert!(rust_code.contains("            owner,\n"));
+    assert!(rust_code.contains("            id\n"));
+}
+
+/// Test decorator factories with object literal arguments
+#[test]
+fn test_decorator_object_argument() {
+    let ts_code = r#"
+        class User {
+            @Column({ nullable: true })
+            name: string;
+
+            @Index()
+            email: string;
+        }
+    "#;
+
+    let compiler = Compiler::new();
+    let program = compiler.parse_str(ts_code).unwrap();
+    let Statement::ClassDeclaration(class) = &program.statements[0] else {
+        panic!("expected a class declaration");
+    };
+    let ClassMember::Property(name) = &class.body.members[0] else {
+        panic!("expected a property");
+    };
+    let decorator = &name.decorators[0];
+    assert_eq!(decorator.name, "Column");
+    let [Expression::Object(options)] = &decorator.arguments[..] else {
+        panic!("expected an object argument");
+    };
+    assert_eq!(options.properties.len(), 1);
+    assert!(matches!(&options.properties[0].key, Expression::Identifier(key) if key == "nullable"));
+    assert!(matches!(&options.properties[0].value, Expression::Literal(Literal::Boolean(true))));
+
+    let ClassMember::Property(email) = &class.body.members[1] else {
+        panic!("expected a property");
+    };
+    assert_eq!(email.decorators[0].name, "Index");
+    assert!(email.decorators[0].arguments.is_empty());
+}
+
+/// Test that a parameter property becomes a field initialized from the parameter
+#[test]
+fn test_parameter_property_field() {
+    let ts_code = r#"
+        class Greeter {
+            constructor(private name: string) {}
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub struct Greeter {\n    pub name: String\n}"));
+    assert!(rust_code.contains("pub fn new(name: String) -> Self {\n        Self {\n            name\n        }\n    }"));
+}
+
+/// Test calling another method through `this`
+#[test]
+fn test_this_method_call() {
+    let ts_code = r#"
+        class Calculator {
+            base: number;
+            compute(x: number): number {
+                return x * 2;
+            }
+            run(x: number): number {
+                return this.compute(x) + this.base;
+            }
+            label(): string {
+                return this.describe();
+            }
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub fn compute(&self, x: f64) -> f64"));
+    assert!(rust_code.contains("return (self.compute(x) + self.base);"));
+    assert!(rust_code.contains("return self.describe();"));
+}
+
+/// Test that method decorators from the registry become attributes
+#[test]
+fn test_deprecated_method_decorator() {
+    let ts_code = r#"
+        class Api {
+            @deprecated
+            fetchAll(): void {
+            }
+
+            @memoize
+            lookup(id: number): string {
+                return "x";
+            }
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("    #[deprecated]\n    pub fn fetchAll(&self)"));
+    assert!(rust_code.contains("    // Decorators: memoize\n    pub fn lookup(&self, id: f64)"));
+    assert_eq!(compiler.warnings().len(), 1);
+}
+
+/// Test hex, octal and binary number literals
+#[test]
+fn test_radix_number_literals() {
+    let mut lexer = Lexer::new("0xFF 0b1010 0o17".to_string());
+    let tokens = lexer.tokenize().unwrap();
+    assert_eq!(tokens[0], Token::RadixNumber(255.0, "0xFF".to_string()));
+    assert_eq!(tokens[1], Token::RadixNumber(10.0, "0b1010".to_string()));
+    assert_eq!(tokens[2], Token::RadixNumber(15.0, "0o17".to_string()));
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str("const mask: number = 0xFF;").unwrap();
+    assert!(rust_code.contains("(0xFF as f64)"));
+}
+
+/// Test class expressions bound to a variable
+#[test]
+fn test_class_expression() {
+    let ts_code = r#"
+        const Widget = class {
+            render(): void {
+            }
+        };
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub struct Widget"));
+    assert!(rust_code.contains("impl Widget {"));
+    assert!(rust_code.contains("pub fn render(&self)"));
+}
+
+/// Test function expressions as named functions and local closures
+#[test]
+fn test_function_expression_bindings() {
+    let ts_code = r#"
+        const g = function(x: number) { return x; };
+        function outer(): void {
+            const h = function(label: string) { return label; };
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub fn g(x: f64) -> f64{\n    return x;\n}"));
+    assert!(rust_code.contains("let h = |label: String| -> String {"));
+}
+
+/// Test numeric separators and exponent notation
+#[test]
+fn test_number_separators_and_exponents() {
+    let mut lexer = Lexer::new("1_000 6.022e23 1.5e-3 0xFF_FF".to_string());
+    let tokens = lexer.tokenize().unwrap();
+    assert_eq!(tokens[0], Token::Number(1000.0));
+    assert_eq!(tokens[1], Token::Number(6.022e23));
+    assert_eq!(tokens[2], Token::Number(1.5e-3));
+    assert_eq!(tokens[3], Token::RadixNumber(65535.0, "0xFFFF".to_string()));
+
+    for invalid in ["1__000", "1_", "1_.5", "1e"] {
+        let mut lexer = Lexer::new(invalid.to_string());
+        assert!(lexer.tokenize().is_err(), "{} should not lex", invalid);
+    }
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str("const rate: number = 1.5e-3;").unwrap();
+    assert!(rust_code.contains("= 0.0015;"));
+}
+
+/// Test BigInt literals with the `n` suffix
+#[test]
+fn test_bigint_literals() {
+    let mut lexer = Lexer::new("const big = 9007199254740993n;".to_string());
+    let tokens = lexer.tokenize().unwrap();
+    assert_eq!(tokens[3], Token::BigInt("9007199254740993".to_string()));
+    assert_eq!(tokens[4], Token::Semicolon);
+
+    let mut lexer = Lexer::new("1.5n".to_string());
+    assert!(lexer.tokenize().is_err());
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str("const big = 9007199254740993n;").unwrap();
+    assert!(rust_code.contains("let big: i64 = 9007199254740993i64;"));
+}
+
+/// Test immediately invoked function expressions
+#[test]
+fn test_iife() {
+    let ts_code = r#"
+        const one = (() => { return 1; })();
+        const two = (function() { return 2; })();
+        const three = ((x: number) => x)(3);
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("let one: f64 = (|| -> f64 {\n    return 1.0;\n})();"));
+    assert!(rust_code.contains("let two: f64 = (|| -> f64 {\n    return 2.0;\n})();"));
+    assert!(rust_code.contains("let three: f64 = (|x: f64| -> f64 { x })(3.0);"));
+}
+
+/// Test regex literals and their disambiguation from division
+#[test]
+fn test_regex_literals() {
+    let mut lexer = Lexer::new("const re = /ab+c/gi;".to_string());
+    let tokens = lexer.tokenize().unwrap();
+    assert_eq!(tokens[3], Token::RegExp("ab+c".to_string(), "gi".to_string()));
+    assert_eq!(tokens[4], Token::Semicolon);
+
+    let mut lexer = Lexer::new("x = a / b / 2; y = /[/]/;".to_string());
+    let tokens = lexer.tokenize().unwrap();
+    assert_eq!(tokens[3], Token::Divide);
+    assert_eq!(tokens[5], Token::Divide);
+    assert_eq!(tokens[10], Token::RegExp("[/]".to_string(), String::new()));
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str("const re = /ab+c/gi;").unwrap();
+    assert!(rust_code.contains("let re: regex::Regex = regex::Regex::new(\"(?i)ab+c\").unwrap();"));
+
+    // Patterns are escaped as ordinary string literals, whatever they contain
+    let rust_code = compiler.compile_str(r##"const quoted = /"#\d/;"##).unwrap();
+    assert!(rust_code.contains(r##"regex::Regex::new("\"#\\d").unwrap()"##), "{}", rust_code);
+
+    // Output projects depend on the regex crate only when it is used
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("re.ts");
+    fs::write(&input_file, "const re = /ab+c/;").unwrap();
+    compiler.compile(&input_file, temp_dir.path()).unwrap();
+    let cargo_toml = fs::read_to_string(temp_dir.path().join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains("regex = \"1\""), "{}", cargo_toml);
+
+    fs::write(&input_file, "const n = 1;").unwrap();
+    compiler.compile(&input_file, temp_dir.path()).unwrap();
+    let cargo_toml = fs::read_to_string(temp_dir.path().join("Cargo.toml")).unwrap();
+    assert!(!cargo_toml.contains("regex"), "{}", cargo_toml);
+}
+
+/// Test mapped types over `keyof` a known type
+#[test]
+fn test_keyof_mapped_type() {
+    let ts_code = r#"
+        interface Point {
+            x: number;
+            y: number;
+        }
+
+        type PointFlags = { [K in keyof Point]: boolean };
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub struct PointFlags {\n    pub x: bool,\n    pub y: bool\n}"));
+}
+
+/// Test hex and Unicode escape sequences in strings
+#[test]
+fn test_string_hex_and_unicode_escapes() {
+    let source = r#""\u00e9" "\x41" '\u{1F600}' `\u{48}i` "\uD83D\uDE00""#;
+    let expected = vec![
+        Token::String("\u{e9}".to_string()),
+        Token::String("A".to_string()),
+        Token::String("\u{1F600}".to_string()),
+        Token::TemplateLiteral("Hi".to_string()),
+        Token::String("\u{1F600}".to_string()),
+        Token::EOF,
+    ];
+
+    let mut lexer = Lexer::new(source.to_string());
+    assert_eq!(lexer.tokenize().unwrap(), expected);
+
+    for malformed in [r#""\x4""#, r#""\u00g9""#, r#""\u{}""#, r#""\u{110000}""#] {
+        assert!(Lexer::new(malformed.to_string()).tokenize().is_err());
+    }
+}
+
+/// Test `?` and `readonly` modifiers on mapped types
+#[test]
+fn test_mapped_type_modifiers() {
+    let ts_code = r#"
+        interface Options {
+            host?: string;
+            port?: number;
+            readonly id: string;
+        }
+
+        type Required = { [K in keyof Options]-?: Options[K] };
+        type Partial = { [K in keyof Options]+?: Options[K] };
+        type Mutable = { -readonly [K in keyof Options]: Options[K] };
+        type Frozen = { readonly [K in keyof Options]-?: boolean };
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("pub struct Required {\n    pub host: String,\n    pub port: f64,\n    id: String\n}"));
+    assert!(rust_code.contains(
+        "pub struct Partial {\n    pub host: Option<String>,\n    pub port: Option<f64>,\n    id: Option<String>\n}"
+    ));
+    assert!(rust_code.contains("pub struct Mutable {\n    pub host: Option<String>,\n    pub port: Option<f64>,\n    pub id: String\n}"));
+    assert!(rust_code.contains("pub struct Frozen {\n    host: bool,\n    port: bool,\n    id: bool\n}"));
+    assert!(rust_code.contains("impl Frozen {\n    pub fn host(&self) -> &bool {\n        &self.host\n    }"));
+}
+
+/// Test template literal interpolations
+#[test]
+fn test_template_literal_interpolation() {
+    let ts_code = r#"
+        function describe(name: string, age: number): string {
+            const message = `Hello ${name}, you are ${age}`;
+            return message;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("let message: String = format!(\"Hello {}, you are {}\", name, age);"));
+
+    let mut lexer = Lexer::new("`{${a + `${b}`}}`".to_string());
+    let tokens = lexer.tokenize().unwrap();
+    let Token::TemplateExpression(quasis, expressions) = &tokens[0] else {
+        panic!("expected a template expression, found {:?}", tokens[0]);
+    };
+    assert_eq!(quasis, &vec!["{".to_string(), "}".to_string()]);
+    assert_eq!(expressions[0][0], Token::Identifier("a".to_string()));
+    assert_eq!(expressions[0].len(), 4);
+}
+
+/// Test prefix and postfix increment and decrement
+#[test]
+fn test_increment_decrement() {
+    let mut lexer = Lexer::new("i++ --j".to_string());
+    let tokens = lexer.tokenize().unwrap();
+    assert_eq!(tokens[1], Token::Increment);
+    assert_eq!(tokens[2], Token::Decrement);
+
+    let ts_code = r#"
+        function sum(n: number): number {
+            let total = 0;
+            for (let i = 0; i < n; i++) {
+                total += i;
+            }
+            let count = 0;
+            ++count;
+            count--;
+            const previous = count++;
+            return total;
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("let mut i: f64 = 0.0;\n        while (i < n) {\n            total += i;\n            i += 1.0;\n        }"));
+    assert!(rust_code.contains("    count += 1.0;\n    count -= 1.0;\n"));
+    assert!(rust_code.contains("let previous: f64 = { let previous = count; count += 1.0; previous };"));
+}
+
+/// Test `instanceof` and `typeof` expressions
+#[test]
+fn test_instanceof_and_typeof() {
+    let ts_code = r#"
+        class Dog {}
+
+        function check(pet: any): boolean {
+            const kind = typeof pet;
+            return pet instanceof Dog;
+        }
+    "#;
+
+    let mut compiler = Compiler::new().with_runtime(true);
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("return pet.downcast_ref::<Dog>().is_some();"));
+    assert!(rust_code.contains("get_type_of(&pet)"));
+    assert!(rust_code.contains("pub fn get_type_of(value: &dyn Any) -> &'static str {"));
+}
+
+/// Test `typeof` reports integer numbers as "number" when numbers are `i64`
+#[test]
+fn test_typeof_integer_numbers() {
+    let ts_code = "function kind(n: number): string { return typeof n; }";
+
+    let mut compiler = Compiler::new().with_generator_config(GeneratorConfig {
+        runtime: true,
+        derive_serde: false,
+        number_as_i64: true,
+        ..Default::default()
+    });
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("value.is::<i64>() {\n        \"number\""), "{}", rust_code);
+    assert!(!rust_code.contains("\"bigint\""), "{}", rust_code);
+
+    let mut compiler = Compiler::new().with_runtime(true);
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("value.is::<i64>() {\n        \"bigint\""), "{}", rust_code);
+}
+
+/// Test console methods writing to stdout and stderr
+#[test]
+fn test_console_error_and_warn() {
+    let ts_code = r#"
+        function report(code: number) {
+            console.error("bad");
+            console.warn("careful", code);
+            console.info("done");
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("eprintln!(\"{}\", \"bad\".to_string())"));
+    assert!(rust_code.contains("eprintln!(\"{} {}\", \"careful\".to_string(), code)"));
+    assert!(rust_code.contains("println!(\"{}\", \"done\".to_string())"));
+}
+
+/// Test console.log statements end with exactly one semicolon
+#[test]
+fn test_console_log_single_semicolon() {
+    let ts_code = r#"
+        function hello(name: string) {
+            console.log(name);
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("println!(\"{}\", name);\n"));
+    assert!(!rust_code.contains(";;"));
+}
+
+/// Test declarations inside namespaces
+#[test]
+fn test_namespace_declarations() {
+    let ts_code = r#"
+        namespace Geo {
+            export class Point {
+                x: number;
+            }
+
+            export function origin(): number {
+                return 0;
+            }
+        }
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    let module = &rust_code[rust_code.find("pub mod Geo {").unwrap()..];
+    assert!(module.contains("    use super::*;\n"));
+    assert!(module.contains("    pub struct Point {\n        pub x: f64\n    }"));
+    assert!(module.contains("    pub fn origin() -> f64"));
+    assert!(rust_code.starts_with("use serde::{Deserialize, Serialize};\n"));
+}
+
+/// Test namespace imports and default imports combined with named imports
+#[test]
+fn test_namespace_and_default_imports() {
+    let ts_code = r#"
+        import * as fs from "fs";
+        import * as path from "node_path";
+        import React, { useState } from "react";
+    "#;
+
+    let mut compiler = Compiler::new();
+    let rust_code = compiler.compile_str(ts_code).unwrap();
+    assert!(rust_code.contains("use fs;\n"));
+    assert!(rust_code.contains("use node_path as path;\n"));
+    assert!(rust_code.contains("use react::{React, useState};\n"));
+}
+
+/// Test aliased named imports
+#[test]
+fn test_import_alias() {
+    let mut compiler = Compiler::new();
+    let rust_code = compiler
+        .compile_str("import { foo as bar, baz } from \"m\";")
+        .unwrap();
+    assert!(rust_code.contains("use m::{foo as bar, baz};"));
+}
+
+/// Test default exports and default imports between project modules
+#[test]
+fn test_export_default() {
+    let mut compiler = Compiler::new();
+    let rust_code = compiler
+        .compile_str("export default class Foo {\n    x: number;\n}")
+        .unwrap();
+    assert!(rust_code.contains("pub struct Foo {"));
+    assert!(rust_code.contains("pub use Foo as DefaultExport;"));
+
+    let rust_code = compiler.compile_str("export default class {}").unwrap();
+    assert!(rust_code.contains("pub struct DefaultExport"));
+    assert!(!rust_code.contains("pub use"));
+
+    let rust_code = compiler.compile_str("export default 42;").unwrap();
+    assert!(rust_code.contains("pub const DefaultExport: f64 = 42.0;"));
+
+    let rust_code = compiler
+        .compile_str("import Widget from \"crate::widget\";")
+        .unwrap();
+    assert!(rust_code.contains("use crate::widget::{DefaultExport as Widget};"));
+}